@@ -0,0 +1,146 @@
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::convert::Infallible;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(input.split('\n').map(|line| line.to_string()).collect())
+    }
+
+    fn part_one(lines: &Self::Input, _: &Parameters) -> Answer {
+        let first_values: Vec<Option<char>> = lines
+            .iter()
+            .map(|line| line.chars().find(|char| char.is_numeric()))
+            .collect();
+        let last_values: Vec<Option<char>> = lines
+            .iter()
+            .map(|line| line.chars().rev().find(|char| char.is_numeric()))
+            .collect();
+
+        get_calibration_values_sum(&first_values, &last_values).into()
+    }
+
+    fn part_two(lines: &Self::Input, _: &Parameters) -> Answer {
+        let first_values: Vec<Option<char>> =
+            lines.iter().map(|line| get_value(line, true)).collect();
+        let last_values: Vec<Option<char>> =
+            lines.iter().map(|line| get_value(line, false)).collect();
+
+        get_calibration_values_sum(&first_values, &last_values).into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(lines: &Self::Input) -> Value {
+        Value::list(lines.iter().map(String::as_str))
+    }
+}
+
+fn get_calibration_values_sum(
+    first_values: &[Option<char>],
+    last_values: &[Option<char>],
+) -> Result<u64, Overflow> {
+    let calibration_value_pairs: Vec<String> = first_values
+        .iter()
+        .zip(last_values)
+        .filter_map(
+            |(maybe_first, maybe_last)| match (maybe_first, maybe_last) {
+                (Some(first), Some(last)) => Some([first, last].into_iter().collect::<String>()),
+                (_, _) => None,
+            },
+        )
+        .collect();
+
+    tracing::debug!(
+        lines = first_values.len(),
+        pairs = calibration_value_pairs.len(),
+        "paired calibration values"
+    );
+
+    calibration_value_pairs
+        .iter()
+        .filter_map(|unparsed| unparsed.parse::<u64>().ok())
+        .checked_sum()
+}
+
+fn get_value(line: &str, get_first: bool) -> Option<char> {
+    const SPELLED_OUT_DIGITS: [(&str, char); 10] = [
+        ("zero", '0'),
+        ("one", '1'),
+        ("two", '2'),
+        ("three", '3'),
+        ("four", '4'),
+        ("five", '5'),
+        ("six", '6'),
+        ("seven", '7'),
+        ("eight", '8'),
+        ("nine", '9'),
+    ];
+
+    let chars: Vec<char> = match get_first {
+        true => line.chars().collect(),
+        false => line.chars().rev().collect(),
+    };
+
+    for i in 0..chars.len() {
+        let maybe_digit = chars.get(i).unwrap();
+
+        if maybe_digit.is_numeric() {
+            return Some(*maybe_digit);
+        }
+
+        for (spelled_out_digit, numeric_digit) in SPELLED_OUT_DIGITS.iter() {
+            let spelled_out_digit_len = spelled_out_digit.len();
+
+            if i + spelled_out_digit_len > chars.len() {
+                continue;
+            }
+
+            let possible_spelled_out_digit: &[char] = &chars[i..i + spelled_out_digit_len];
+            let spelled_out_digit: Vec<char> = match get_first {
+                true => spelled_out_digit.chars().collect(),
+                false => spelled_out_digit.chars().rev().collect(),
+            };
+
+            if spelled_out_digit == possible_spelled_out_digit {
+                return Some(*numeric_digit);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn sums_more_than_fit_in_a_u16() {
+        let lines = Day::parse(&"9nine9\n".repeat(1000)).unwrap();
+
+        assert_eq!(
+            Day::part_one(&lines, &Parameters::default()),
+            Answer::from(99_000u64)
+        );
+        assert_eq!(
+            Day::part_two(&lines, &Parameters::default()),
+            Answer::from(99_000u64)
+        );
+    }
+}
@@ -0,0 +1,667 @@
+use common::frame::Cell;
+use common::frame::Colour;
+use common::frame::Frame;
+use common::frame::Simulation;
+use common::image::Image;
+use common::image::Render;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use grid::Direction;
+use grid::Grid;
+use grid::ParseGridError;
+use grid::Position;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::Deref;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    tiles: Grid<Tile>,
+}
+
+impl Deref for Field {
+    type Target = Grid<Tile>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.tiles
+    }
+}
+
+impl Field {
+    /// The starting position, if there is one. Only searching for the loop
+    /// needs it, so logging it must not fail when it's missing.
+    fn get_start_position(&self) -> Option<Position> {
+        self.find_position(|tile| *tile == Tile::StartingPosition)
+    }
+
+    fn filter(&mut self, positions: &[Position]) {
+        let positions_to_keep: HashSet<&Position> = positions.iter().collect();
+
+        self.tiles.iter_mut().for_each(|(position, tile)| {
+            if !positions_to_keep.contains(&position) {
+                *tile = Tile::Ground
+            }
+        });
+    }
+
+    /// Displays the field with box-drawing characters, which are easier to
+    /// follow than the puzzle's letters when looking for the loop.
+    pub fn pretty(&self) -> impl Display + '_ {
+        self.tiles.render(|tile| tile.pretty_symbol())
+    }
+}
+
+pub type FieldError = ParseGridError<ParseTileError>;
+
+impl FromStr for Field {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = s.parse()?;
+
+        Ok(Field { tiles })
+    }
+}
+
+/// Displays the field in the puzzle's own notation, so it parses back to the
+/// same field. See [`Field::pretty`] for a more readable rendering.
+impl Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.tiles.render(|tile| char::from(*tile)).fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    VerticalPipe,
+    HorizontalPipe,
+    NorthEastBend,
+    NorthWestBend,
+    SouthWestBend,
+    SouthEastBend,
+    Ground,
+    StartingPosition,
+}
+
+#[derive(Debug)]
+pub enum ParseTileError {
+    UnknownTile(char),
+}
+
+impl Tile {
+    fn pretty_symbol(self) -> char {
+        match self {
+            Tile::VerticalPipe => '│',
+            Tile::HorizontalPipe => '─',
+            Tile::NorthEastBend => '╰',
+            Tile::NorthWestBend => '╯',
+            Tile::SouthWestBend => '╮',
+            Tile::SouthEastBend => '╭',
+            Tile::Ground => '.',
+            Tile::StartingPosition => 'S',
+        }
+    }
+}
+
+impl Display for ParseTileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseTileError::UnknownTile(tile) => write!(f, "unknown tile {:?}", tile),
+        }
+    }
+}
+
+impl Error for ParseTileError {}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::VerticalPipe => '|',
+            Tile::HorizontalPipe => '-',
+            Tile::NorthEastBend => 'L',
+            Tile::NorthWestBend => 'J',
+            Tile::SouthWestBend => '7',
+            Tile::SouthEastBend => 'F',
+            Tile::Ground => '.',
+            Tile::StartingPosition => 'S',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseTileError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Tile::VerticalPipe),
+            '-' => Ok(Tile::HorizontalPipe),
+            'L' => Ok(Tile::NorthEastBend),
+            'J' => Ok(Tile::NorthWestBend),
+            '7' => Ok(Tile::SouthWestBend),
+            'F' => Ok(Tile::SouthEastBend),
+            '.' => Ok(Tile::Ground),
+            'S' => Ok(Tile::StartingPosition),
+            _ => Err(ParseTileError::UnknownTile(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    position: Position,
+    direction: Direction,
+    distance: u32,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Field;
+    type Error = FieldError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let field: Field = input.parse()?;
+        tracing::debug!(start = ?field.get_start_position(), "parsed field");
+
+        Ok(field)
+    }
+
+    fn part_one(field: &Self::Input, _: &Parameters) -> Answer {
+        let pipe_loop = find_loop(field);
+
+        pipe_loop.iter().map(|s| s.distance).max().unwrap().into()
+    }
+
+    fn part_two(field: &Self::Input, _: &Parameters) -> Answer {
+        let mut field = field.clone();
+
+        let pipe_loop = find_loop(&field);
+
+        field.filter(
+            &pipe_loop
+                .iter()
+                .map(|s| s.position)
+                .collect::<Vec<Position>>(),
+        );
+
+        let enclosed_tiles = find_enclosed_tiles(&field, &pipe_loop);
+        enclosed_tiles.len().into()
+    }
+}
+
+/// Walks the loop from the starting position one pipe per frame, leaving a
+/// trail behind.
+impl Simulation for Day {
+    fn frames(field: &Self::Input) -> Box<dyn Iterator<Item = Frame> + '_> {
+        let pipe_loop = find_loop(field);
+        let loop_length = pipe_loop.len();
+        let steps: HashMap<Position, usize> = pipe_loop
+            .into_iter()
+            .enumerate()
+            .map(|(step, state)| (state.position, step))
+            .collect();
+
+        Box::new((0..loop_length).map(move |current_step| {
+            field
+                .frame(|position, tile| {
+                    let symbol = tile.pretty_symbol();
+
+                    match steps.get(&position) {
+                        Some(&step) if step == current_step => Cell::coloured(symbol, Colour::Red),
+                        Some(&step) if step < current_step => {
+                            Cell::coloured(symbol, Colour::Yellow)
+                        }
+                        _ => Cell::coloured(symbol, Colour::Grey),
+                    }
+                })
+                .with_caption(format!("pipe {} of {}", current_step + 1, loop_length))
+        }))
+    }
+}
+
+/// Picks the loop out of the field, starting from the red starting position.
+impl Render for Day {
+    fn render(field: &Self::Input, _: &Parameters) -> Image {
+        let pipe_loop: HashSet<Position> = find_loop(field)
+            .into_iter()
+            .map(|state| state.position)
+            .collect();
+
+        Image::Raster(field.frame(|position, tile| {
+            let symbol = tile.pretty_symbol();
+
+            match tile {
+                Tile::StartingPosition => Cell::coloured(symbol, Colour::Red),
+                _ if pipe_loop.contains(&position) => Cell::coloured(symbol, Colour::Yellow),
+                Tile::Ground => Cell::new(symbol),
+                _ => Cell::coloured(symbol, Colour::Grey),
+            }
+        }))
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(field: &Self::Input) -> Value {
+        Value::record([
+            ("tiles", field.to_value(|tile| char::from(*tile))),
+            ("start", field.get_start_position().into()),
+        ])
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(start = ?field.get_start_position()))]
+fn find_loop(field: &Field) -> Vec<State> {
+    let start = field
+        .get_start_position()
+        .expect("the field should have a starting position");
+
+    let (start_1, start_2) = find_connected_pipes(field, &start).unwrap();
+    tracing::trace!(?start_1.position, ?start_2.position, "found the pipes joining the start");
+    let start_direction = start_2.direction.reverse();
+
+    let mut steps_1 = Vec::new();
+    let mut steps_2 = Vec::new();
+
+    let mut current_1 = start_1;
+    let mut current_2 = start_2;
+
+    while current_1.position != current_2.position {
+        steps_1.push(current_1.clone());
+        steps_2.push(current_2.clone());
+
+        current_1 = get_next_state(field, current_1);
+        current_2 = get_next_state(field, current_2);
+    }
+
+    steps_1.push(current_1);
+
+    [State {
+        position: start,
+        direction: start_direction,
+        distance: 0,
+    }]
+    .into_iter()
+    .chain(
+        steps_1
+            .into_iter()
+            .chain(steps_2.into_iter().rev().map(|s| {
+                State {
+                    direction: traverse_pipe(*field.get(s.position).unwrap(), s.direction)
+                        .unwrap()
+                        .reverse(),
+                    ..s
+                }
+            })),
+    )
+    .collect()
+}
+
+fn get_next_state(field: &Field, state: State) -> State {
+    let current_tile = *field.get(state.position).unwrap();
+    let next_direction = traverse_pipe(current_tile, state.direction).unwrap();
+    let next_position = field.step(state.position, next_direction).unwrap();
+
+    State {
+        position: next_position,
+        direction: next_direction,
+        distance: state.distance + 1,
+    }
+}
+
+fn find_connected_pipes(field: &Field, position: &Position) -> Option<(State, State)> {
+    let mut direction_combinations = Vec::new();
+
+    const DIRECTIONS: [Direction; 4] = Direction::ALL;
+    for (i, first_direction) in DIRECTIONS.iter().enumerate().take(DIRECTIONS.len() - 1) {
+        for second_direction in DIRECTIONS.iter().skip(i + 1) {
+            direction_combinations.push((*first_direction, *second_direction))
+        }
+    }
+
+    for (direction_1, direction_2) in direction_combinations {
+        if let (Some(new_position_1), Some(new_position_2)) = (
+            field.step(*position, direction_1),
+            field.step(*position, direction_2),
+        ) {
+            if let (Some(&new_tile_1), Some(&new_tile_2)) =
+                (field.get(new_position_1), field.get(new_position_2))
+            {
+                if traverse_pipe(new_tile_1, direction_1).is_some()
+                    && traverse_pipe(new_tile_2, direction_2).is_some()
+                {
+                    return Some((
+                        State {
+                            position: new_position_1,
+                            direction: direction_1,
+                            distance: 1,
+                        },
+                        State {
+                            position: new_position_2,
+                            direction: direction_2,
+                            distance: 1,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn traverse_pipe(tile: Tile, direction: Direction) -> Option<Direction> {
+    match (tile, direction) {
+        (Tile::Ground | Tile::StartingPosition, _) => None,
+        (Tile::VerticalPipe, Direction::Up) => Some(Direction::Up),
+        (Tile::VerticalPipe, Direction::Down) => Some(Direction::Down),
+        (Tile::HorizontalPipe, Direction::Left) => Some(Direction::Left),
+        (Tile::HorizontalPipe, Direction::Right) => Some(Direction::Right),
+        (Tile::NorthEastBend, Direction::Down) => Some(Direction::Right),
+        (Tile::NorthEastBend, Direction::Left) => Some(Direction::Up),
+        (Tile::NorthWestBend, Direction::Down) => Some(Direction::Left),
+        (Tile::NorthWestBend, Direction::Right) => Some(Direction::Up),
+        (Tile::SouthWestBend, Direction::Up) => Some(Direction::Left),
+        (Tile::SouthWestBend, Direction::Right) => Some(Direction::Down),
+        (Tile::SouthEastBend, Direction::Up) => Some(Direction::Right),
+        (Tile::SouthEastBend, Direction::Left) => Some(Direction::Down),
+        (_, _) => None,
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum SideOfLoop {
+    Left,
+    Right,
+}
+
+fn find_enclosed_tiles(field: &Field, pipe_loop: &[State]) -> HashSet<Position> {
+    let loop_iter = pipe_loop
+        .iter()
+        .map(|s| (s.position, *field.get(s.position).unwrap(), s.direction));
+
+    // Is left will go from the perspective of the iteration;
+    let mut known_inside: Option<SideOfLoop> = None;
+
+    let mut left_ground_tiles = HashSet::new();
+    let mut right_ground_tiles = HashSet::new();
+
+    for (current_position, current_tile, current_direction) in loop_iter {
+        let (left_positions_to_check, right_positions_to_check) =
+            get_positions_to_check(field, current_position, current_tile, current_direction);
+
+        if known_inside != Some(SideOfLoop::Right) {
+            for maybe_position in left_positions_to_check {
+                if maybe_position.is_none() {
+                    known_inside = Some(SideOfLoop::Right);
+                    break;
+                }
+
+                let search_position = maybe_position.unwrap();
+                let search_tile = *field.get(search_position).unwrap();
+
+                if search_tile == Tile::Ground {
+                    if let Some(connected_grounds) = find_connected_grounds(field, search_position)
+                    {
+                        left_ground_tiles = left_ground_tiles
+                            .union(&connected_grounds)
+                            .cloned()
+                            .collect();
+                    } else {
+                        known_inside = Some(SideOfLoop::Right);
+                        break;
+                    }
+                }
+            }
+        }
+
+        if known_inside != Some(SideOfLoop::Left) {
+            for maybe_position in right_positions_to_check {
+                if maybe_position.is_none() {
+                    known_inside = Some(SideOfLoop::Left);
+                    break;
+                }
+
+                let search_position = maybe_position.unwrap();
+                let search_tile = *field.get(search_position).unwrap();
+
+                if let Tile::Ground = search_tile {
+                    if let Some(connected_grounds) = find_connected_grounds(field, search_position)
+                    {
+                        right_ground_tiles = right_ground_tiles
+                            .union(&connected_grounds)
+                            .cloned()
+                            .collect();
+                    } else {
+                        known_inside = Some(SideOfLoop::Left);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    tracing::trace!(
+        left = left_ground_tiles.len(),
+        right = right_ground_tiles.len(),
+        "sorted the ground tiles by side of the loop"
+    );
+
+    match known_inside.unwrap() {
+        SideOfLoop::Left => left_ground_tiles,
+        SideOfLoop::Right => right_ground_tiles,
+    }
+}
+
+fn find_connected_grounds(field: &Field, position: Position) -> Option<HashSet<Position>> {
+    let mut found = HashSet::new();
+    let mut stack = Vec::new();
+
+    stack.push(position);
+
+    while let Some(search_position) = stack.pop() {
+        found.insert(search_position);
+
+        for direction in Direction::ALL {
+            if let Some(new_position) = field.step(search_position, direction) {
+                let new_tile = *field.get(new_position)?;
+                if new_tile == Tile::Ground && !found.contains(&new_position) {
+                    stack.push(new_position);
+                }
+            } else {
+                return None;
+            }
+        }
+    }
+
+    Some(found)
+}
+
+fn get_positions_to_check(
+    field: &Field,
+    current_position: Position,
+    current_tile: Tile,
+    current_direction: Direction,
+) -> (Vec<Option<Position>>, Vec<Option<Position>>) {
+    let (left_tiles_to_check, right_tiles_to_check) = match (current_direction, current_tile) {
+        (Direction::Up, Tile::VerticalPipe) => {
+            (vec![vec![Direction::Left]], vec![vec![Direction::Right]])
+        }
+        (Direction::Up, Tile::SouthWestBend) => (
+            vec![],
+            vec![
+                vec![Direction::Right],
+                vec![Direction::Up],
+                vec![Direction::Up, Direction::Right],
+            ],
+        ),
+        (Direction::Up, Tile::SouthEastBend) => (
+            vec![
+                vec![Direction::Left],
+                vec![Direction::Up],
+                vec![Direction::Up, Direction::Left],
+            ],
+            vec![],
+        ),
+        (Direction::Down, Tile::VerticalPipe) => {
+            (vec![vec![Direction::Right]], vec![vec![Direction::Left]])
+        }
+        (Direction::Down, Tile::NorthEastBend) => (
+            vec![],
+            vec![
+                vec![Direction::Left],
+                vec![Direction::Down],
+                vec![Direction::Down, Direction::Left],
+            ],
+        ),
+        (Direction::Down, Tile::NorthWestBend) => (
+            vec![
+                vec![Direction::Right],
+                vec![Direction::Down],
+                vec![Direction::Down, Direction::Right],
+            ],
+            vec![],
+        ),
+        (Direction::Left, Tile::HorizontalPipe) => {
+            (vec![vec![Direction::Down]], vec![vec![Direction::Up]])
+        }
+        (Direction::Left, Tile::NorthEastBend) => (
+            vec![
+                vec![Direction::Left],
+                vec![Direction::Down],
+                vec![Direction::Down, Direction::Left],
+            ],
+            vec![],
+        ),
+        (Direction::Left, Tile::SouthEastBend) => (
+            vec![],
+            vec![
+                vec![Direction::Left],
+                vec![Direction::Up],
+                vec![Direction::Up, Direction::Left],
+            ],
+        ),
+        (Direction::Right, Tile::HorizontalPipe) => {
+            (vec![vec![Direction::Up]], vec![vec![Direction::Down]])
+        }
+        (Direction::Right, Tile::NorthWestBend) => (
+            vec![],
+            vec![
+                vec![Direction::Right],
+                vec![Direction::Down],
+                vec![Direction::Down, Direction::Right],
+            ],
+        ),
+        (Direction::Right, Tile::SouthWestBend) => (
+            vec![
+                vec![Direction::Right],
+                vec![Direction::Up],
+                vec![Direction::Up, Direction::Right],
+            ],
+            vec![],
+        ),
+
+        (_, _) => (vec![], vec![]),
+    };
+
+    let left_positions: Vec<Option<Position>> =
+        move_directions(field, current_position, left_tiles_to_check);
+    let right_positions: Vec<Option<Position>> =
+        move_directions(field, current_position, right_tiles_to_check);
+
+    (left_positions, right_positions)
+}
+
+fn move_directions(
+    field: &Field,
+    current_position: Position,
+    directions: Vec<Vec<Direction>>,
+) -> Vec<Option<Position>> {
+    directions
+        .into_iter()
+        .map(|directions| {
+            directions.into_iter().try_fold(
+                current_position,
+                |position: Position, direction: Direction| field.step(position, direction),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Day, Field};
+
+    use common::frame::Colour;
+    use common::frame::Simulation;
+    use common::image::Image;
+    use common::image::Render;
+    use common::parameters::Parameters;
+    use proptest::prelude::*;
+
+    const TILES: [char; 8] = ['|', '-', 'L', 'J', '7', 'F', '.', 'S'];
+
+    fn field() -> impl Strategy<Value = Field> {
+        grid::strategy::text(&TILES, 8).prop_map(|text| text.parse().unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn display_is_the_puzzle_notation(text in grid::strategy::text(&TILES, 8)) {
+            let field: Field = text.parse().unwrap();
+
+            prop_assert_eq!(field.to_string(), text);
+        }
+
+        #[test]
+        fn display_parses_back(field in field()) {
+            prop_assert_eq!(field.to_string().parse::<Field>().unwrap(), field);
+        }
+
+        #[test]
+        fn pretty_keeps_the_shape(field in field()) {
+            let pretty = field.pretty().to_string();
+            let rows: Vec<&str> = pretty.split('\n').collect();
+
+            prop_assert_eq!(rows.len(), field.get_height());
+            prop_assert!(rows.iter().all(|row| row.chars().count() == field.get_width()));
+        }
+    }
+
+    #[test]
+    fn frames_walk_the_whole_loop() {
+        let field: Field = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".parse().unwrap();
+
+        let captions: Vec<String> = Day::frames(&field)
+            .map(|frame| frame.caption().to_string())
+            .collect();
+
+        assert_eq!(captions.len(), 8);
+        assert_eq!(captions.last().unwrap(), "pipe 8 of 8");
+    }
+
+    #[test]
+    fn render_picks_out_the_loop() {
+        let field: Field = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF".parse().unwrap();
+
+        let Image::Raster(frame) = Day::render(&field, &Parameters::default()) else {
+            panic!("a field should be drawn as a grid");
+        };
+        let loop_symbols: String = frame
+            .rows()
+            .iter()
+            .flatten()
+            .filter(|cell| matches!(cell.colour, Some(Colour::Red | Colour::Yellow)))
+            .map(|cell| cell.symbol)
+            .collect();
+
+        assert_eq!(loop_symbols, "S─╮││╰─╯");
+    }
+}
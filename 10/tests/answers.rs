@@ -0,0 +1,4 @@
+#[test]
+fn expected_answers() {
+    common::answers::check::<ten::Day>(env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,350 @@
+use common::checked::CheckedIterator;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+struct Coordinate {
+    y: u64,
+    x: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Image {
+    pixels: BTreeSet<Coordinate>,
+}
+
+impl Image {
+    /// Displays the image with each galaxy numbered in reading order, like
+    /// the puzzle's examples.
+    pub fn numbered(&self) -> NumberedGalaxies<'_> {
+        NumberedGalaxies(self)
+    }
+
+    fn render(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        mut render_galaxy: impl FnMut(&mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        let (Some(max_x), Some(max_y)) = (
+            self.pixels.iter().map(|coord| coord.x).max(),
+            self.pixels.last().map(|coord| coord.y),
+        ) else {
+            return Ok(());
+        };
+
+        for y in 0..=max_y {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+
+            for x in 0..=max_x {
+                if self.pixels.contains(&Coordinate { x, y }) {
+                    render_galaxy(f)?;
+                } else {
+                    f.write_str(".")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseImageError(Span);
+
+impl Display for ParseImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown pixel {:?}", self.0.snippet())
+    }
+}
+
+impl Error for ParseImageError {}
+
+impl ParseError for ParseImageError {
+    fn span(&self) -> Option<&Span> {
+        Some(&self.0)
+    }
+}
+
+impl FromStr for Image {
+    type Err = ParseImageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut pixels = BTreeSet::new();
+
+        let mut x = 0;
+        let mut y = 0;
+
+        for (i, c) in s.char_indices() {
+            match c {
+                '#' => {
+                    pixels.insert(Coordinate { x, y });
+                    x += 1;
+                }
+                '\n' => {
+                    y += 1;
+                    x = 0;
+                }
+                '.' => x += 1,
+                _ => return Err(ParseImageError(Span::of(&s[i..i + c.len_utf8()]))),
+            }
+        }
+
+        Ok(Image { pixels })
+    }
+}
+
+/// Displays the image in the puzzle's notation, up to the last row and
+/// column with a galaxy in, so it parses back to the same image.
+impl Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(f, |f| f.write_str("#"))
+    }
+}
+
+pub struct NumberedGalaxies<'a>(&'a Image);
+
+impl Display for NumberedGalaxies<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut galaxy_count = 0;
+
+        self.0.render(f, |f| {
+            galaxy_count += 1;
+            write!(f, "{}", galaxy_count)
+        })
+    }
+}
+
+pub struct Day;
+
+const PART_1_EXPANSION: Parameter = Parameter {
+    name: "part_one_expansion",
+    description: "How many times bigger each empty row and column is in part one.",
+    default: 2,
+    range: 1..=1_000_000_000,
+};
+
+const PART_2_EXPANSION: Parameter = Parameter {
+    name: "part_two_expansion",
+    description: "How many times bigger each empty row and column is in part two.",
+    default: 1000000,
+    range: 1..=1_000_000_000,
+};
+
+impl Solution for Day {
+    type Input = Image;
+    type Error = ParseImageError;
+
+    const PARAMETERS: &'static [Parameter] = &[PART_1_EXPANSION, PART_2_EXPANSION];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let image: Image = input.parse()?;
+        tracing::debug!(galaxies = image.pixels.len(), "parsed image");
+
+        Ok(image)
+    }
+
+    fn part_one(image: &Self::Input, parameters: &Parameters) -> Answer {
+        let adjusted_image =
+            account_for_gravitational_effects(image, parameters.get(&PART_1_EXPANSION));
+        let shortest_paths_between_galaxies = find_shortest_paths_between_galaxies(&adjusted_image);
+        tracing::debug!(
+            pairs = shortest_paths_between_galaxies.len(),
+            "found shortest paths"
+        );
+        shortest_paths_between_galaxies
+            .into_iter()
+            .checked_sum()
+            .into()
+    }
+
+    fn part_two(image: &Self::Input, parameters: &Parameters) -> Answer {
+        let adjusted_image =
+            account_for_gravitational_effects(image, parameters.get(&PART_2_EXPANSION));
+        let shortest_paths_between_galaxies = find_shortest_paths_between_galaxies(&adjusted_image);
+        shortest_paths_between_galaxies
+            .into_iter()
+            .checked_sum()
+            .into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(image: &Self::Input) -> Value {
+        Value::record([(
+            "galaxies",
+            Value::list(image.pixels.iter().map(|coord| (coord.x, coord.y))),
+        )])
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn find_shortest_paths_between_galaxies(adjusted_image: &Image) -> Vec<u64> {
+    adjusted_image
+        .pixels
+        .iter()
+        .enumerate()
+        .flat_map(|(i, coordinate_1)| {
+            adjusted_image
+                .pixels
+                .iter()
+                .skip(i + 1)
+                .map(|coordinate_2| shortest_path_length(coordinate_1, coordinate_2))
+        })
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+fn find_shortest_paths_between_galaxies(adjusted_image: &Image) -> Vec<u64> {
+    let galaxies: Vec<&Coordinate> = adjusted_image.pixels.iter().collect();
+
+    galaxies
+        .par_iter()
+        .enumerate()
+        .flat_map_iter(|(i, coordinate_1)| {
+            galaxies[i + 1..]
+                .iter()
+                .map(|coordinate_2| shortest_path_length(coordinate_1, coordinate_2))
+        })
+        .collect()
+}
+
+/// The number of steps between two galaxies, where `coordinate_2` is not
+/// above `coordinate_1`.
+fn shortest_path_length(coordinate_1: &Coordinate, coordinate_2: &Coordinate) -> u64 {
+    (coordinate_2.y - coordinate_1.y)
+        + (coordinate_1.x.max(coordinate_2.x) - coordinate_1.x.min(coordinate_2.x))
+}
+
+fn account_for_gravitational_effects(image: &Image, gap_increase_factor: u64) -> Image {
+    let xs: BTreeSet<u64> = image.pixels.iter().map(|coordinate| coordinate.x).collect();
+    let ys: BTreeSet<u64> = image.pixels.iter().map(|coordinate| coordinate.y).collect();
+
+    let x_gaps = find_gaps(&xs);
+    let y_gaps = find_gaps(&ys);
+    tracing::trace!(
+        x_gaps = x_gaps.len(),
+        y_gaps = y_gaps.len(),
+        "found empty rows and columns"
+    );
+
+    let x_shifts = calculate_shifts(&x_gaps, gap_increase_factor);
+    let y_shifts = calculate_shifts(&y_gaps, gap_increase_factor);
+
+    let mut shifted = BTreeSet::new();
+    for coordinate in &image.pixels {
+        let x = match x_shifts
+            .iter()
+            .rev()
+            .find(|(shift_start, _)| coordinate.x >= *shift_start)
+        {
+            Some((_, shift_amount)) => coordinate.x + shift_amount,
+            None => coordinate.x,
+        };
+
+        let y = match y_shifts
+            .iter()
+            .rev()
+            .find(|(shift_start, _)| coordinate.y >= *shift_start)
+        {
+            Some((_, shift_amount)) => coordinate.y + shift_amount,
+            None => coordinate.y,
+        };
+
+        let replacement = Coordinate { x, y };
+
+        shifted.insert(replacement);
+    }
+
+    Image { pixels: shifted }
+}
+
+fn find_gaps(ordinates: &BTreeSet<u64>) -> Vec<(u64, u64)> {
+    let mut gaps = Vec::new();
+    let ordinate_iter = ordinates.iter();
+
+    let mut previous = None;
+    for current in ordinate_iter {
+        if let Some(previous) = previous {
+            let gap_start = previous + 1;
+            let gap = current - gap_start;
+
+            if gap > 0 {
+                gaps.push((gap_start, gap));
+            }
+        }
+
+        previous = Some(current)
+    }
+
+    gaps
+}
+
+fn calculate_shifts(gaps: &[(u64, u64)], gap_increase_factor: u64) -> Vec<(u64, u64)> {
+    let mut cumulative_gap_size = 0;
+    let mut shifts = Vec::new();
+
+    for (gap_start, gap_lengh) in gaps {
+        let gap_increase = gap_lengh * (gap_increase_factor - 1);
+
+        let shift_start = *gap_start;
+        let shift_amount = cumulative_gap_size + gap_increase;
+        shifts.push((shift_start, shift_amount));
+        cumulative_gap_size += gap_increase;
+    }
+
+    shifts
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{account_for_gravitational_effects, Image};
+
+    use proptest::prelude::*;
+
+    const IMAGE: &str = "[.#]{1,8}(\n[.#]{1,8}){0,7}";
+
+    proptest! {
+        #[test]
+        fn display_parses_back(text in IMAGE) {
+            let image: Image = text.parse().unwrap();
+
+            prop_assert_eq!(image.to_string().parse::<Image>().unwrap(), image);
+        }
+
+        #[test]
+        fn every_galaxy_is_kept(text in IMAGE) {
+            let image: Image = text.parse().unwrap();
+            let galaxies = text.matches('#').count();
+
+            prop_assert_eq!(image.pixels.len(), galaxies);
+            prop_assert_eq!(image.to_string().matches('#').count(), galaxies);
+            prop_assert_eq!(
+                image.numbered().to_string().split('\n').count(),
+                image.to_string().split('\n').count()
+            );
+        }
+
+        #[test]
+        fn no_expansion_leaves_the_image_alone(text in IMAGE) {
+            let image: Image = text.parse().unwrap();
+
+            prop_assert_eq!(account_for_gravitational_effects(&image, 1), image);
+        }
+    }
+}
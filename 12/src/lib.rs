@@ -0,0 +1,479 @@
+use common::checked::CheckedIterator;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConditionRecord {
+    format_1: Vec<Condition>,
+    format_2: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub enum ParseConditionRecordError {
+    UnknownConditionType(Span),
+    MissingDamagedGroups(Span),
+    ParseIntError(Span, ParseIntError),
+}
+
+impl Display for ParseConditionRecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseConditionRecordError::UnknownConditionType(span) => {
+                write!(f, "unknown spring condition {:?}", span.snippet())
+            }
+            ParseConditionRecordError::MissingDamagedGroups(_) => {
+                write!(f, "missing the sizes of the damaged groups")
+            }
+            ParseConditionRecordError::ParseIntError(span, _) => {
+                write!(f, "invalid group size {:?}", span.snippet())
+            }
+        }
+    }
+}
+
+impl Error for ParseConditionRecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseConditionRecordError::ParseIntError(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseConditionRecordError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseConditionRecordError::UnknownConditionType(span)
+            | ParseConditionRecordError::MissingDamagedGroups(span)
+            | ParseConditionRecordError::ParseIntError(span, _) => Some(span),
+        }
+    }
+}
+
+impl FromStr for ConditionRecord {
+    type Err = ParseConditionRecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split(' ');
+        let format_1 = split.next().unwrap();
+        let format_2 = split
+            .next()
+            .ok_or_else(|| ParseConditionRecordError::MissingDamagedGroups(Span::end_of(s)))?;
+
+        let format_1_parse_result: Result<Vec<Condition>, ParseConditionRecordError> = format_1
+            .char_indices()
+            .map(|(i, c)| match c {
+                '.' => Ok(Condition::Operational),
+                '#' => Ok(Condition::Damaged),
+                '?' => Ok(Condition::Unknown),
+                _ => Err(ParseConditionRecordError::UnknownConditionType(Span::of(
+                    &format_1[i..i + c.len_utf8()],
+                ))),
+            })
+            .collect();
+
+        let format_1 = format_1_parse_result?;
+
+        let format_2: Result<Vec<usize>, ParseConditionRecordError> = format_2
+            .split(',')
+            .map(|s| {
+                s.parse()
+                    .map_err(|error| ParseConditionRecordError::ParseIntError(Span::of(s), error))
+            })
+            .collect();
+        let format_2 = format_2?;
+
+        Ok(ConditionRecord { format_1, format_2 })
+    }
+}
+
+impl From<Condition> for char {
+    fn from(value: Condition) -> Self {
+        match value {
+            Condition::Operational => '.',
+            Condition::Damaged => '#',
+            Condition::Unknown => '?',
+        }
+    }
+}
+
+impl Display for ConditionRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_1: String = self.format_1.iter().copied().map(char::from).collect();
+
+        let format_2 = self
+            .format_2
+            .iter()
+            .map(|num| num.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        f.write_fmt(format_args!("{} {}", format_1, format_2))?;
+        Ok(())
+    }
+}
+
+pub struct Day;
+
+/// Arrangements grow so quickly with unfolding that beyond 7 copies the
+/// total no longer fits in a `usize`.
+const UNFOLD_FACTOR: Parameter = Parameter {
+    name: "unfold_factor",
+    description: "How many copies of each record make up the unfolded one in part two.",
+    default: 5,
+    range: 1..=7,
+};
+
+impl Solution for Day {
+    type Input = Vec<ConditionRecord>;
+    type Error = ParseConditionRecordError;
+
+    const PARAMETERS: &'static [Parameter] = &[UNFOLD_FACTOR];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .split('\n')
+            .filter(|line| line != &"")
+            .map(|line| line.parse())
+            .collect()
+    }
+
+    fn part_one(condition_records: &Self::Input, _: &Parameters) -> Answer {
+        let arrangements = count_arrangements(condition_records);
+
+        arrangements.into_iter().checked_sum().into()
+    }
+
+    fn part_two(condition_records: &Self::Input, parameters: &Parameters) -> Answer {
+        let unfold_factor = parameters.get(&UNFOLD_FACTOR) as usize;
+        let unfolded_condition_records: Vec<ConditionRecord> = condition_records
+            .iter()
+            .map(|condition_record| unfold_condition_record(condition_record, unfold_factor))
+            .collect();
+
+        let arrangements = count_arrangements(&unfolded_condition_records);
+
+        arrangements.into_iter().checked_sum().into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(condition_records: &Self::Input) -> Value {
+        Value::list(condition_records.iter().map(|condition_record| {
+            Value::record([
+                (
+                    "conditions",
+                    condition_record
+                        .format_1
+                        .iter()
+                        .copied()
+                        .map(char::from)
+                        .collect::<String>()
+                        .into(),
+                ),
+                ("damaged_groups", Value::list(&condition_record.format_2)),
+            ])
+        }))
+    }
+}
+
+type ArrangementCache = HashMap<(Vec<Condition>, Vec<usize>), usize>;
+
+#[cfg(not(feature = "parallel"))]
+fn count_arrangements(condition_records: &[ConditionRecord]) -> Vec<usize> {
+    let mut cache = ArrangementCache::new();
+
+    condition_records
+        .iter()
+        .map(|record| find_possible_arrangements(&mut cache, &record.format_1, &record.format_2))
+        .inspect(|arrangements| tracing::trace!(arrangements, "counted arrangements"))
+        .collect()
+}
+
+/// Each worker keeps its own cache, as records rarely share sub-problems and
+/// locking one shared cache would serialise the recursion.
+#[cfg(feature = "parallel")]
+fn count_arrangements(condition_records: &[ConditionRecord]) -> Vec<usize> {
+    condition_records
+        .par_iter()
+        .map_init(ArrangementCache::new, |cache, record| {
+            find_possible_arrangements(cache, &record.format_1, &record.format_2)
+        })
+        .inspect(|arrangements| tracing::trace!(arrangements, "counted arrangements"))
+        .collect()
+}
+
+fn unfold_condition_record(
+    condition_record: &ConditionRecord,
+    unfold_factor: usize,
+) -> ConditionRecord {
+    let mut format_1 = Vec::new();
+    let mut format_2 = Vec::new();
+
+    for i in 0..unfold_factor {
+        format_1.append(&mut condition_record.format_1.to_vec());
+        format_2.append(&mut condition_record.format_2.to_vec());
+
+        if i < unfold_factor - 1 {
+            format_1.push(Condition::Unknown);
+        }
+    }
+
+    ConditionRecord { format_1, format_2 }
+}
+
+fn find_possible_arrangements(
+    cache: &mut ArrangementCache,
+    record: &[Condition],
+    criteria: &[usize],
+) -> usize {
+    if let Some(result) = cache.get(&(record.to_vec(), criteria.to_vec())) {
+        tracing::trace!(
+            springs = record.len(),
+            groups = criteria.len(),
+            "found cached arrangements"
+        );
+        return *result;
+    }
+
+    let compressed_record = compress_record(record);
+    if compressed_record.len() != record.len() {
+        return find_and_cache(cache, &compressed_record, criteria);
+    }
+
+    let maybe_current_criteria = calculate_current_criteria(record);
+
+    match maybe_current_criteria {
+        CalculateCriteriaResult::Full(current_criteria) => {
+            if current_criteria == criteria {
+                return 1;
+            } else {
+                return 0;
+            }
+        }
+        CalculateCriteriaResult::PartialIncomplete(current_criteria) => {
+            if !current_criteria.is_empty()
+                && !criteria.is_empty()
+                && (current_criteria.len() <= criteria.len())
+            {
+                let last_index = current_criteria.len() - 1;
+                if current_criteria
+                    .iter()
+                    .zip(criteria[..last_index].iter())
+                    .any(|(a, b)| a != b)
+                {
+                    return 0;
+                }
+
+                if current_criteria[last_index] > criteria[last_index] {
+                    return 0;
+                }
+            }
+        }
+        CalculateCriteriaResult::PartialComplete(current_criteria, splitting_index) => {
+            if !current_criteria.is_empty() {
+                if current_criteria.len() > criteria.len() {
+                    return 0;
+                }
+
+                if current_criteria == criteria[..current_criteria.len()] {
+                    return find_and_cache(
+                        cache,
+                        &record[splitting_index..],
+                        &criteria[current_criteria.len()..],
+                    );
+                } else {
+                    return 0;
+                }
+            }
+        }
+    };
+
+    let unknown_index = record
+        .iter()
+        .position(|condition| *condition == Condition::Unknown)
+        .unwrap();
+
+    let mut damaged_branch = record.to_vec();
+    damaged_branch[unknown_index] = Condition::Damaged;
+
+    let mut operational_branch = record.to_vec();
+    operational_branch[unknown_index] = Condition::Operational;
+
+    find_and_cache(cache, &damaged_branch, criteria)
+        + find_and_cache(cache, &operational_branch, criteria)
+}
+
+fn find_and_cache(cache: &mut ArrangementCache, record: &[Condition], criteria: &[usize]) -> usize {
+    let result = find_possible_arrangements(cache, record, criteria);
+    cache.insert((record.to_vec(), criteria.to_vec()), result);
+    result
+}
+
+#[derive(Debug)]
+enum CalculateCriteriaResult {
+    Full(Vec<usize>),
+    PartialComplete(Vec<usize>, usize),
+    PartialIncomplete(Vec<usize>),
+}
+
+fn calculate_current_criteria(format_1: &[Condition]) -> CalculateCriteriaResult {
+    let mut criteria = Vec::new();
+    let mut num_contingous_damaged_springs = 0;
+
+    for (i, condition) in format_1.iter().enumerate() {
+        match condition {
+            Condition::Unknown => {
+                if num_contingous_damaged_springs > 0 {
+                    criteria.push(num_contingous_damaged_springs);
+                    return CalculateCriteriaResult::PartialIncomplete(criteria);
+                }
+                return CalculateCriteriaResult::PartialComplete(criteria, i);
+            }
+            Condition::Damaged => {
+                num_contingous_damaged_springs += 1;
+            }
+            Condition::Operational => {
+                if num_contingous_damaged_springs > 0 {
+                    criteria.push(num_contingous_damaged_springs);
+                    num_contingous_damaged_springs = 0;
+                }
+            }
+        }
+    }
+
+    if num_contingous_damaged_springs > 0 {
+        criteria.push(num_contingous_damaged_springs);
+    }
+
+    CalculateCriteriaResult::Full(criteria)
+}
+
+fn compress_record(record: &[Condition]) -> Vec<Condition> {
+    let mut reduced: Vec<Condition> = record.iter().fold(vec![], |mut current, next| {
+        if *next == Condition::Operational {
+            if let Some(last) = current.last() {
+                if *last == Condition::Operational {
+                    return current;
+                }
+            }
+        }
+
+        current.push(*next);
+        current
+    });
+
+    reduced = reduced
+        .into_iter()
+        .rev()
+        .skip_while(|v| *v == Condition::Operational)
+        .collect::<Vec<Condition>>()
+        .into_iter()
+        .rev()
+        .collect();
+
+    reduced
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{find_possible_arrangements, unfold_condition_record, Condition, ConditionRecord};
+
+    use proptest::prelude::*;
+
+    use std::collections::HashMap;
+
+    const CONDITION_RECORD: &str = "[.#?]{1,12} [1-9][0-9]?(,[1-9][0-9]?){0,4}";
+
+    fn damaged_groups(conditions: &[Condition]) -> Vec<usize> {
+        conditions
+            .split(|condition| *condition != Condition::Damaged)
+            .map(|group| group.len())
+            .filter(|length| *length > 0)
+            .collect()
+    }
+
+    /// Counts arrangements by trying every way of filling in the unknowns.
+    fn count_arrangements_by_brute_force(record: &ConditionRecord) -> usize {
+        let unknowns: Vec<usize> = record
+            .format_1
+            .iter()
+            .enumerate()
+            .filter(|(_, condition)| **condition == Condition::Unknown)
+            .map(|(i, _)| i)
+            .collect();
+
+        (0..1 << unknowns.len())
+            .filter(|choice| {
+                let mut conditions = record.format_1.clone();
+                for (bit, i) in unknowns.iter().enumerate() {
+                    conditions[*i] = if choice & (1 << bit) != 0 {
+                        Condition::Damaged
+                    } else {
+                        Condition::Operational
+                    };
+                }
+
+                damaged_groups(&conditions) == record.format_2
+            })
+            .count()
+    }
+
+    proptest! {
+        #[test]
+        fn display_is_the_puzzle_notation(text in CONDITION_RECORD) {
+            let record: ConditionRecord = text.parse().unwrap();
+
+            prop_assert_eq!(record.to_string(), text);
+        }
+
+        #[test]
+        fn display_parses_back(text in CONDITION_RECORD) {
+            let record: ConditionRecord = text.parse().unwrap();
+
+            prop_assert_eq!(record.to_string().parse::<ConditionRecord>().unwrap(), record);
+        }
+
+        #[test]
+        fn unfolding_repeats_the_record_five_times(text in CONDITION_RECORD) {
+            let record: ConditionRecord = text.parse().unwrap();
+            let unfolded = unfold_condition_record(&record, 5);
+
+            prop_assert_eq!(unfolded.format_1.len(), record.format_1.len() * 5 + 4);
+            prop_assert_eq!(unfolded.format_2, record.format_2.repeat(5));
+        }
+
+        #[test]
+        fn arrangements_match_brute_force(
+            text in "[.#?]{1,10} [1-4](,[1-4]){0,3}"
+        ) {
+            let record: ConditionRecord = text.parse().unwrap();
+            let arrangements =
+                find_possible_arrangements(&mut HashMap::new(), &record.format_1, &record.format_2);
+
+            prop_assert_eq!(arrangements, count_arrangements_by_brute_force(&record));
+        }
+    }
+}
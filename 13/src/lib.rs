@@ -0,0 +1,261 @@
+use common::checked::Checked;
+use common::checked::Overflow;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use grid::Grid;
+use grid::ParseGridError;
+
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Part {
+    Ash,
+    Rock,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Reflection {
+    Horizontal,
+    Vertical,
+}
+
+type Line = [Part];
+type Pattern = Grid<Part>;
+
+/// Each pattern's line of reflection, or `None` if a pattern has none.
+fn find_reflections(patterns: &[Pattern]) -> Option<Vec<(Reflection, usize)>> {
+    patterns
+        .iter()
+        .map(|pattern| find_reflection(pattern, None))
+        .inspect(|reflection| tracing::trace!(?reflection, "found reflection"))
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pattern>;
+    type Error = ParsePatternError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_patterns(input)
+    }
+
+    fn part_one(patterns: &Self::Input, _: &Parameters) -> Answer {
+        let Some(reflections) = find_reflections(patterns) else {
+            return Answer::Unavailable;
+        };
+
+        summarise(&reflections).into()
+    }
+
+    fn part_two(patterns: &Self::Input, _: &Parameters) -> Answer {
+        let Some(reflections) = find_reflections(patterns) else {
+            return Answer::Unavailable;
+        };
+
+        let Some(new_reflections) = patterns
+            .iter()
+            .zip(reflections)
+            .map(|(pattern, reflection)| find_clean_reflection(pattern, reflection))
+            .collect::<Option<Vec<(Reflection, usize)>>>()
+        else {
+            return Answer::Unavailable;
+        };
+        summarise(&new_reflections).into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(patterns: &Self::Input) -> Value {
+        Value::list(
+            patterns
+                .iter()
+                .map(|pattern| pattern.to_value(|part| char::from(*part))),
+        )
+    }
+}
+
+/// The other line of reflection once the smudge is cleaned, or `None` if
+/// cleaning no single position gives one.
+fn find_clean_reflection(
+    pattern: &Pattern,
+    reflection: (Reflection, usize),
+) -> Option<(Reflection, usize)> {
+    for position in pattern.positions() {
+        let mut smudgeless_pattern = pattern.clone();
+        let part = smudgeless_pattern
+            .get_mut(position)
+            .expect("the position should be in the pattern");
+
+        *part = match part {
+            Part::Ash => Part::Rock,
+            Part::Rock => Part::Ash,
+        };
+
+        if let Some(possible_new_reflection) =
+            find_reflection(&smudgeless_pattern, Some(reflection))
+        {
+            return Some(possible_new_reflection);
+        }
+    }
+
+    None
+}
+
+fn summarise(reflections: &[(Reflection, usize)]) -> Result<usize, Overflow> {
+    reflections
+        .iter()
+        .try_fold(0usize, |curr, (reflection, index_start)| {
+            curr.try_add(match reflection {
+                Reflection::Horizontal => 100.try_mul(*index_start)?,
+                Reflection::Vertical => *index_start,
+            })
+        })
+}
+
+fn find_reflection(
+    pattern: &Pattern,
+    skip_reflection: Option<(Reflection, usize)>,
+) -> Option<(Reflection, usize)> {
+    let transposed_pattern = pattern.transpose();
+    let maybe_vertical_reflection =
+        scan_for_reflection(&transposed_pattern, Reflection::Vertical, skip_reflection);
+    if let Some(vertical_reflection) = maybe_vertical_reflection {
+        return Some(vertical_reflection);
+    }
+
+    let maybe_horizontal_reflection =
+        scan_for_reflection(pattern, Reflection::Horizontal, skip_reflection);
+    if let Some(horizontal_reflection) = maybe_horizontal_reflection {
+        return Some(horizontal_reflection);
+    }
+
+    None
+}
+
+fn scan_for_reflection(
+    pattern: &Pattern,
+    reflection_type: Reflection,
+    skip_reflection: Option<(Reflection, usize)>,
+) -> Option<(Reflection, usize)> {
+    let pattern: Vec<&Line> = pattern.rows().collect();
+
+    for i in 0..pattern.len() - 1 {
+        let j = i + 1;
+
+        let is_potential_reflection = pattern[i] == pattern[j];
+        if is_potential_reflection {
+            let reflection_confirmation = confirm_reflection(&pattern, i);
+
+            if reflection_confirmation {
+                let found_reflection = (reflection_type, i + 1);
+
+                if let Some(skip_reflection) = skip_reflection {
+                    if skip_reflection == found_reflection {
+                        continue;
+                    }
+                }
+
+                return Some(found_reflection);
+            }
+        }
+    }
+
+    None
+}
+
+fn confirm_reflection(pattern: &[&Line], i: usize) -> bool {
+    let (mut i, mut j) = (i, i + 1);
+
+    loop {
+        let lines_match = pattern[i] == pattern[j];
+
+        if !lines_match {
+            return false;
+        }
+
+        if i == 0 || j == pattern.len() - 1 {
+            break;
+        }
+
+        i -= 1;
+        j += 1;
+    }
+
+    true
+}
+
+#[derive(Debug)]
+pub enum ParsePartError {
+    UnknownPart(char),
+}
+
+impl Display for ParsePartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePartError::UnknownPart(part) => write!(f, "unknown pattern part {:?}", part),
+        }
+    }
+}
+
+impl Error for ParsePartError {}
+
+impl From<Part> for char {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::Ash => '.',
+            Part::Rock => '#',
+        }
+    }
+}
+
+impl TryFrom<char> for Part {
+    type Error = ParsePartError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Part::Ash),
+            '#' => Ok(Part::Rock),
+            a => Err(ParsePartError::UnknownPart(a)),
+        }
+    }
+}
+
+pub type ParsePatternError = ParseGridError<ParsePartError>;
+
+fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParsePatternError> {
+    input
+        .trim_end_matches('\n')
+        .split("\n\n")
+        .map(|pattern_str| pattern_str.parse())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn has_no_answer_without_a_reflection() {
+        let patterns = Day::parse("#.\n##").unwrap();
+
+        assert_eq!(
+            Day::part_one(&patterns, &Parameters::default()),
+            Answer::Unavailable
+        );
+        assert_eq!(
+            Day::part_two(&patterns, &Parameters::default()),
+            Answer::Unavailable
+        );
+    }
+}
@@ -0,0 +1,222 @@
+mod platform;
+
+use common::checked::Checked;
+use common::checked::Overflow;
+use common::frame::Cell;
+use common::frame::Colour;
+use common::frame::Frame;
+use common::frame::Simulation;
+use common::image::Image;
+use common::image::Render;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use grid::Position;
+
+use platform::ParsePlatformError;
+use platform::Platform;
+use platform::Space;
+use platform::TiltDirection;
+use platform::TiltResult;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+pub struct Day;
+
+const NUMBER_OF_CYCLES: Parameter = Parameter {
+    name: "number_of_cycles",
+    description: "How many spin cycles the platform goes through in part two.",
+    default: 1000000000,
+    range: 0..=usize::MAX as u64,
+};
+
+impl Solution for Day {
+    type Input = Platform;
+    type Error = ParsePlatformError;
+
+    const PARAMETERS: &'static [Parameter] = &[NUMBER_OF_CYCLES];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let platform: Platform = input.parse()?;
+        tracing::debug!(
+            width = platform.get_width(),
+            height = platform.get_height(),
+            "parsed platform"
+        );
+
+        Ok(platform)
+    }
+
+    fn part_one(platform: &Self::Input, _: &Parameters) -> Answer {
+        let mut part_1_platform = platform.clone();
+
+        while part_1_platform.tilt(TiltDirection::North) == TiltResult::RocksMoved {}
+
+        calculate_total_load(&part_1_platform).into()
+    }
+
+    fn part_two(platform: &Self::Input, parameters: &Parameters) -> Answer {
+        let mut part_2_platform = platform.clone();
+
+        spin_platform(
+            &mut part_2_platform,
+            parameters.get(&NUMBER_OF_CYCLES) as usize,
+        );
+
+        calculate_total_load(&part_2_platform).into()
+    }
+}
+
+/// Spins the platform one [`Platform::tilt`] per frame, so the rocks can be
+/// seen rolling, until a spin cycle ends somewhere it has been before.
+impl Simulation for Day {
+    fn frames(platform: &Self::Input) -> Box<dyn Iterator<Item = Frame> + '_> {
+        let first_frame = draw_platform(platform).with_caption("before spinning");
+
+        Box::new(std::iter::once(first_frame).chain(SpinFrames::new(platform.clone())))
+    }
+}
+
+/// Draws the platform before it is tilted.
+impl Render for Day {
+    fn render(platform: &Self::Input, _: &Parameters) -> Image {
+        Image::Raster(draw_platform(platform))
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(platform: &Self::Input) -> Value {
+        platform.to_value(|space| char::from(*space))
+    }
+}
+
+struct SpinFrames {
+    platform: Platform,
+    cycle: usize,
+    tilt_index: usize,
+    seen: HashSet<Platform>,
+    finished: bool,
+}
+
+impl SpinFrames {
+    fn new(platform: Platform) -> SpinFrames {
+        SpinFrames {
+            seen: HashSet::from([platform.clone()]),
+            platform,
+            cycle: 1,
+            tilt_index: 0,
+            finished: false,
+        }
+    }
+}
+
+impl Iterator for SpinFrames {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let direction = TiltDirection::SPIN_CYCLE[self.tilt_index];
+
+            if self.platform.tilt(direction) == TiltResult::RocksMoved {
+                return Some(draw_platform(&self.platform).with_caption(format!(
+                    "cycle {}, tilting {}",
+                    self.cycle,
+                    direction.name()
+                )));
+            }
+
+            self.tilt_index += 1;
+            if self.tilt_index == TiltDirection::SPIN_CYCLE.len() {
+                if !self.seen.insert(self.platform.clone()) {
+                    self.finished = true;
+
+                    return Some(
+                        draw_platform(&self.platform)
+                            .with_caption(format!("cycle {} repeats an earlier one", self.cycle)),
+                    );
+                }
+
+                self.tilt_index = 0;
+                self.cycle += 1;
+            }
+        }
+
+        None
+    }
+}
+
+fn draw_platform(platform: &Platform) -> Frame {
+    platform.frame(|_, space| {
+        let symbol = char::from(*space);
+
+        match space {
+            Space::RoundedRock => Cell::coloured(symbol, Colour::Yellow),
+            Space::CubeShapedRock => Cell::coloured(symbol, Colour::Blue),
+            Space::EmptySpace => Cell::coloured(symbol, Colour::Grey),
+        }
+    })
+}
+
+#[tracing::instrument(level = "debug", skip(platform))]
+fn spin_platform(platform: &mut Platform, number_of_cycles: usize) {
+    let mut cache = HashMap::new();
+
+    let mut i = 0;
+    while i < number_of_cycles {
+        platform.spin_cycle();
+
+        if let Some(old_i) = cache.get(platform) {
+            tracing::trace!(cycle = i, repeats = old_i, "found a repeated platform");
+            let i_difference = i - old_i;
+
+            let remaining_cycles = number_of_cycles - i;
+
+            let new_i = number_of_cycles - (remaining_cycles % i_difference);
+            i = new_i
+        } else {
+            cache.insert(platform.clone(), i);
+        }
+
+        i += 1;
+    }
+}
+
+fn calculate_total_load(platform: &Platform) -> Result<u64, Overflow> {
+    let mut total_load: u64 = 0;
+
+    let platform_height = platform.get_height();
+    for y in 0..platform.get_height() {
+        for x in 0..platform.get_width() {
+            if platform.get(Position { x, y }) == Some(&Space::RoundedRock) {
+                let rows_to_south_wall = platform_height - y;
+                total_load = total_load.try_add(rows_to_south_wall as u64)?;
+            }
+        }
+    }
+
+    Ok(total_load)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn loads_more_than_fit_in_a_u32() {
+        let platform = Day::parse(&"O\n".repeat(100_000)).unwrap();
+
+        assert_eq!(
+            Day::part_one(&platform, &Parameters::default()),
+            Answer::from(5_000_050_000u64)
+        );
+    }
+}
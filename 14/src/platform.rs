@@ -1,10 +1,6 @@
-use std::{fmt::Display, str::FromStr};
+use std::{error::Error, fmt::Display, ops::Deref, str::FromStr};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-pub struct Position {
-    pub x: usize,
-    pub y: usize,
-}
+use grid::{Direction, Grid, ParseGridError, Position};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Space {
@@ -15,7 +11,27 @@ pub enum Space {
 
 #[derive(Debug)]
 pub enum ParseSpaceError {
-    Unknown,
+    Unknown(char),
+}
+
+impl Display for ParseSpaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseSpaceError::Unknown(space) => write!(f, "unknown space {:?}", space),
+        }
+    }
+}
+
+impl Error for ParseSpaceError {}
+
+impl From<Space> for char {
+    fn from(value: Space) -> Self {
+        match value {
+            Space::RoundedRock => 'O',
+            Space::CubeShapedRock => '#',
+            Space::EmptySpace => '.',
+        }
+    }
 }
 
 impl TryFrom<char> for Space {
@@ -26,7 +42,7 @@ impl TryFrom<char> for Space {
             'O' => Ok(Space::RoundedRock),
             '#' => Ok(Space::CubeShapedRock),
             '.' => Ok(Space::EmptySpace),
-            _ => Err(ParseSpaceError::Unknown),
+            _ => Err(ParseSpaceError::Unknown(value)),
         }
     }
 }
@@ -39,6 +55,35 @@ pub enum TiltDirection {
     East,
 }
 
+impl TiltDirection {
+    /// The order of the tilts in one spin cycle.
+    pub const SPIN_CYCLE: [TiltDirection; 4] = [
+        TiltDirection::North,
+        TiltDirection::West,
+        TiltDirection::South,
+        TiltDirection::East,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            TiltDirection::North => "north",
+            TiltDirection::West => "west",
+            TiltDirection::South => "south",
+            TiltDirection::East => "east",
+        }
+    }
+
+    /// The direction rocks roll in from, opposite to the way they move.
+    fn trailing_direction(self) -> Direction {
+        match self {
+            TiltDirection::North => Direction::Down,
+            TiltDirection::West => Direction::Right,
+            TiltDirection::South => Direction::Up,
+            TiltDirection::East => Direction::Left,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum TiltResult {
     RocksMoved,
@@ -47,46 +92,23 @@ pub enum TiltResult {
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Platform {
-    spaces: Vec<Space>,
-    width: usize,
-    height: usize,
+    spaces: Grid<Space>,
 }
 
-impl Platform {
-    pub fn get_width(&self) -> usize {
-        self.width
-    }
-
-    pub fn get_height(&self) -> usize {
-        self.height
-    }
-
-    fn get_index(&self, position: Position) -> usize {
-        position.y * self.width + position.x
-    }
-
-    pub fn get(&self, position: Position) -> Option<Space> {
-        if position.x >= self.width || position.y >= self.height {
-            return None;
-        }
-
-        let lookup_index = self.get_index(position);
-
-        Some(self.spaces[lookup_index])
-    }
-
-    fn swap(&mut self, position_1: Position, position_2: Position) {
-        let index_1 = self.get_index(position_1);
-        let index_2 = self.get_index(position_2);
+impl Deref for Platform {
+    type Target = Grid<Space>;
 
-        self.spaces.swap(index_1, index_2);
+    fn deref(&self) -> &Self::Target {
+        &self.spaces
     }
+}
 
+impl Platform {
     pub fn tilt(&mut self, tilt_direction: TiltDirection) -> TiltResult {
         let mut tilt_result = TiltResult::NothingMoved;
 
-        let height = self.height;
-        let width = self.width;
+        let height = self.get_height();
+        let width = self.get_width();
 
         let position_iter: Box<dyn Iterator<Item = Position>> = match tilt_direction {
             TiltDirection::North => {
@@ -108,12 +130,14 @@ impl Platform {
         };
 
         for position in position_iter {
-            if let Some(adjacent_position) = get_adjacent_position(position, tilt_direction) {
+            if let Some(adjacent_position) =
+                self.step(position, tilt_direction.trailing_direction())
+            {
                 if let (Some(space), Some(space_below)) =
                     (self.get(position), self.get(adjacent_position))
                 {
-                    if space == Space::EmptySpace && space_below == Space::RoundedRock {
-                        self.swap(position, adjacent_position);
+                    if *space == Space::EmptySpace && *space_below == Space::RoundedRock {
+                        self.spaces.swap(position, adjacent_position);
                         tilt_result = TiltResult::RocksMoved;
                     }
                 }
@@ -124,105 +148,86 @@ impl Platform {
     }
 
     pub fn spin_cycle(&mut self) {
-        for direction in &[
-            TiltDirection::North,
-            TiltDirection::West,
-            TiltDirection::South,
-            TiltDirection::East,
-        ] {
+        for direction in &TiltDirection::SPIN_CYCLE {
             while self.tilt(*direction) == TiltResult::RocksMoved {}
-            log::trace!("{:?}", direction);
-            log::trace!("{}", self);
         }
     }
 }
 
-fn get_adjacent_position(position: Position, tilt_direction: TiltDirection) -> Option<Position> {
-    match tilt_direction {
-        TiltDirection::North => Some(Position {
-            x: position.x,
-            y: position.y + 1,
-        }),
-        TiltDirection::East => {
-            if position.x == 0 {
-                return None;
-            }
-
-            Some(Position {
-                x: position.x - 1,
-                y: position.y,
-            })
-        }
-        TiltDirection::South => {
-            if position.y == 0 {
-                return None;
-            }
-
-            Some(Position {
-                x: position.x,
-                y: position.y - 1,
-            })
-        }
-        TiltDirection::West => Some(Position {
-            x: position.x + 1,
-            y: position.y,
-        }),
-    }
-}
-
-#[derive(Debug)]
-pub enum ParsePlatformError {
-    ParseSpaceError(ParseSpaceError),
-}
+pub type ParsePlatformError = ParseGridError<ParseSpaceError>;
 
 impl FromStr for Platform {
     type Err = ParsePlatformError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<String> = s
-            .split('\n')
-            .filter(|line| line != &"")
-            .map(|line| line.to_string())
-            .collect();
-        let rows: Vec<Vec<Space>> = lines
-            .iter()
-            .map(|line| {
-                line.chars()
-                    .map(Space::try_from)
-                    .collect::<Result<Vec<Space>, ParseSpaceError>>()
-            })
-            .collect::<Result<Vec<Vec<Space>>, ParseSpaceError>>()
-            .map_err(ParsePlatformError::ParseSpaceError)?;
-
-        let height = rows.len();
-        let width = rows.first().unwrap().len();
-
-        let spaces = rows.into_iter().flatten().collect();
-
-        Ok(Platform {
-            spaces,
-            width,
-            height,
-        })
+        let spaces = s.parse()?;
+
+        Ok(Platform { spaces })
     }
 }
 
 impl Display for Platform {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, space) in self.spaces.iter().enumerate() {
-            if i % self.width == 0 {
-                f.write_str("\n")?;
-            }
+        self.spaces.render(|space| char::from(*space)).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::platform::{Platform, Space, TiltDirection, TiltResult};
+
+    use grid::Direction;
+    use proptest::prelude::*;
 
-            let symbol = match *space {
-                Space::RoundedRock => "O",
-                Space::CubeShapedRock => "#",
-                Space::EmptySpace => ".",
-            };
+    const SPACES: [char; 3] = ['O', '#', '.'];
 
-            f.write_str(symbol)?;
+    fn platform() -> impl Strategy<Value = Platform> {
+        grid::strategy::text(&SPACES, 10).prop_map(|text| text.parse().unwrap())
+    }
+
+    fn count(platform: &Platform, space: Space) -> usize {
+        platform.iter().filter(|(_, cell)| **cell == space).count()
+    }
+
+    proptest! {
+        #[test]
+        fn display_is_the_puzzle_notation(text in grid::strategy::text(&SPACES, 10)) {
+            let platform: Platform = text.parse().unwrap();
+
+            prop_assert_eq!(platform.to_string(), text);
+        }
+
+        #[test]
+        fn display_parses_back(platform in platform()) {
+            prop_assert_eq!(platform.to_string().parse::<Platform>().unwrap(), platform);
+        }
+
+        #[test]
+        fn tilting_north_settles_every_rock(platform in platform()) {
+            let mut tilted = platform.clone();
+            while tilted.tilt(TiltDirection::North) == TiltResult::RocksMoved {}
+
+            prop_assert_eq!(count(&tilted, Space::RoundedRock), count(&platform, Space::RoundedRock));
+            for (position, space) in platform.iter() {
+                if *space == Space::CubeShapedRock {
+                    prop_assert_eq!(tilted.get(position), Some(&Space::CubeShapedRock));
+                }
+            }
+            for (position, space) in tilted.iter() {
+                if *space == Space::RoundedRock {
+                    let above = tilted.step(position, Direction::Up);
+                    prop_assert_ne!(above.and_then(|above| tilted.get(above)), Some(&Space::EmptySpace));
+                }
+            }
         }
 
-        Ok(())
+        #[test]
+        fn spin_cycles_keep_every_rock(platform in platform()) {
+            let mut spun = platform.clone();
+            spun.spin_cycle();
+
+            prop_assert_eq!(count(&spun, Space::RoundedRock), count(&platform, Space::RoundedRock));
+            prop_assert_eq!(count(&spun, Space::CubeShapedRock), count(&platform, Space::CubeShapedRock));
+        }
     }
 }
@@ -0,0 +1,341 @@
+use common::checked::Checked;
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+type FocalLength = u8;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Lens {
+    focal_length: FocalLength,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Remove,
+    Insert(Lens),
+}
+
+#[derive(Debug)]
+pub enum ParseOperationError {
+    InvalidOperation(Span),
+    InvalidFocalLength(Span, ParseIntError),
+}
+
+impl Display for ParseOperationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseOperationError::InvalidOperation(span) => {
+                write!(f, "invalid operation {:?}", span.snippet())
+            }
+            ParseOperationError::InvalidFocalLength(span, _) => {
+                write!(f, "invalid focal length {:?}", span.snippet())
+            }
+        }
+    }
+}
+
+impl Error for ParseOperationError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseOperationError::InvalidFocalLength(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseOperationError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseOperationError::InvalidOperation(span)
+            | ParseOperationError::InvalidFocalLength(span, _) => Some(span),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseOperationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let operation = s.chars().next();
+
+        match operation {
+            Some('-') => Ok(Operation::Remove),
+            Some('=') => {
+                let focal_length_str = &s[1..];
+                let focal_length = focal_length_str.parse().map_err(|error| {
+                    ParseOperationError::InvalidFocalLength(Span::of(focal_length_str), error)
+                })?;
+
+                let lens = Lens { focal_length };
+
+                Ok(Operation::Insert(lens))
+            }
+            _ => Err(ParseOperationError::InvalidOperation(Span::of(s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    label: String,
+    operation: Operation,
+}
+
+#[derive(Debug)]
+pub enum ParseStepError {
+    ParseOperationError(ParseOperationError),
+}
+
+impl Display for ParseStepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseStepError::ParseOperationError(_) => write!(f, "invalid step"),
+        }
+    }
+}
+
+impl Error for ParseStepError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseStepError::ParseOperationError(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseStepError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseStepError::ParseOperationError(error) => error.span(),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = ParseStepError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let opeation_index = s.find(['-', '=']).ok_or_else(|| {
+            ParseStepError::ParseOperationError(ParseOperationError::InvalidOperation(Span::of(s)))
+        })?;
+
+        let label = s[..opeation_index].to_string();
+        let operation = s[opeation_index..]
+            .parse()
+            .map_err(ParseStepError::ParseOperationError)?;
+
+        Ok(Step { label, operation })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct LabelledLens {
+    label: String,
+    lens: Lens,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Box {
+    lens_slots: Vec<LabelledLens>,
+}
+
+impl Box {
+    pub fn new() -> Box {
+        let lens_slots = Vec::new();
+        Box { lens_slots }
+    }
+}
+
+/// More boxes than this would never be used, as the HASH algorithm only
+/// gives values below 256.
+const NUMBER_OF_BOXES: Parameter = Parameter {
+    name: "number_of_boxes",
+    description: "How many boxes the lenses are arranged into in part two.",
+    default: 256,
+    range: 1..=256,
+};
+
+pub struct InitialisationSequence {
+    step_strs: Vec<String>,
+    steps: Vec<Step>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = InitialisationSequence;
+    type Error = ParseStepError;
+
+    const PARAMETERS: &'static [Parameter] = &[NUMBER_OF_BOXES];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let step_strs: Vec<&str> = input.trim_end_matches('\n').split(',').collect();
+
+        let steps: Vec<Step> = step_strs
+            .iter()
+            .map(|step_str| step_str.parse())
+            .collect::<Result<_, _>>()?;
+        tracing::debug!(steps = steps.len(), "parsed initialisation sequence");
+        let step_strs = step_strs.into_iter().map(str::to_string).collect();
+
+        Ok(InitialisationSequence { step_strs, steps })
+    }
+
+    fn part_one(sequence: &Self::Input, _: &Parameters) -> Answer {
+        sequence
+            .step_strs
+            .iter()
+            .map(|step| u64::from(holiday_ascii_string_helper(step)))
+            .checked_sum()
+            .into()
+    }
+
+    fn part_two(sequence: &Self::Input, parameters: &Parameters) -> Answer {
+        let mut boxes = vec![Box::new(); parameters.get(&NUMBER_OF_BOXES) as usize];
+
+        sequence.steps.iter().fold(&mut boxes[..], |boxes, step| {
+            holiday_ascii_string_helper_manual_arrangement_procedure(boxes, step.clone())
+        });
+
+        calculate_focusing_power(&boxes).into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(sequence: &Self::Input) -> Value {
+        Value::list(sequence.steps.iter().map(|step| {
+            let (operation, focal_length) = match step.operation {
+                Operation::Remove => ("remove", Value::Null),
+                Operation::Insert(lens) => ("insert", lens.focal_length.into()),
+            };
+
+            Value::record([
+                ("label", step.label.as_str().into()),
+                ("operation", operation.into()),
+                ("focal_length", focal_length),
+            ])
+        }))
+    }
+}
+
+fn calculate_focusing_power(boxes: &[Box]) -> Result<u64, Overflow> {
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(box_number, selected_box)| {
+            selected_box
+                .lens_slots
+                .iter()
+                .enumerate()
+                .map(move |(lens_slot, labelled_lens)| {
+                    [
+                        1 + box_number as u64,
+                        1 + lens_slot as u64,
+                        u64::from(labelled_lens.lens.focal_length),
+                    ]
+                    .into_iter()
+                    .checked_product()
+                })
+        })
+        .try_fold(0, |total, power| total.try_add(power?))
+}
+
+/// Every step is taken modulo 256, so working in a `u32` leaves room for any
+/// `char` without overflowing.
+fn holiday_ascii_string_helper(input: &str) -> u8 {
+    let hash = input.chars().fold(0, |mut current_value, c| {
+        let ascii_code = c as u32;
+        current_value += ascii_code;
+        current_value *= 17;
+        current_value %= 256;
+        current_value
+    });
+
+    hash as u8
+}
+
+fn holiday_ascii_string_helper_manual_arrangement_procedure(
+    boxes: &mut [Box],
+    step: Step,
+) -> &mut [Box] {
+    let label = step.label;
+    let operation = step.operation;
+
+    let box_index = holiday_ascii_string_helper(&label) as usize % boxes.len();
+
+    let selected_box = &mut boxes[box_index];
+
+    match operation {
+        Operation::Remove => {
+            if let Some(index) = selected_box
+                .lens_slots
+                .iter()
+                .position(|labelled_lens| labelled_lens.label == label.clone())
+            {
+                selected_box.lens_slots.remove(index);
+            }
+
+            boxes
+        }
+        Operation::Insert(lens) => {
+            let labelled_lens = LabelledLens {
+                label: label.clone(),
+                lens,
+            };
+
+            if let Some(matched_label) = selected_box
+                .lens_slots
+                .iter()
+                .position(|labelled_lens| labelled_lens.label == label.clone())
+            {
+                selected_box.lens_slots[matched_label] = labelled_lens;
+            } else {
+                selected_box.lens_slots.push(labelled_lens);
+            }
+
+            boxes
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn focusing_power_can_go_past_a_u32() {
+        let steps: Vec<String> = (0..50_000).map(|i| format!("lens{}=9", i)).collect();
+        let sequence = Day::parse(&steps.join(",")).unwrap();
+
+        assert!(matches!(
+            Day::part_two(&sequence, &Parameters::default()),
+            Answer::Unsigned(power) if power > u32::MAX.into()
+        ));
+    }
+
+    #[test]
+    fn hashes_any_character() {
+        let sequence = Day::parse("\u{10FFFF}-").unwrap();
+
+        assert_eq!(
+            Day::part_one(&sequence, &Parameters::default()),
+            Answer::from(220u64)
+        );
+    }
+}
@@ -1,10 +1,6 @@
-use std::{fmt::Display, str::FromStr};
+use std::{error::Error, fmt::Display, ops::Deref, str::FromStr};
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
-pub struct Position {
-    pub y: usize,
-    pub x: usize,
-}
+use grid::{Grid, ParseGridError};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum MirrorType {
@@ -27,7 +23,29 @@ pub enum Tile {
 
 #[derive(Debug)]
 pub enum ParseTileError {
-    Unknown,
+    Unknown(char),
+}
+
+impl Display for ParseTileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseTileError::Unknown(tile) => write!(f, "unknown tile {:?}", tile),
+        }
+    }
+}
+
+impl Error for ParseTileError {}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Mirror(MirrorType::Forward) => '/',
+            Tile::Mirror(MirrorType::Backward) => '\\',
+            Tile::Splitter(SplitterType::Horizontal) => '-',
+            Tile::Splitter(SplitterType::Vertical) => '|',
+            Tile::EmptySpace => '.',
+        }
+    }
 }
 
 impl TryFrom<char> for Tile {
@@ -40,97 +58,38 @@ impl TryFrom<char> for Tile {
             '/' => Ok(Tile::Mirror(MirrorType::Forward)),
             '\\' => Ok(Tile::Mirror(MirrorType::Backward)),
             '.' => Ok(Tile::EmptySpace),
-            _ => Err(ParseTileError::Unknown),
+            _ => Err(ParseTileError::Unknown(value)),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Contraption {
-    tiles: Vec<Tile>,
-    width: usize,
-    height: usize,
+    tiles: Grid<Tile>,
 }
 
-impl Contraption {
-    pub fn get_width(&self) -> usize {
-        self.width
-    }
+impl Deref for Contraption {
+    type Target = Grid<Tile>;
 
-    pub fn get_height(&self) -> usize {
-        self.height
-    }
-
-    fn get_index(&self, position: Position) -> usize {
-        position.y * self.width + position.x
-    }
-
-    pub fn get(&self, position: Position) -> Option<Tile> {
-        if position.x >= self.width || position.y >= self.height {
-            return None;
-        }
-
-        let lookup_index = self.get_index(position);
-
-        Some(self.tiles[lookup_index])
+    fn deref(&self) -> &Self::Target {
+        &self.tiles
     }
 }
 
-#[derive(Debug)]
-pub enum ParseContraptionError {
-    ParseTileError(ParseTileError),
-}
+pub type ParseContraptionError = ParseGridError<ParseTileError>;
 
 impl FromStr for Contraption {
     type Err = ParseContraptionError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<String> = s
-            .split('\n')
-            .filter(|line| line != &"")
-            .map(|line| line.to_string())
-            .collect();
-        let rows: Vec<Vec<Tile>> = lines
-            .iter()
-            .map(|line| {
-                line.chars()
-                    .map(Tile::try_from)
-                    .collect::<Result<Vec<Tile>, ParseTileError>>()
-            })
-            .collect::<Result<Vec<Vec<Tile>>, ParseTileError>>()
-            .map_err(ParseContraptionError::ParseTileError)?;
-
-        let height = rows.len();
-        let width = rows.first().unwrap().len();
-
-        let tiles = rows.into_iter().flatten().collect();
-
-        Ok(Contraption {
-            tiles,
-            width,
-            height,
-        })
+        let tiles = s.parse()?;
+
+        Ok(Contraption { tiles })
     }
 }
 
 impl Display for Contraption {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, tile) in self.tiles.iter().enumerate() {
-            if i % self.width == 0 {
-                f.write_str("\n")?;
-            }
-
-            let symbol = match *tile {
-                Tile::Mirror(MirrorType::Forward) => '/',
-                Tile::Mirror(MirrorType::Backward) => '\\',
-                Tile::Splitter(SplitterType::Horizontal) => '-',
-                Tile::Splitter(SplitterType::Vertical) => '|',
-                Tile::EmptySpace => '.',
-            };
-
-            f.write_str(&symbol.to_string())?;
-        }
-
-        Ok(())
+        self.tiles.render(|tile| char::from(*tile)).fmt(f)
     }
 }
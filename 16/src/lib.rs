@@ -0,0 +1,318 @@
+mod contraption;
+
+use common::frame::Cell;
+use common::frame::Colour;
+use common::frame::Frame;
+use common::frame::Simulation;
+use common::image::Image;
+use common::image::Render;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use grid::Direction;
+use grid::Position;
+
+use contraption::Contraption;
+use contraption::MirrorType;
+use contraption::ParseContraptionError;
+use contraption::SplitterType;
+use contraption::Tile;
+
+use std::collections::HashSet;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+struct Beam {
+    position: Position,
+    direction: Direction,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Contraption;
+    type Error = ParseContraptionError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input.parse()
+    }
+
+    fn part_one(contraption: &Self::Input, _: &Parameters) -> Answer {
+        let start_position = Position { x: 0, y: 0 };
+        let start_direction = Direction::Right;
+        let start_beam = Beam {
+            position: start_position,
+            direction: start_direction,
+        };
+
+        let energised_tile_positions = simulate_beam_through_contraption(contraption, start_beam);
+        energised_tile_positions.len().into()
+    }
+
+    fn part_two(contraption: &Self::Input, _: &Parameters) -> Answer {
+        let top_edges = [
+            (0, Direction::Down),
+            (contraption.get_height() - 1, Direction::Up),
+        ];
+        let top_edge_iter = top_edges.iter().flat_map(|(y, direction)| {
+            (0..contraption.get_width()).map(move |x| Beam {
+                position: Position { x, y: *y },
+                direction: *direction,
+            })
+        });
+
+        let side_edges = [
+            (0, Direction::Right),
+            (contraption.get_width() - 1, Direction::Left),
+        ];
+        // The corners can send a beam sideways too, which is how part one
+        // starts.
+        let side_edge_iter = side_edges.iter().flat_map(|(x, direction)| {
+            (0..contraption.get_height()).map(move |y| Beam {
+                position: Position { x: *x, y },
+                direction: *direction,
+            })
+        });
+
+        let start_beams: Vec<Beam> = top_edge_iter.chain(side_edge_iter).collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let start_beams = start_beams.into_iter();
+        #[cfg(feature = "parallel")]
+        let start_beams = start_beams.into_par_iter();
+
+        start_beams
+            .map(|beam| simulate_beam_through_contraption(contraption, beam).len())
+            .max()
+            .unwrap()
+            .into()
+    }
+}
+
+/// Follows the beam from the top-left corner, moving every front of the beam
+/// one tile per frame.
+impl Simulation for Day {
+    fn frames(contraption: &Self::Input) -> Box<dyn Iterator<Item = Frame> + '_> {
+        let start = Beam {
+            position: Position { x: 0, y: 0 },
+            direction: Direction::Right,
+        };
+
+        let mut seen = HashSet::from([start]);
+        let mut energised = HashSet::from([start.position]);
+        let mut fronts = vec![start];
+        let mut step = 0;
+
+        Box::new(std::iter::from_fn(move || {
+            if fronts.is_empty() {
+                return None;
+            }
+
+            step += 1;
+            let frame = draw_beams(contraption, &energised, &fronts).with_caption(format!(
+                "step {}, {} tiles energised",
+                step,
+                energised.len()
+            ));
+
+            fronts = fronts
+                .iter()
+                .filter_map(|beam| {
+                    let tile = *contraption.get(beam.position)?;
+                    Some(get_next_beams(contraption, *beam, tile))
+                })
+                .flatten()
+                .filter(|beam| seen.insert(*beam))
+                .collect();
+            energised.extend(fronts.iter().map(|beam| beam.position));
+
+            Some(frame)
+        }))
+    }
+}
+
+/// Lights up the tiles energised by the part one beam, which enters in the
+/// top-left corner heading right.
+impl Render for Day {
+    fn render(contraption: &Self::Input, _: &Parameters) -> Image {
+        let start = Beam {
+            position: Position { x: 0, y: 0 },
+            direction: Direction::Right,
+        };
+        let energised = simulate_beam_through_contraption(contraption, start);
+
+        Image::Raster(contraption.frame(|position, tile| {
+            let symbol = char::from(*tile);
+
+            if *tile != Tile::EmptySpace {
+                Cell::coloured(symbol, Colour::Blue)
+            } else if energised.contains(&position) {
+                Cell::coloured(symbol, Colour::Yellow)
+            } else {
+                Cell::new(symbol)
+            }
+        }))
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(contraption: &Self::Input) -> Value {
+        contraption.to_value(|tile| char::from(*tile))
+    }
+}
+
+fn draw_beams(contraption: &Contraption, energised: &HashSet<Position>, fronts: &[Beam]) -> Frame {
+    contraption.frame(|position, tile| {
+        if let Some(beam) = fronts.iter().find(|beam| beam.position == position) {
+            return Cell::coloured(beam.direction.arrow(), Colour::Red);
+        }
+
+        let symbol = char::from(*tile);
+        if energised.contains(&position) {
+            Cell::coloured(symbol, Colour::Yellow)
+        } else {
+            Cell::coloured(symbol, Colour::Grey)
+        }
+    })
+}
+
+fn simulate_beam_through_contraption(contraption: &Contraption, start: Beam) -> HashSet<Position> {
+    let mut distinct_beam_directions: HashSet<Beam> = HashSet::new();
+
+    let mut current_beams = vec![start];
+
+    while let Some(beam) = current_beams.pop() {
+        if let Some(&tile) = contraption.get(beam.position) {
+            distinct_beam_directions.insert(beam);
+
+            let next_beams = get_next_beams(contraption, beam, tile);
+
+            for next_beam in next_beams {
+                if !distinct_beam_directions.contains(&next_beam) {
+                    current_beams.push(next_beam);
+                }
+            }
+        }
+    }
+
+    distinct_beam_directions
+        .into_iter()
+        .map(|beam| beam.position)
+        .collect()
+}
+
+fn get_next_beams(contraption: &Contraption, beam: Beam, tile: Tile) -> Vec<Beam> {
+    let next_directions = match (beam.direction, tile) {
+        (Direction::Up | Direction::Down, Tile::Splitter(SplitterType::Horizontal)) => {
+            vec![Direction::Left, Direction::Right]
+        }
+        (Direction::Left | Direction::Right, Tile::Splitter(SplitterType::Vertical)) => {
+            vec![Direction::Up, Direction::Down]
+        }
+        (Direction::Up, Tile::Mirror(MirrorType::Forward)) => vec![Direction::Right],
+        (Direction::Up, Tile::Mirror(MirrorType::Backward)) => vec![Direction::Left],
+        (Direction::Down, Tile::Mirror(MirrorType::Forward)) => vec![Direction::Left],
+        (Direction::Down, Tile::Mirror(MirrorType::Backward)) => vec![Direction::Right],
+        (Direction::Left, Tile::Mirror(MirrorType::Forward)) => vec![Direction::Down],
+        (Direction::Left, Tile::Mirror(MirrorType::Backward)) => vec![Direction::Up],
+        (Direction::Right, Tile::Mirror(MirrorType::Forward)) => vec![Direction::Up],
+        (Direction::Right, Tile::Mirror(MirrorType::Backward)) => vec![Direction::Down],
+        (direction, _) => vec![direction],
+    };
+
+    next_directions
+        .into_iter()
+        .map(|direction| (contraption.step(beam.position, direction), direction))
+        .filter(|(maybe_position, _)| maybe_position.is_some())
+        .map(|(position, direction)| Beam {
+            position: position.unwrap(),
+            direction,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{simulate_beam_through_contraption, Beam, Contraption, Day};
+
+    use common::frame::Simulation;
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+    use grid::Direction;
+    use grid::Position;
+    use proptest::prelude::*;
+
+    const TILES: [char; 5] = ['|', '-', '/', '\\', '.'];
+
+    fn contraption() -> impl Strategy<Value = Contraption> {
+        grid::strategy::text(&TILES, 10).prop_map(|text| text.parse().unwrap())
+    }
+
+    proptest! {
+        #[test]
+        fn display_is_the_puzzle_notation(text in grid::strategy::text(&TILES, 10)) {
+            let contraption: Contraption = text.parse().unwrap();
+
+            prop_assert_eq!(contraption.to_string(), text);
+        }
+
+        #[test]
+        fn display_parses_back(contraption in contraption()) {
+            prop_assert_eq!(contraption.to_string().parse::<Contraption>().unwrap(), contraption);
+        }
+
+        #[test]
+        fn beams_stay_inside_the_contraption(contraption in contraption()) {
+            let start = Beam {
+                position: Position { x: 0, y: 0 },
+                direction: Direction::Right,
+            };
+            let energised = simulate_beam_through_contraption(&contraption, start);
+
+            prop_assert!(energised.contains(&start.position));
+            prop_assert!(energised.iter().all(|position| contraption.contains(*position)));
+        }
+
+        #[test]
+        fn frames_end_with_every_tile_energised(contraption in contraption()) {
+            let start = Beam {
+                position: Position { x: 0, y: 0 },
+                direction: Direction::Right,
+            };
+            let energised = simulate_beam_through_contraption(&contraption, start).len();
+
+            let last_frame = Day::frames(&contraption).last().unwrap();
+
+            let expected_caption = format!(", {} tiles energised", energised);
+            prop_assert!(last_frame.caption().ends_with(&expected_caption));
+        }
+
+        #[test]
+        fn part_two_energises_at_least_as_many_tiles_as_part_one(contraption in contraption()) {
+            let Answer::Unsigned(part_one) = Day::part_one(&contraption, &Parameters::default()) else {
+                panic!("part one should always have an answer");
+            };
+            let Answer::Unsigned(part_two) = Day::part_two(&contraption, &Parameters::default()) else {
+                panic!("part two should always have an answer");
+            };
+
+            prop_assert!(part_two >= part_one);
+        }
+    }
+
+    #[test]
+    fn starts_beams_along_a_single_row() {
+        let contraption = Day::parse(".").unwrap();
+
+        assert_eq!(
+            Day::part_two(&contraption, &Parameters::default()),
+            Answer::from(1usize)
+        );
+    }
+}
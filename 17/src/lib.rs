@@ -0,0 +1,435 @@
+mod heat_loss_map;
+
+use common::frame::Cell;
+use common::frame::Colour;
+use common::frame::Frame;
+use common::frame::Simulation;
+use common::image::Image;
+use common::image::Render;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use graph::Graph;
+use graph::ShortestPaths;
+
+use grid::Direction;
+use grid::Position;
+
+use heat_loss_map::HeatLossAmount;
+use heat_loss_map::HeatLossMap;
+use heat_loss_map::ParseHeatLossMapError;
+
+use std::collections::HashSet;
+
+const PART_1_MIN_BLOCKS_STRAIGHT: Parameter = Parameter {
+    name: "part_one_min_blocks_straight",
+    description:
+        "How far the crucible has to move in a straight line before it can turn in part one.",
+    default: 0,
+    range: 0..=254,
+};
+
+const PART_1_MAX_BLOCKS_STRAIGHT: Parameter = Parameter {
+    name: "part_one_max_blocks_straight",
+    description: "The furthest the crucible can move in a straight line in part one.",
+    default: 3,
+    range: 1..=254,
+};
+
+const PART_2_MIN_BLOCKS_STRAIGHT: Parameter = Parameter {
+    name: "part_two_min_blocks_straight",
+    description:
+        "How far the ultra crucible has to move in a straight line before it can turn in part two.",
+    default: 4,
+    range: 0..=254,
+};
+
+const PART_2_MAX_BLOCKS_STRAIGHT: Parameter = Parameter {
+    name: "part_two_max_blocks_straight",
+    description: "The furthest the ultra crucible can move in a straight line in part two.",
+    default: 10,
+    range: 1..=254,
+};
+
+/// Reads a crucible's limits for the search, which stops a crucible before
+/// it reaches the maximum rather than after.
+fn get_blocks_straight(parameters: &Parameters, min: &Parameter, max: &Parameter) -> (u8, u8) {
+    (parameters.get(min) as u8, parameters.get(max) as u8 + 1)
+}
+
+const LAVA_POOL_POSITION: Position = Position { x: 0, y: 0 };
+
+fn get_machine_parts_factory_position(heat_loss_map: &HeatLossMap) -> Position {
+    Position {
+        x: heat_loss_map.get_width() - 1,
+        y: heat_loss_map.get_height() - 1,
+    }
+}
+
+/// `None` if the crucible can't reach the factory, like when it has to go
+/// further before turning than it is able to.
+fn find_minimum_heat_loss(
+    heat_loss_map: &HeatLossMap,
+    min_blocks_straight: u8,
+    max_blocks_straight: u8,
+) -> Option<HeatLossAmount> {
+    shortest_path(
+        heat_loss_map,
+        LAVA_POOL_POSITION,
+        get_machine_parts_factory_position(heat_loss_map),
+        min_blocks_straight,
+        max_blocks_straight,
+    )
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HeatLossMap;
+    type Error = ParseHeatLossMapError;
+
+    const PARAMETERS: &'static [Parameter] = &[
+        PART_1_MIN_BLOCKS_STRAIGHT,
+        PART_1_MAX_BLOCKS_STRAIGHT,
+        PART_2_MIN_BLOCKS_STRAIGHT,
+        PART_2_MAX_BLOCKS_STRAIGHT,
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input.parse()
+    }
+
+    fn part_one(heat_loss_map: &Self::Input, parameters: &Parameters) -> Answer {
+        let (min_blocks_straight, max_blocks_straight) = get_blocks_straight(
+            parameters,
+            &PART_1_MIN_BLOCKS_STRAIGHT,
+            &PART_1_MAX_BLOCKS_STRAIGHT,
+        );
+
+        find_minimum_heat_loss(heat_loss_map, min_blocks_straight, max_blocks_straight).into()
+    }
+
+    fn part_two(heat_loss_map: &Self::Input, parameters: &Parameters) -> Answer {
+        let (min_blocks_straight, max_blocks_straight) = get_blocks_straight(
+            parameters,
+            &PART_2_MIN_BLOCKS_STRAIGHT,
+            &PART_2_MAX_BLOCKS_STRAIGHT,
+        );
+
+        find_minimum_heat_loss(heat_loss_map, min_blocks_straight, max_blocks_straight).into()
+    }
+}
+
+/// Watches the part one search spread out from the lava pool, one frame per
+/// unit of heat loss, until it reaches the machine parts factory.
+impl Simulation for Day {
+    fn frames(heat_loss_map: &Self::Input) -> Box<dyn Iterator<Item = Frame> + '_> {
+        let goal = get_machine_parts_factory_position(heat_loss_map);
+        let (min_blocks_straight, max_blocks_straight) = get_blocks_straight(
+            &Parameters::default(),
+            &PART_1_MIN_BLOCKS_STRAIGHT,
+            &PART_1_MAX_BLOCKS_STRAIGHT,
+        );
+        let crucible = Crucible {
+            heat_loss_map,
+            min_blocks_straight,
+            max_blocks_straight,
+        };
+        let is_goal = move |state: &State| crucible.is_goal(state, goal);
+        let mut search = ShortestPaths::dijkstra(crucible, Crucible::start(LAVA_POOL_POSITION));
+        let mut settled = HashSet::new();
+        let mut finished = false;
+
+        Box::new(std::iter::from_fn(move || {
+            if finished {
+                return None;
+            }
+
+            let heat_loss = search.peek_priority()?;
+
+            while search.peek_priority() == Some(heat_loss) {
+                let Some((state, total_heat_loss)) = search.next() else {
+                    break;
+                };
+                settled.insert(state.position);
+
+                if is_goal(&state) {
+                    finished = true;
+
+                    return Some(draw_search(heat_loss_map, &search, &settled).with_caption(
+                        format!(
+                            "reached the factory with a heat loss of {}",
+                            total_heat_loss
+                        ),
+                    ));
+                }
+            }
+
+            Some(
+                draw_search(heat_loss_map, &search, &settled)
+                    .with_caption(format!("heat loss {}", heat_loss)),
+            )
+        }))
+    }
+}
+
+/// Shades each block from dark blue for the least heat loss to bright red
+/// for the most.
+impl Render for Day {
+    fn render(heat_loss_map: &Self::Input, _: &Parameters) -> Image {
+        Image::Raster(heat_loss_map.frame(|_, heat_loss| {
+            let symbol = char::from_digit(*heat_loss, 10).unwrap_or('?');
+            let heat = (heat_loss.min(&9) * 255 / 9) as u8;
+
+            Cell::coloured(symbol, Colour::Rgb(heat, 0, 255 - heat))
+        }))
+    }
+}
+
+/// The heat loss of every block, row by row.
+impl Inspect for Day {
+    fn inspect(heat_loss_map: &Self::Input) -> Value {
+        Value::list(heat_loss_map.rows().map(Value::list))
+    }
+}
+
+fn draw_search(
+    heat_loss_map: &HeatLossMap,
+    search: &ShortestPaths<Crucible>,
+    settled: &HashSet<Position>,
+) -> Frame {
+    let frontier: HashSet<Position> = search.frontier().map(|state| state.position).collect();
+
+    heat_loss_map.frame(|position, heat_loss| {
+        let symbol = char::from_digit(*heat_loss, 10).unwrap_or('?');
+
+        if frontier.contains(&position) {
+            Cell::coloured(symbol, Colour::Red)
+        } else if settled.contains(&position) {
+            Cell::coloured(symbol, Colour::Yellow)
+        } else {
+            Cell::coloured(symbol, Colour::Grey)
+        }
+    })
+}
+
+/// Where the crucible is, which way it is heading and how far it has gone
+/// that way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: Position,
+    direction: Option<Direction>,
+    distance_in_current_direction: u8,
+}
+
+/// Every state a crucible can be in on the map, with each move costing the
+/// heat lost in the block it enters.
+#[derive(Clone, Copy)]
+struct Crucible<'a> {
+    heat_loss_map: &'a HeatLossMap,
+    min_blocks_straight: u8,
+    max_blocks_straight: u8,
+}
+
+impl Crucible<'_> {
+    fn start(position: Position) -> State {
+        State {
+            position,
+            direction: None,
+            distance_in_current_direction: 0,
+        }
+    }
+
+    /// Whether the crucible can stop at `goal` in this state.
+    fn is_goal(&self, state: &State, goal: Position) -> bool {
+        state.position == goal && state.distance_in_current_direction >= self.min_blocks_straight
+    }
+}
+
+impl Graph for Crucible<'_> {
+    type Node = State;
+    type Cost = HeatLossAmount;
+
+    fn neighbours(&self, state: &State) -> Vec<(State, HeatLossAmount)> {
+        get_next_states(
+            self.heat_loss_map,
+            *state,
+            self.min_blocks_straight,
+            self.max_blocks_straight,
+        )
+    }
+}
+
+fn shortest_path(
+    heat_loss_map: &HeatLossMap,
+    start: Position,
+    goal: Position,
+    min_blocks_straight: u8,
+    max_blocks_straight: u8,
+) -> Option<HeatLossAmount> {
+    let crucible = Crucible {
+        heat_loss_map,
+        min_blocks_straight,
+        max_blocks_straight,
+    };
+
+    let path = graph::dijkstra(&crucible, Crucible::start(start), |state| {
+        crucible.is_goal(state, goal)
+    })?;
+
+    Some(path.cost)
+}
+
+fn get_next_states(
+    heat_loss_map: &HeatLossMap,
+    state: State,
+    min_blocks_straight: u8,
+    max_blocks_straight: u8,
+) -> Vec<(State, HeatLossAmount)> {
+    let next_turns = match state.direction {
+        Some(Direction::Up) => vec![
+            (Direction::Left, false),
+            (Direction::Up, true),
+            (Direction::Right, false),
+        ],
+        Some(Direction::Left) => vec![
+            (Direction::Down, false),
+            (Direction::Left, true),
+            (Direction::Up, false),
+        ],
+        Some(Direction::Down) => vec![
+            (Direction::Right, false),
+            (Direction::Down, true),
+            (Direction::Left, false),
+        ],
+        Some(Direction::Right) => vec![
+            (Direction::Down, false),
+            (Direction::Right, true),
+            (Direction::Up, false),
+        ],
+        None => vec![
+            (Direction::Left, true),
+            (Direction::Up, true),
+            (Direction::Right, true),
+            (Direction::Down, true),
+        ],
+    };
+
+    let mut new_states = Vec::new();
+
+    for (turn, is_straight) in next_turns {
+        if !is_straight && (state.distance_in_current_direction < min_blocks_straight) {
+            continue;
+        }
+
+        if let Some(position) = heat_loss_map.step(state.position, turn) {
+            if let Some(heat_loss) = heat_loss_map.get(position) {
+                let direction = Some(turn);
+                let distance_in_current_direction = if state.direction.is_none() || is_straight {
+                    state.distance_in_current_direction + 1
+                } else {
+                    1
+                };
+
+                if distance_in_current_direction >= max_blocks_straight {
+                    continue;
+                }
+
+                let new_state = State {
+                    position,
+                    direction,
+                    distance_in_current_direction,
+                };
+
+                new_states.push((new_state, *heat_loss));
+            };
+        }
+    }
+
+    new_states
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{shortest_path, Day, HeatLossMap};
+
+    use common::frame::Simulation;
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+    use grid::Position;
+    use proptest::prelude::*;
+
+    const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+    proptest! {
+        #[test]
+        fn display_is_the_puzzle_notation(text in grid::strategy::text(&DIGITS, 10)) {
+            let heat_loss_map: HeatLossMap = text.parse().unwrap();
+
+            prop_assert_eq!(heat_loss_map.to_string(), text);
+        }
+
+        #[test]
+        fn display_parses_back(text in grid::strategy::text(&DIGITS, 10)) {
+            let heat_loss_map: HeatLossMap = text.parse().unwrap();
+
+            prop_assert_eq!(heat_loss_map.to_string().parse::<HeatLossMap>().unwrap(), heat_loss_map);
+        }
+
+        #[test]
+        fn every_block_entered_loses_heat(text in grid::strategy::text(&DIGITS[1..], 6)) {
+            let heat_loss_map: HeatLossMap = text.parse().unwrap();
+            let end = Position {
+                x: heat_loss_map.get_width() - 1,
+                y: heat_loss_map.get_height() - 1,
+            };
+
+            if let Some(heat_loss) = shortest_path(&heat_loss_map, Position { x: 0, y: 0 }, end, 0, 4) {
+                prop_assert!(heat_loss as usize >= end.x + end.y);
+            }
+        }
+
+        #[test]
+        fn frames_end_at_the_shortest_path(text in grid::strategy::text(&DIGITS[1..], 6)) {
+            let heat_loss_map: HeatLossMap = text.parse().unwrap();
+            let end = Position {
+                x: heat_loss_map.get_width() - 1,
+                y: heat_loss_map.get_height() - 1,
+            };
+
+            let last_frame = Day::frames(&heat_loss_map).last().unwrap();
+
+            if let Some(heat_loss) = shortest_path(&heat_loss_map, Position { x: 0, y: 0 }, end, 0, 4) {
+                prop_assert_eq!(
+                    last_frame.caption(),
+                    format!("reached the factory with a heat loss of {}", heat_loss)
+                );
+            } else {
+                prop_assert!(!last_frame.caption().starts_with("reached"));
+            }
+        }
+    }
+
+    #[test]
+    fn has_no_answer_when_the_crucible_cannot_reach_the_factory() {
+        let heat_loss_map = Day::parse("1").unwrap();
+        let mut parameters = Parameters::new(Day::PARAMETERS);
+        parameters
+            .set_str("part_one_min_blocks_straight", "5")
+            .unwrap();
+
+        assert_eq!(
+            Day::part_one(&heat_loss_map, &parameters),
+            Answer::Unavailable
+        );
+        assert_eq!(
+            Day::part_two(&heat_loss_map, &Parameters::new(Day::PARAMETERS)),
+            Answer::Unavailable
+        );
+    }
+}
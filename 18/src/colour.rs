@@ -1,4 +1,6 @@
-use std::{num::ParseIntError, str::FromStr};
+use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+use common::diagnostic::{ParseError, Span};
 
 #[derive(Debug, Clone, Copy)]
 pub(super) enum Colour {
@@ -9,24 +11,88 @@ impl FromStr for Colour {
     type Err = ParseColourError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hex = &s[2..s.len() - 1];
+        let hex = s
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6 && hex.is_ascii())
+            .ok_or_else(|| ParseColourError::InvalidFormat(Span::of(s)))?;
 
         const HEX_RADIX: u32 = 16;
 
-        let red =
-            u8::from_str_radix(&hex[0..2], HEX_RADIX).map_err(ParseColourError::InvalidRed)?;
-        let green =
-            u8::from_str_radix(&hex[2..4], HEX_RADIX).map_err(ParseColourError::InvalidGreen)?;
-        let blue =
-            u8::from_str_radix(&hex[4..6], HEX_RADIX).map_err(ParseColourError::InvalidBlue)?;
+        let parse_channel = |channel: &str, error: fn(Span, ParseIntError) -> ParseColourError| {
+            u8::from_str_radix(channel, HEX_RADIX)
+                .map_err(|source| error(Span::of(channel), source))
+        };
+
+        let red = parse_channel(&hex[0..2], ParseColourError::InvalidRed)?;
+        let green = parse_channel(&hex[2..4], ParseColourError::InvalidGreen)?;
+        let blue = parse_channel(&hex[4..6], ParseColourError::InvalidBlue)?;
+
+        // Part two reads the last digit as the direction to dig in.
+        if !hex.ends_with(['0', '1', '2', '3']) {
+            return Err(ParseColourError::InvalidDirection(Span::of(&hex[5..])));
+        }
 
         Ok(Colour::RGB(red, green, blue))
     }
 }
 
 #[derive(Debug)]
-pub(super) enum ParseColourError {
-    InvalidRed(ParseIntError),
-    InvalidGreen(ParseIntError),
-    InvalidBlue(ParseIntError),
+pub enum ParseColourError {
+    InvalidFormat(Span),
+    InvalidRed(Span, ParseIntError),
+    InvalidGreen(Span, ParseIntError),
+    InvalidBlue(Span, ParseIntError),
+    InvalidDirection(Span),
+}
+
+impl Display for ParseColourError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseColourError::InvalidFormat(span) => {
+                write!(
+                    f,
+                    "expected a colour like (#70c710), found {:?}",
+                    span.snippet()
+                )
+            }
+            ParseColourError::InvalidRed(span, _) => write!(f, "invalid red {:?}", span.snippet()),
+            ParseColourError::InvalidGreen(span, _) => {
+                write!(f, "invalid green {:?}", span.snippet())
+            }
+            ParseColourError::InvalidBlue(span, _) => {
+                write!(f, "invalid blue {:?}", span.snippet())
+            }
+            ParseColourError::InvalidDirection(span) => {
+                write!(
+                    f,
+                    "expected the last digit to be a direction from 0 to 3, found {:?}",
+                    span.snippet()
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseColourError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseColourError::InvalidFormat(_) | ParseColourError::InvalidDirection(_) => None,
+            ParseColourError::InvalidRed(_, error)
+            | ParseColourError::InvalidGreen(_, error)
+            | ParseColourError::InvalidBlue(_, error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseColourError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseColourError::InvalidFormat(span)
+            | ParseColourError::InvalidDirection(span)
+            | ParseColourError::InvalidRed(span, _)
+            | ParseColourError::InvalidGreen(span, _)
+            | ParseColourError::InvalidBlue(span, _) => Some(span),
+        }
+    }
 }
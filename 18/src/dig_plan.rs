@@ -1,10 +1,15 @@
+use std::error::Error;
+use std::fmt::Display;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+
 use crate::colour::{Colour, ParseColourError};
 
 #[derive(Debug)]
-pub(super) struct DigPlan {
+pub struct DigPlan {
     pub(super) instructions: Vec<DigInstruction>,
 }
 
@@ -14,6 +19,7 @@ impl FromStr for DigPlan {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let instructions = s
             .split('\n')
+            .filter(|line| !line.is_empty())
             .map(|line| line.parse())
             .collect::<Result<Vec<DigInstruction>, ParseDigInstructionError>>()
             .map_err(ParseDigPlanError::ParseDigInstructionError)?;
@@ -22,10 +28,34 @@ impl FromStr for DigPlan {
 }
 
 #[derive(Debug)]
-pub(super) enum ParseDigPlanError {
+pub enum ParseDigPlanError {
     ParseDigInstructionError(ParseDigInstructionError),
 }
 
+impl Display for ParseDigPlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDigPlanError::ParseDigInstructionError(_) => write!(f, "invalid dig instruction"),
+        }
+    }
+}
+
+impl Error for ParseDigPlanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDigPlanError::ParseDigInstructionError(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseDigPlanError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseDigPlanError::ParseDigInstructionError(error) => error.span(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(super) struct DigInstruction {
     pub(super) direction: Direction,
@@ -41,14 +71,14 @@ impl FromStr for DigInstruction {
             .split(' ')
             .collect::<Vec<&str>>()
             .try_into()
-            .map_err(|_| ParseDigInstructionError::InvalidDigInstructionFormat)?;
-
-        let direction = split[0]
-            .parse()
-            .map_err(ParseDigInstructionError::InvalidDirection)?;
-        let amount = split[1]
-            .parse()
-            .map_err(ParseDigInstructionError::InvalidDigAmount)?;
+            .map_err(|_| ParseDigInstructionError::InvalidDigInstructionFormat(Span::of(s)))?;
+
+        let direction = split[0].parse().map_err(|error| {
+            ParseDigInstructionError::InvalidDirection(Span::of(split[0]), error)
+        })?;
+        let amount = split[1].parse().map_err(|error| {
+            ParseDigInstructionError::InvalidDigAmount(Span::of(split[1]), error)
+        })?;
         let colour = split[2]
             .parse()
             .map_err(ParseDigInstructionError::InvalidColour)?;
@@ -62,13 +92,53 @@ impl FromStr for DigInstruction {
 }
 
 #[derive(Debug)]
-pub(super) enum ParseDigInstructionError {
-    InvalidDigInstructionFormat,
-    InvalidDirection(ParseDirectionError),
-    InvalidDigAmount(ParseIntError),
+pub enum ParseDigInstructionError {
+    InvalidDigInstructionFormat(Span),
+    InvalidDirection(Span, ParseDirectionError),
+    InvalidDigAmount(Span, ParseIntError),
     InvalidColour(ParseColourError),
 }
 
+impl Display for ParseDigInstructionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDigInstructionError::InvalidDigInstructionFormat(_) => write!(
+                f,
+                "expected a direction, amount and colour separated by spaces"
+            ),
+            ParseDigInstructionError::InvalidDirection(span, _) => {
+                write!(f, "invalid direction {:?}", span.snippet())
+            }
+            ParseDigInstructionError::InvalidDigAmount(span, _) => {
+                write!(f, "invalid dig amount {:?}", span.snippet())
+            }
+            ParseDigInstructionError::InvalidColour(_) => write!(f, "invalid colour"),
+        }
+    }
+}
+
+impl Error for ParseDigInstructionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDigInstructionError::InvalidDigInstructionFormat(_) => None,
+            ParseDigInstructionError::InvalidDirection(_, error) => Some(error),
+            ParseDigInstructionError::InvalidDigAmount(_, error) => Some(error),
+            ParseDigInstructionError::InvalidColour(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseDigInstructionError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseDigInstructionError::InvalidDigInstructionFormat(span)
+            | ParseDigInstructionError::InvalidDirection(span, _)
+            | ParseDigInstructionError::InvalidDigAmount(span, _) => Some(span),
+            ParseDigInstructionError::InvalidColour(error) => error.span(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum Direction {
     Up,
@@ -92,8 +162,18 @@ impl FromStr for Direction {
 }
 
 #[derive(Debug)]
-pub(super) enum ParseDirectionError {
+pub enum ParseDirectionError {
     UnknownDirection,
 }
 
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDirectionError::UnknownDirection => write!(f, "expected one of U, D, L or R"),
+        }
+    }
+}
+
+impl Error for ParseDirectionError {}
+
 type DigAmount = u64;
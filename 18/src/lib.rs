@@ -0,0 +1,284 @@
+mod colour;
+mod dig_plan;
+mod trench;
+
+use common::checked::Checked;
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::frame::Frame;
+use common::frame::Simulation;
+use common::image::Image;
+use common::image::Render;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use colour::Colour;
+use dig_plan::DigInstruction;
+use dig_plan::DigPlan;
+use dig_plan::Direction;
+use dig_plan::ParseDigPlanError;
+use trench::Edge;
+use trench::Position;
+use trench::Trench;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = DigPlan;
+    type Error = ParseDigPlanError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input.parse()
+    }
+
+    fn part_one(dig_plan: &Self::Input, _: &Parameters) -> Answer {
+        let trench = Trench::from(dig_plan);
+        tracing::debug!(edges = trench.edges.len(), "dug trench");
+
+        get_total_volume(&trench).into()
+    }
+
+    fn part_two(dig_plan: &Self::Input, _: &Parameters) -> Answer {
+        let corrected_dig_plan = extract_correct_instructions(dig_plan);
+        let trench = Trench::from(&corrected_dig_plan);
+        tracing::debug!(edges = trench.edges.len(), "dug trench");
+        get_total_volume(&trench).into()
+    }
+}
+
+/// Digs the part one trench one instruction per frame.
+impl Simulation for Day {
+    fn frames(dig_plan: &Self::Input) -> Box<dyn Iterator<Item = Frame> + '_> {
+        Box::new(Trench::from(dig_plan).into_frames())
+    }
+}
+
+/// Draws the trench as lines, each edge in its colour from the plan. The
+/// part two trench is far bigger.
+impl Render for Day {
+    fn render(dig_plan: &Self::Input, _: &Parameters) -> Image {
+        Image::Vector(Trench::from(dig_plan).to_svg())
+    }
+
+    fn render_part_two(dig_plan: &Self::Input, _: &Parameters) -> Image {
+        Image::Vector(Trench::from(&extract_correct_instructions(dig_plan)).to_svg())
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(dig_plan: &Self::Input) -> Value {
+        Value::list(dig_plan.instructions.iter().map(|instruction| {
+            let direction = match instruction.direction {
+                Direction::Up => 'U',
+                Direction::Down => 'D',
+                Direction::Left => 'L',
+                Direction::Right => 'R',
+            };
+            let Colour::RGB(red, green, blue) = instruction.colour;
+
+            Value::record([
+                ("direction", direction.into()),
+                ("amount", instruction.amount.into()),
+                (
+                    "colour",
+                    format!("#{:02x}{:02x}{:02x}", red, green, blue).into(),
+                ),
+            ])
+        }))
+    }
+}
+
+fn extract_correct_instructions(dig_plan: &DigPlan) -> DigPlan {
+    let instructions = dig_plan
+        .instructions
+        .iter()
+        .map(extract_correct_instruction)
+        .collect();
+
+    DigPlan { instructions }
+}
+
+fn extract_correct_instruction(instruction: &DigInstruction) -> DigInstruction {
+    let Colour::RGB(red, green, blue) = instruction.colour;
+    let hex_int: u64 = ((red as u64) << (2 * 8)) | ((green as u64) << (8)) | blue as u64;
+
+    let hex = format!("{hex_int:#08x}");
+
+    let amount = u64::from_str_radix(&hex[2..7], 16).unwrap();
+    let direction_num = u64::from_str_radix(&hex[7..], 16).unwrap();
+    let direction = match direction_num {
+        0 => Direction::Right,
+        1 => Direction::Down,
+        2 => Direction::Left,
+        3 => Direction::Up,
+        _ => unreachable!("the colour's direction digit should have been checked when parsing"),
+    };
+
+    DigInstruction {
+        direction,
+        amount,
+        colour: Colour::RGB(red, green, blue),
+    }
+}
+
+/// The volume of the lagoon, or `None` if the trench isn't a single loop.
+fn get_total_volume(trench: &Trench) -> Result<Option<u64>, Overflow> {
+    let Some(anti_clockwise_positions) = get_anti_clockwise_positions(&trench.edges) else {
+        return Ok(None);
+    };
+
+    let number_of_boundary_points = trench.edges.iter().map(|edge| edge.length).checked_sum()?;
+    let area = shoelace_formula(anti_clockwise_positions)?;
+
+    let number_of_internal_points =
+        calculate_number_of_internal_points(area, number_of_boundary_points)?;
+
+    number_of_boundary_points
+        .try_add(number_of_internal_points)
+        .map(Some)
+}
+
+fn calculate_number_of_internal_points(
+    area: u64,
+    number_of_boundary_points: u64,
+) -> Result<u64, Overflow> {
+    // Pick's Theorem with help from aoc subreddit
+    area.try_add(1)?.try_sub(number_of_boundary_points / 2)
+}
+
+fn shoelace_formula(anti_clockwise_positions: Vec<Position>) -> Result<u64, Overflow> {
+    let (xs, ys): (Vec<i64>, Vec<i64>) = anti_clockwise_positions
+        .into_iter()
+        .map(|position| (position.x, position.y))
+        .unzip();
+
+    let sum_1: i64 = xs
+        .iter()
+        .zip(ys.iter().cycle().skip(1))
+        .map(|(x, y)| x.try_mul(*y))
+        .collect::<Result<Vec<i64>, Overflow>>()?
+        .into_iter()
+        .checked_sum()?;
+    let sum_2: i64 = ys
+        .iter()
+        .zip(xs.iter().cycle().skip(1))
+        .map(|(y, x)| x.try_mul(*y))
+        .collect::<Result<Vec<i64>, Overflow>>()?
+        .into_iter()
+        .checked_sum()?;
+
+    Ok(sum_1.try_sub(sum_2)?.unsigned_abs() / 2)
+}
+
+/// The corners of the trench going anti-clockwise, or `None` if there are
+/// none or they don't join up into a loop.
+fn get_anti_clockwise_positions(edges: &[Edge]) -> Option<Vec<Position>> {
+    let mut anti_clockwise_positions = Vec::new();
+
+    let (first, last) = (edges.first()?, edges.last()?);
+    if last.end != first.start {
+        return None;
+    }
+    let start = first.start;
+
+    const ANTI_CLOCKWISE_DIRECTION_ORDER: [Direction; 4] = [
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+        Direction::Up,
+    ];
+
+    let mut current_position = start;
+
+    'outer: loop {
+        anti_clockwise_positions.push(current_position);
+
+        for direction in ANTI_CLOCKWISE_DIRECTION_ORDER {
+            if let Some(edge) = edges
+                .iter()
+                .find(|edge| (edge.start == current_position) && edge.direction == direction)
+            {
+                if !anti_clockwise_positions.contains(&edge.end) {
+                    current_position = edge.end;
+                    continue 'outer;
+                }
+            }
+
+            if let Some(edge) = edges.iter().find(|edge| {
+                (edge.end == current_position) && reversed_direction(edge.direction) == direction
+            }) {
+                if !anti_clockwise_positions.contains(&edge.start) {
+                    current_position = edge.start;
+                    continue 'outer;
+                }
+            }
+        }
+
+        if anti_clockwise_positions.len() >= edges.len() {
+            break;
+        }
+
+        return None;
+    }
+
+    Some(anti_clockwise_positions)
+}
+
+fn reversed_direction(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Down => Direction::Up,
+        Direction::Left => Direction::Right,
+        Direction::Right => Direction::Left,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::diagnostic::ParseError;
+    use common::diagnostic::Span;
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn volumes_can_overflow() {
+        let dig_plan = Day::parse(
+            "R 4000000000 (#000000)\nD 4000000000 (#000000)\nL 4000000000 (#000000)\nU 4000000000 (#000000)\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Day::part_one(&dig_plan, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+
+    #[test]
+    fn rejects_a_colour_without_a_direction() {
+        let error = Day::parse("R 6 (#70c714)\n").unwrap_err();
+
+        assert_eq!(error.span().map(Span::snippet), Some("4"));
+    }
+
+    #[test]
+    fn has_no_answer_without_a_loop() {
+        for plan in ["", "R 2 (#000020)\nD 3 (#000031)\n"] {
+            let dig_plan = Day::parse(plan).unwrap();
+
+            assert_eq!(
+                Day::part_one(&dig_plan, &Parameters::default()),
+                Answer::Unavailable
+            );
+            assert_eq!(
+                Day::part_two(&dig_plan, &Parameters::default()),
+                Answer::Unavailable
+            );
+        }
+    }
+}
@@ -4,6 +4,10 @@ use crate::dig_plan::{DigInstruction, DigPlan, Direction};
 use std::fmt::Display;
 
 use colored::Colorize;
+use common::frame;
+use common::frame::Cell;
+use common::frame::Frame;
+use common::image::Svg;
 
 #[derive(Debug)]
 pub(super) struct Edge {
@@ -54,6 +58,52 @@ impl From<&DigPlan> for Trench {
     }
 }
 
+impl Trench {
+    /// Digs the trench one edge per frame, each in its colour from the plan.
+    pub(super) fn into_frames(self) -> impl Iterator<Item = Frame> {
+        let positions = || self.edges.iter().flat_map(|edge| [edge.start, edge.end]);
+        let min_x = positions().map(|position| position.x).min().unwrap_or(0);
+        let max_x = positions().map(|position| position.x).max().unwrap_or(0);
+        let min_y = positions().map(|position| position.y).min().unwrap_or(0);
+        let max_y = positions().map(|position| position.y).max().unwrap_or(0);
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut rows = vec![vec![Cell::new(' '); width]; height];
+
+        let number_of_edges = self.edges.len();
+
+        self.edges.into_iter().enumerate().map(move |(i, edge)| {
+            let Colour::RGB(red, green, blue) = edge.colour;
+            let cell = Cell::coloured('█', frame::Colour::Rgb(red, green, blue));
+
+            for amount in 0..=edge.length {
+                let position = move_direction(edge.start, edge.direction, amount);
+                rows[(position.y - min_y) as usize][(position.x - min_x) as usize] = cell;
+            }
+
+            Frame::new(rows.clone()).with_caption(format!("edge {} of {}", i + 1, number_of_edges))
+        })
+    }
+
+    /// Draws the trench as lines, so it stays sharp however big it is.
+    pub(super) fn to_svg(&self) -> Svg {
+        let mut svg = Svg::new();
+
+        for edge in &self.edges {
+            let Colour::RGB(red, green, blue) = edge.colour;
+
+            svg.line(
+                (edge.start.x, edge.start.y),
+                (edge.end.x, edge.end.y),
+                frame::Colour::Rgb(red, green, blue),
+            );
+        }
+
+        svg
+    }
+}
+
 pub fn move_direction(position: Position, direction: Direction, amount: u64) -> Position {
     let amount = amount as i64;
 
@@ -0,0 +1,347 @@
+mod parser;
+
+use common::checked::Checked;
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use interval::Interval;
+use interval::IntervalSet;
+
+use std::collections::HashMap;
+
+use crate::parser::ParseSystemError;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Statement {
+    Accepted,
+    Rejected,
+    If(BooleanExpression, Box<Statement>, Box<Statement>),
+    Workflow(WorkflowName),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Var {
+    X,
+    M,
+    A,
+    S,
+}
+
+type PartRatingValue = u64;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum BooleanExpression {
+    GreaterThan(Var, PartRatingValue),
+    LessThan(Var, PartRatingValue),
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct WorkflowName(String);
+
+#[derive(Debug, Clone, Copy)]
+struct PartRating {
+    x: PartRatingValue,
+    m: PartRatingValue,
+    a: PartRatingValue,
+    s: PartRatingValue,
+}
+
+#[derive(Debug)]
+pub struct System {
+    workflows: HashMap<WorkflowName, Statement>,
+    part_ratings: Vec<PartRating>,
+}
+
+pub(crate) const STARTING_WORKFLOW_NAME: &str = "in";
+
+pub struct Day;
+
+/// Ratings are kept low enough that the number of combinations of all four
+/// still fits in a [`PartRatingValue`].
+const MIN_RATING: Parameter = Parameter {
+    name: "min_rating",
+    description: "The lowest value each rating can have in part two.",
+    default: 1,
+    range: 0..=60000,
+};
+
+const MAX_RATING: Parameter = Parameter {
+    name: "max_rating",
+    description: "The highest value each rating can have in part two.",
+    default: 4000,
+    range: 0..=60000,
+};
+
+impl Solution for Day {
+    type Input = System;
+    type Error = ParseSystemError;
+
+    const PARAMETERS: &'static [Parameter] = &[MIN_RATING, MAX_RATING];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let system: System = input.parse()?;
+        tracing::debug!(
+            workflows = system.workflows.len(),
+            parts = system.part_ratings.len(),
+            "parsed system"
+        );
+
+        Ok(system)
+    }
+
+    fn part_one(system: &Self::Input, _: &Parameters) -> Answer {
+        let starting_workflow_name = WorkflowName(STARTING_WORKFLOW_NAME.to_string());
+        let starting_statement = system
+            .workflows
+            .get(&starting_workflow_name)
+            .expect("the starting workflow should have been checked when parsing");
+
+        system
+            .part_ratings
+            .iter()
+            .filter(|part_rating| {
+                evaluate(&system.workflows, starting_statement, **part_rating)
+                    == Statement::Accepted
+            })
+            .flat_map(|part_rating| [part_rating.x, part_rating.m, part_rating.a, part_rating.s])
+            .checked_sum()
+            .into()
+    }
+
+    fn part_two(system: &Self::Input, parameters: &Parameters) -> Answer {
+        let starting_workflow_name = WorkflowName(STARTING_WORKFLOW_NAME.to_string());
+        let starting_statement = system
+            .workflows
+            .get(&starting_workflow_name)
+            .expect("the starting workflow should have been checked when parsing");
+
+        let min = parameters.get(&MIN_RATING);
+        let max = parameters.get(&MAX_RATING) + 1;
+
+        let starting_set = IntervalSet::from(Interval::new(min, max));
+        let sets = [
+            starting_set.clone(),
+            starting_set.clone(),
+            starting_set.clone(),
+            starting_set,
+        ];
+
+        calculate_total_combinations(&system.workflows, starting_statement, sets).into()
+    }
+}
+
+/// Each workflow is keyed by name, in order, and holds its rules as a tree of
+/// statements, each with a `kind` of `accepted`, `rejected`, `workflow` or
+/// `if`.
+impl Inspect for Day {
+    fn inspect(system: &Self::Input) -> Value {
+        let mut workflows: Vec<(&WorkflowName, &Statement)> = system.workflows.iter().collect();
+        workflows.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+
+        Value::record([
+            (
+                "workflows",
+                Value::record(
+                    workflows
+                        .into_iter()
+                        .map(|(name, statement)| (name.0.as_str(), statement.into())),
+                ),
+            ),
+            (
+                "part_ratings",
+                Value::list(system.part_ratings.iter().map(|part_rating| {
+                    Value::record([
+                        ("x", part_rating.x.into()),
+                        ("m", part_rating.m.into()),
+                        ("a", part_rating.a.into()),
+                        ("s", part_rating.s.into()),
+                    ])
+                })),
+            ),
+        ])
+    }
+}
+
+impl From<&Statement> for Value {
+    fn from(statement: &Statement) -> Self {
+        match statement {
+            Statement::Accepted => Value::record([("kind", "accepted".into())]),
+            Statement::Rejected => Value::record([("kind", "rejected".into())]),
+            Statement::Workflow(name) => Value::record([
+                ("kind", "workflow".into()),
+                ("name", name.0.as_str().into()),
+            ]),
+            Statement::If(boolean_expression, stmt_1, stmt_2) => {
+                let (var, comparison, value) = match boolean_expression {
+                    BooleanExpression::GreaterThan(var, value) => (var, ">", value),
+                    BooleanExpression::LessThan(var, value) => (var, "<", value),
+                };
+                let rating = match var {
+                    Var::X => 'x',
+                    Var::M => 'm',
+                    Var::A => 'a',
+                    Var::S => 's',
+                };
+
+                Value::record([
+                    ("kind", "if".into()),
+                    (
+                        "condition",
+                        Value::record([
+                            ("rating", rating.into()),
+                            ("comparison", comparison.into()),
+                            ("value", value.into()),
+                        ]),
+                    ),
+                    ("then", stmt_1.as_ref().into()),
+                    ("else", stmt_2.as_ref().into()),
+                ])
+            }
+        }
+    }
+}
+
+/// The ratings still possible for each of x, m, a and s.
+type RatingSets = [IntervalSet<PartRatingValue>; 4];
+
+fn calculate_total_combinations(
+    workflows: &HashMap<WorkflowName, Statement>,
+    statement: &Statement,
+    sets: RatingSets,
+) -> Result<PartRatingValue, Overflow> {
+    match statement {
+        Statement::Accepted => sets
+            .iter()
+            .map(IntervalSet::cardinality)
+            .collect::<Result<Vec<PartRatingValue>, Overflow>>()?
+            .into_iter()
+            .checked_product(),
+        Statement::Rejected => Ok(0),
+        Statement::If(boolean_expression, stmt_1, stmt_2) => {
+            let (set_1, set_2) = split_sets(boolean_expression, sets);
+
+            let combinations_if_true = calculate_total_combinations(workflows, stmt_1, set_1)?;
+            let combinations_if_false = calculate_total_combinations(workflows, stmt_2, set_2)?;
+
+            combinations_if_true.try_add(combinations_if_false)
+        }
+        Statement::Workflow(workflow_name) => {
+            let new_statement = workflows
+                .get(workflow_name)
+                .expect("workflows should have been checked when parsing");
+            calculate_total_combinations(workflows, new_statement, sets)
+        }
+    }
+}
+
+/// Splits the ratings into those for which the expression is true and those
+/// for which it is false.
+fn split_sets(
+    boolean_expression: &BooleanExpression,
+    sets: RatingSets,
+) -> (RatingSets, RatingSets) {
+    let (var, true_ratings) = match boolean_expression {
+        BooleanExpression::GreaterThan(var, value) => (
+            var,
+            Interval::new(value.saturating_add(1), PartRatingValue::MAX),
+        ),
+        BooleanExpression::LessThan(var, value) => {
+            (var, Interval::new(PartRatingValue::MIN, *value))
+        }
+    };
+    let true_ratings = IntervalSet::from(true_ratings);
+
+    let set_index = match var {
+        Var::X => 0,
+        Var::M => 1,
+        Var::A => 2,
+        Var::S => 3,
+    };
+
+    let mut sets_if_true = sets.clone();
+    let mut sets_if_false = sets;
+    sets_if_true[set_index] = sets_if_true[set_index].intersection(&true_ratings);
+    sets_if_false[set_index] = sets_if_false[set_index].difference(&true_ratings);
+
+    (sets_if_true, sets_if_false)
+}
+
+fn evaluate(
+    workflows: &HashMap<WorkflowName, Statement>,
+    statement: &Statement,
+    part_rating: PartRating,
+) -> Statement {
+    match statement {
+        Statement::Accepted => Statement::Accepted,
+        Statement::Rejected => Statement::Rejected,
+        Statement::If(boolean_statement, if_true_statement, if_false_statement) => {
+            match evaluate_boolean(boolean_statement, part_rating) {
+                true => evaluate(workflows, if_true_statement, part_rating),
+                false => evaluate(workflows, if_false_statement, part_rating),
+            }
+        }
+        Statement::Workflow(workflow_name) => {
+            let statement = workflows
+                .get(workflow_name)
+                .expect("workflows should have been checked when parsing");
+            evaluate(workflows, statement, part_rating)
+        }
+    }
+}
+
+fn evaluate_boolean(boolean_statement: &BooleanExpression, part_rating: PartRating) -> bool {
+    match boolean_statement {
+        BooleanExpression::GreaterThan(var, value) => get_var(part_rating, var) > *value,
+        BooleanExpression::LessThan(var, value) => get_var(part_rating, var) < *value,
+    }
+}
+
+fn get_var(part_rating: PartRating, var: &Var) -> PartRatingValue {
+    match var {
+        Var::X => part_rating.x,
+        Var::M => part_rating.m,
+        Var::A => part_rating.a,
+        Var::S => part_rating.s,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::diagnostic::ParseError;
+    use common::diagnostic::Span;
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn ratings_can_overflow() {
+        let system = Day::parse(
+            "in{x>0:A,R}\n\n{x=10000000000000000000,m=0,a=0,s=0}\n{x=10000000000000000000,m=0,a=0,s=0}\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            Day::part_one(&system, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+
+    #[test]
+    fn rejects_workflows_that_cannot_sort_parts() {
+        let no_start = Day::parse("px{a<1:A,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+        let unknown = Day::parse("in{a<1:zz,R}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+        let cyclic = Day::parse("in{a<5:px,R}\npx{m>2:in,A}\n\n{x=1,m=1,a=1,s=1}\n").unwrap_err();
+
+        assert_eq!(no_start.to_string(), "no workflow is named \"in\"");
+        assert_eq!(unknown.span().map(Span::snippet), Some("zz"));
+        assert_eq!(cyclic.span().map(Span::snippet), Some("in"));
+    }
+}
@@ -1,13 +1,62 @@
-use std::{collections::HashMap, num::ParseIntError, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+    num::ParseIntError,
+    str::FromStr,
+};
 
-use crate::{BooleanExpression, PartRating, PartRatingValue, Statement, System, Var, WorkflowName};
+use common::diagnostic::{ParseError, Span};
+
+use crate::{
+    BooleanExpression, PartRating, PartRatingValue, Statement, System, Var, WorkflowName,
+    STARTING_WORKFLOW_NAME,
+};
 
 #[derive(Debug)]
 pub enum ParseStatementError {
-    UnableToParseStatement(String),
-    InvalidVar(ParseVarError),
-    InvalidRating(ParseIntError),
-    UnknownBooleanOperator(String),
+    UnableToParseStatement(Span),
+    InvalidVar(Span, ParseVarError),
+    InvalidRating(Span, ParseIntError),
+    UnknownBooleanOperator(Span),
+}
+
+impl Display for ParseStatementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseStatementError::UnableToParseStatement(span) => {
+                write!(f, "unable to parse statement {:?}", span.snippet())
+            }
+            ParseStatementError::InvalidVar(_, _) => write!(f, "invalid rating category"),
+            ParseStatementError::InvalidRating(span, _) => {
+                write!(f, "invalid rating {:?}", span.snippet())
+            }
+            ParseStatementError::UnknownBooleanOperator(span) => {
+                write!(f, "unknown comparison {:?}", span.snippet())
+            }
+        }
+    }
+}
+
+impl Error for ParseStatementError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseStatementError::InvalidVar(_, error) => Some(error),
+            ParseStatementError::InvalidRating(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseStatementError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseStatementError::UnableToParseStatement(span)
+            | ParseStatementError::InvalidVar(span, _)
+            | ParseStatementError::InvalidRating(span, _)
+            | ParseStatementError::UnknownBooleanOperator(span) => Some(span),
+        }
+    }
 }
 
 impl FromStr for Statement {
@@ -27,15 +76,13 @@ impl FromStr for Statement {
         const TERNARY_SYMBOL: char = ':';
         const OR_SYMBOL: char = ',';
 
-        let maybe_ternary_symbol_position = s.chars().position(|c| c == TERNARY_SYMBOL);
-        let maybe_or_symbol_position = s.chars().position(|c| c == OR_SYMBOL);
-
-        if let (Some(ternary_position), Some(or_symbol_position)) =
-            (maybe_ternary_symbol_position, maybe_or_symbol_position)
+        if let Some((condition_str, (statement_1_str, statement_2_str))) = s
+            .split_once(TERNARY_SYMBOL)
+            .and_then(|(condition_str, rest)| Some((condition_str, rest.split_once(OR_SYMBOL)?)))
         {
-            let boolean_statement = parse_boolean_statement(&s[0..ternary_position])?;
-            let statement_1: Statement = s[ternary_position + 1..or_symbol_position].parse()?;
-            let statement_2: Statement = s[or_symbol_position + 1..].parse()?;
+            let boolean_statement = parse_boolean_statement(condition_str)?;
+            let statement_1: Statement = statement_1_str.parse()?;
+            let statement_2: Statement = statement_2_str.parse()?;
 
             return Ok(Statement::If(
                 boolean_statement,
@@ -48,24 +95,45 @@ impl FromStr for Statement {
             return Ok(Statement::Workflow(workflow_name));
         }
 
-        Err(ParseStatementError::UnableToParseStatement(s.to_string()))
+        Err(ParseStatementError::UnableToParseStatement(Span::of(s)))
+    }
+}
+
+/// The workflows that the rules in `s` send parts to, as they appear in the
+/// input. `s` should already have parsed as a [`Statement`].
+fn referenced_workflows(s: &str) -> Vec<&str> {
+    if s == "A" || s == "R" {
+        return Vec::new();
+    }
+
+    match s.split_once(':').and_then(|(_, rest)| rest.split_once(',')) {
+        Some((statement_1_str, statement_2_str)) => {
+            let mut references = referenced_workflows(statement_1_str);
+            references.extend(referenced_workflows(statement_2_str));
+            references
+        }
+        None => vec![s],
     }
 }
 
 fn parse_boolean_statement(s: &str) -> Result<BooleanExpression, ParseStatementError> {
-    let var = s[0..1].parse().map_err(ParseStatementError::InvalidVar)?;
-    let operator_str = &s[1..2];
+    let (Some(var_str), Some(operator_str)) = (s.get(0..1), s.get(1..2)) else {
+        return Err(ParseStatementError::UnableToParseStatement(Span::of(s)));
+    };
+    let var = var_str
+        .parse()
+        .map_err(|error| ParseStatementError::InvalidVar(Span::of(var_str), error))?;
     let rating_str = &s[2..];
     let rating_value = rating_str
         .parse()
-        .map_err(ParseStatementError::InvalidRating)?;
+        .map_err(|error| ParseStatementError::InvalidRating(Span::of(rating_str), error))?;
 
     match operator_str {
         ">" => Ok(BooleanExpression::GreaterThan(var, rating_value)),
         "<" => Ok(BooleanExpression::LessThan(var, rating_value)),
-        _ => Err(ParseStatementError::UnknownBooleanOperator(
-            operator_str.to_string(),
-        )),
+        _ => Err(ParseStatementError::UnknownBooleanOperator(Span::of(
+            operator_str,
+        ))),
     }
 }
 
@@ -74,6 +142,20 @@ pub enum ParseVarError {
     UnknownVar(String),
 }
 
+impl Display for ParseVarError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseVarError::UnknownVar(var) => write!(
+                f,
+                "unknown rating category {:?}, expected one of x, m, a or s",
+                var
+            ),
+        }
+    }
+}
+
+impl Error for ParseVarError {}
+
 impl FromStr for Var {
     type Err = ParseVarError;
 
@@ -89,45 +171,114 @@ impl FromStr for Var {
 }
 
 #[derive(Debug)]
-pub enum ParseWorkflowNameError {}
+pub enum ParseWorkflowNameError {
+    EmptyName,
+}
+
+impl Display for ParseWorkflowNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWorkflowNameError::EmptyName => write!(f, "workflow name is empty"),
+        }
+    }
+}
+
+impl Error for ParseWorkflowNameError {}
 
 impl FromStr for WorkflowName {
     type Err = ParseWorkflowNameError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseWorkflowNameError::EmptyName);
+        }
+
         Ok(WorkflowName(s.to_string()))
     }
 }
 
 #[derive(Debug)]
 pub enum ParsePartRatingError {
-    InvalidX(ParseIntError),
-    InvalidM(ParseIntError),
-    InvalidA(ParseIntError),
-    InvalidS(ParseIntError),
-    InvalidFormat,
+    InvalidX(Span, ParseIntError),
+    InvalidM(Span, ParseIntError),
+    InvalidA(Span, ParseIntError),
+    InvalidS(Span, ParseIntError),
+    InvalidFormat(Span),
+}
+
+impl Display for ParsePartRatingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParsePartRatingError::InvalidX(span, _)
+            | ParsePartRatingError::InvalidM(span, _)
+            | ParsePartRatingError::InvalidA(span, _)
+            | ParsePartRatingError::InvalidS(span, _) => {
+                write!(f, "invalid rating {:?}", span.snippet())
+            }
+            ParsePartRatingError::InvalidFormat(_) => {
+                write!(f, "expected ratings for x, m, a and s")
+            }
+        }
+    }
+}
+
+impl Error for ParsePartRatingError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParsePartRatingError::InvalidX(_, error)
+            | ParsePartRatingError::InvalidM(_, error)
+            | ParsePartRatingError::InvalidA(_, error)
+            | ParsePartRatingError::InvalidS(_, error) => Some(error),
+            ParsePartRatingError::InvalidFormat(_) => None,
+        }
+    }
+}
+
+impl ParseError for ParsePartRatingError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParsePartRatingError::InvalidX(span, _)
+            | ParsePartRatingError::InvalidM(span, _)
+            | ParsePartRatingError::InvalidA(span, _)
+            | ParsePartRatingError::InvalidS(span, _)
+            | ParsePartRatingError::InvalidFormat(span) => Some(span),
+        }
+    }
 }
 
 impl FromStr for PartRating {
     type Err = ParsePartRatingError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let values = &s[1..s.len() - 1];
+        let values = s
+            .strip_prefix('{')
+            .and_then(|values| values.strip_suffix('}'))
+            .ok_or_else(|| ParsePartRatingError::InvalidFormat(Span::of(s)))?;
 
         let [x_value, m_value, a_value, s_value]: [&str; 4] = values
             .split(',')
             .collect::<Vec<&str>>()
             .try_into()
-            .map_err(|_| ParsePartRatingError::InvalidFormat)?;
-
-        fn parse_rating_declaration(s: &str) -> Result<PartRatingValue, ParseIntError> {
-            s[2..].parse()
+            .map_err(|_| ParsePartRatingError::InvalidFormat(Span::of(s)))?;
+
+        fn parse_rating_declaration(
+            s: &str,
+            name: &str,
+            error: fn(Span, ParseIntError) -> ParsePartRatingError,
+        ) -> Result<PartRatingValue, ParsePartRatingError> {
+            let value = s
+                .strip_prefix(name)
+                .and_then(|s| s.strip_prefix('='))
+                .ok_or_else(|| ParsePartRatingError::InvalidFormat(Span::of(s)))?;
+            value
+                .parse()
+                .map_err(|source| error(Span::of(value), source))
         }
 
-        let x = parse_rating_declaration(x_value).map_err(ParsePartRatingError::InvalidX)?;
-        let m = parse_rating_declaration(m_value).map_err(ParsePartRatingError::InvalidM)?;
-        let a = parse_rating_declaration(a_value).map_err(ParsePartRatingError::InvalidA)?;
-        let s = parse_rating_declaration(s_value).map_err(ParsePartRatingError::InvalidS)?;
+        let x = parse_rating_declaration(x_value, "x", ParsePartRatingError::InvalidX)?;
+        let m = parse_rating_declaration(m_value, "m", ParsePartRatingError::InvalidM)?;
+        let a = parse_rating_declaration(a_value, "a", ParsePartRatingError::InvalidA)?;
+        let s = parse_rating_declaration(s_value, "s", ParsePartRatingError::InvalidS)?;
 
         Ok(PartRating { x, m, a, s })
     }
@@ -135,11 +286,78 @@ impl FromStr for PartRating {
 
 #[derive(Debug)]
 pub enum ParseSystemError {
-    InvalidSystemFormat,
-    UnableToFindStatementStart,
-    InvalidWorkflowName(ParseWorkflowNameError),
+    InvalidSystemFormat(Span),
+    UnableToFindStatementStart(Span),
+    UnableToFindStatementEnd(Span),
+    InvalidWorkflowName(Span, ParseWorkflowNameError),
     InvalidStatement(ParseStatementError),
     InvalidRating(ParsePartRatingError),
+    MissingStartingWorkflow(Span),
+    UnknownWorkflow(Span),
+    CyclicWorkflow(Span),
+}
+
+impl Display for ParseSystemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseSystemError::InvalidSystemFormat(_) => write!(
+                f,
+                "expected workflows and part ratings separated by a blank line"
+            ),
+            ParseSystemError::UnableToFindStatementStart(_) => {
+                write!(f, "workflow is missing its rules")
+            }
+            ParseSystemError::UnableToFindStatementEnd(_) => {
+                write!(f, "workflow rules are missing their closing brace")
+            }
+            ParseSystemError::InvalidWorkflowName(_, _) => write!(f, "invalid workflow name"),
+            ParseSystemError::InvalidStatement(_) => write!(f, "invalid workflow rules"),
+            ParseSystemError::InvalidRating(_) => write!(f, "invalid part ratings"),
+            ParseSystemError::MissingStartingWorkflow(_) => {
+                write!(f, "no workflow is named {:?}", STARTING_WORKFLOW_NAME)
+            }
+            ParseSystemError::UnknownWorkflow(span) => {
+                write!(f, "no workflow is named {:?}", span.snippet())
+            }
+            ParseSystemError::CyclicWorkflow(span) => write!(
+                f,
+                "workflow {:?} leads back to itself, so parts would never be sorted",
+                span.snippet()
+            ),
+        }
+    }
+}
+
+impl Error for ParseSystemError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseSystemError::InvalidSystemFormat(_)
+            | ParseSystemError::UnableToFindStatementStart(_)
+            | ParseSystemError::UnableToFindStatementEnd(_)
+            | ParseSystemError::MissingStartingWorkflow(_)
+            | ParseSystemError::UnknownWorkflow(_)
+            | ParseSystemError::CyclicWorkflow(_) => None,
+            ParseSystemError::InvalidWorkflowName(_, error) => Some(error),
+            ParseSystemError::InvalidStatement(error) => Some(error),
+            ParseSystemError::InvalidRating(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseSystemError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseSystemError::InvalidSystemFormat(span)
+            | ParseSystemError::UnableToFindStatementStart(span)
+            | ParseSystemError::UnableToFindStatementEnd(span)
+            | ParseSystemError::InvalidWorkflowName(span, _)
+            | ParseSystemError::MissingStartingWorkflow(span)
+            | ParseSystemError::UnknownWorkflow(span)
+            | ParseSystemError::CyclicWorkflow(span) => Some(span),
+            ParseSystemError::InvalidStatement(error) => error.span(),
+            ParseSystemError::InvalidRating(error) => error.span(),
+        }
+    }
 }
 
 impl FromStr for System {
@@ -147,31 +365,38 @@ impl FromStr for System {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let [workflows_str, part_ratings_str]: [&str; 2] = s
+            .trim_end_matches('\n')
             .split("\n\n")
             .collect::<Vec<&str>>()
             .try_into()
-            .map_err(|_| ParseSystemError::InvalidSystemFormat)?;
+            .map_err(|_| ParseSystemError::InvalidSystemFormat(Span::of(s)))?;
 
+        let mut references: Vec<(&str, Vec<&str>)> = Vec::new();
         let workflows: HashMap<WorkflowName, Statement> = workflows_str
             .split('\n')
             .map(|workflow_str| {
-                let statement_start = workflow_str
-                    .chars()
-                    .position(|c| c == '{')
-                    .ok_or(ParseSystemError::UnableToFindStatementStart)?;
-
-                let workflow_name: WorkflowName = workflow_str[..statement_start]
-                    .parse()
-                    .map_err(ParseSystemError::InvalidWorkflowName)?;
-                let statement: Statement = workflow_str
-                    [statement_start + 1..workflow_str.len() - 1]
+                let (workflow_name_str, statement_str) =
+                    workflow_str.split_once('{').ok_or_else(|| {
+                        ParseSystemError::UnableToFindStatementStart(Span::of(workflow_str))
+                    })?;
+
+                let workflow_name: WorkflowName = workflow_name_str.parse().map_err(|error| {
+                    ParseSystemError::InvalidWorkflowName(Span::of(workflow_name_str), error)
+                })?;
+                let rules_str = statement_str.strip_suffix('}').ok_or_else(|| {
+                    ParseSystemError::UnableToFindStatementEnd(Span::end_of(workflow_str))
+                })?;
+                let statement: Statement = rules_str
                     .parse()
                     .map_err(ParseSystemError::InvalidStatement)?;
+                references.push((workflow_name_str, referenced_workflows(rules_str)));
 
                 Ok((workflow_name, statement))
             })
             .collect::<Result<HashMap<WorkflowName, Statement>, ParseSystemError>>()?;
 
+        check_workflow_references(workflows_str, &references)?;
+
         let part_ratings: Vec<PartRating> = part_ratings_str
             .split('\n')
             .map(|part_rating_str| {
@@ -187,3 +412,63 @@ impl FromStr for System {
         })
     }
 }
+
+/// Checks that there is a workflow to start from, that every workflow a rule
+/// sends parts to exists, and that no workflow sends parts back to itself,
+/// directly or through others, which would never finish.
+fn check_workflow_references(
+    workflows_str: &str,
+    references: &[(&str, Vec<&str>)],
+) -> Result<(), ParseSystemError> {
+    let references: HashMap<&str, &[&str]> = references
+        .iter()
+        .map(|(name, referenced)| (*name, referenced.as_slice()))
+        .collect();
+
+    if !references.contains_key(STARTING_WORKFLOW_NAME) {
+        return Err(ParseSystemError::MissingStartingWorkflow(Span::of(
+            workflows_str,
+        )));
+    }
+
+    let mut in_progress = HashSet::new();
+    let mut finished = HashSet::new();
+    // In the order the workflows are written, so the first problem is the
+    // one reported.
+    for line in workflows_str.split('\n') {
+        if let Some((name, _)) = line.split_once('{') {
+            visit_workflow(name, &references, &mut in_progress, &mut finished)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn visit_workflow<'a>(
+    name: &'a str,
+    references: &HashMap<&'a str, &[&'a str]>,
+    in_progress: &mut HashSet<&'a str>,
+    finished: &mut HashSet<&'a str>,
+) -> Result<(), ParseSystemError> {
+    if finished.contains(name) {
+        return Ok(());
+    }
+
+    in_progress.insert(name);
+
+    for reference in references.get(name).copied().unwrap_or_default() {
+        if !references.contains_key(reference) {
+            return Err(ParseSystemError::UnknownWorkflow(Span::of(reference)));
+        }
+        if in_progress.contains(reference) {
+            return Err(ParseSystemError::CyclicWorkflow(Span::of(reference)));
+        }
+
+        visit_workflow(reference, references, in_progress, finished)?;
+    }
+
+    in_progress.remove(name);
+    finished.insert(name);
+
+    Ok(())
+}
@@ -0,0 +1,331 @@
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+struct HandfulCount {
+    red: u16,
+    green: u16,
+    blue: u16,
+}
+
+#[derive(Debug)]
+pub enum ParseHandfulCountError {
+    MissingColour(Span),
+    UnknownColour(Span),
+    AmountParseError(Span, ParseIntError),
+}
+
+impl Display for ParseHandfulCountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHandfulCountError::MissingColour(_) => {
+                write!(f, "expected an amount and a colour separated by a space")
+            }
+            ParseHandfulCountError::UnknownColour(span) => {
+                write!(f, "unknown cube colour {:?}", span.snippet())
+            }
+            ParseHandfulCountError::AmountParseError(_, _) => write!(f, "invalid cube amount"),
+        }
+    }
+}
+
+impl Error for ParseHandfulCountError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseHandfulCountError::MissingColour(_) | ParseHandfulCountError::UnknownColour(_) => {
+                None
+            }
+            ParseHandfulCountError::AmountParseError(_, error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseHandfulCountError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseHandfulCountError::MissingColour(span)
+            | ParseHandfulCountError::UnknownColour(span)
+            | ParseHandfulCountError::AmountParseError(span, _) => Some(span),
+        }
+    }
+}
+
+impl FromStr for HandfulCount {
+    type Err = ParseHandfulCountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut handful_count = HandfulCount {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+
+        let cube_counts = s.split(", ");
+
+        for count in cube_counts {
+            let (amount_str, cube_type) = count
+                .split_once(' ')
+                .ok_or_else(|| ParseHandfulCountError::MissingColour(Span::of(count)))?;
+
+            let amount = amount_str.parse().map_err(|error| {
+                ParseHandfulCountError::AmountParseError(Span::of(amount_str), error)
+            })?;
+
+            match cube_type {
+                "red" => handful_count.red = amount,
+                "green" => handful_count.green = amount,
+                "blue" => handful_count.blue = amount,
+                _ => return Err(ParseHandfulCountError::UnknownColour(Span::of(cube_type))),
+            };
+        }
+
+        Ok(handful_count)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseGameError {
+    MissingGamePrefix(Span),
+    MissingSubsets(Span),
+    ParseIdError(Span, ParseIntError),
+    ParseSubsetsError(ParseHandfulCountError),
+}
+
+impl Display for ParseGameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGameError::MissingGamePrefix(_) => write!(f, "expected \"Game \" and an id"),
+            ParseGameError::MissingSubsets(_) => {
+                write!(f, "expected the handfuls of cubes after \": \"")
+            }
+            ParseGameError::ParseIdError(_, _) => write!(f, "invalid game id"),
+            ParseGameError::ParseSubsetsError(_) => write!(f, "invalid handful of cubes"),
+        }
+    }
+}
+
+impl Error for ParseGameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseGameError::MissingGamePrefix(_) | ParseGameError::MissingSubsets(_) => None,
+            ParseGameError::ParseIdError(_, error) => Some(error),
+            ParseGameError::ParseSubsetsError(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseGameError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseGameError::MissingGamePrefix(span)
+            | ParseGameError::MissingSubsets(span)
+            | ParseGameError::ParseIdError(span, _) => Some(span),
+            ParseGameError::ParseSubsetsError(error) => error.span(),
+        }
+    }
+}
+
+impl From<ParseHandfulCountError> for ParseGameError {
+    fn from(value: ParseHandfulCountError) -> Self {
+        ParseGameError::ParseSubsetsError(value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    id: u16,
+    subsets: Vec<HandfulCount>,
+}
+
+impl FromStr for Game {
+    type Err = ParseGameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game_str, handfuls) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseGameError::MissingSubsets(Span::end_of(s)))?;
+
+        const GAME_PREFIX: &str = "Game ";
+        let id_str = game_str
+            .strip_prefix(GAME_PREFIX)
+            .ok_or_else(|| ParseGameError::MissingGamePrefix(Span::of(game_str)))?;
+        let id = id_str
+            .parse()
+            .map_err(|error| ParseGameError::ParseIdError(Span::of(id_str), error))?;
+        let subsets: Vec<HandfulCount> = handfuls
+            .split("; ")
+            .map(HandfulCount::from_str)
+            .collect::<Result<Vec<HandfulCount>, ParseHandfulCountError>>()?;
+
+        Ok(Game { id, subsets })
+    }
+}
+
+pub struct Day;
+
+const BAG_RED: Parameter = Parameter {
+    name: "bag_red",
+    description: "How many red cubes are in the bag in part one.",
+    default: 12,
+    range: 0..=u16::MAX as u64,
+};
+
+const BAG_GREEN: Parameter = Parameter {
+    name: "bag_green",
+    description: "How many green cubes are in the bag in part one.",
+    default: 13,
+    range: 0..=u16::MAX as u64,
+};
+
+const BAG_BLUE: Parameter = Parameter {
+    name: "bag_blue",
+    description: "How many blue cubes are in the bag in part one.",
+    default: 14,
+    range: 0..=u16::MAX as u64,
+};
+
+impl Solution for Day {
+    type Input = Vec<Game>;
+    type Error = ParseGameError;
+
+    const PARAMETERS: &'static [Parameter] = &[BAG_RED, BAG_GREEN, BAG_BLUE];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let record: Vec<Game> = input
+            .split('\n')
+            .filter(|line| line != &"")
+            .map(Game::from_str)
+            .collect::<Result<Vec<Game>, ParseGameError>>()?;
+
+        tracing::debug!(games = record.len(), "parsed record");
+
+        Ok(record)
+    }
+
+    fn part_one(record: &Self::Input, parameters: &Parameters) -> Answer {
+        let bag_contents = HandfulCount {
+            red: parameters.get(&BAG_RED) as u16,
+            green: parameters.get(&BAG_GREEN) as u16,
+            blue: parameters.get(&BAG_BLUE) as u16,
+        };
+
+        let possible_games: Vec<Game> = record
+            .iter()
+            .filter(|&x| is_valid_game(x, &bag_contents))
+            .cloned()
+            .collect();
+        possible_games
+            .iter()
+            .map(|game| u64::from(game.id))
+            .checked_sum()
+            .into()
+    }
+
+    fn part_two(record: &Self::Input, _: &Parameters) -> Answer {
+        let minimum_counts: Vec<HandfulCount> = record.iter().map(get_minimum_cube_count).collect();
+        minimum_counts
+            .iter()
+            .map(get_power)
+            .collect::<Result<Vec<u64>, _>>()
+            .and_then(|powers| powers.into_iter().checked_sum())
+            .into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(record: &Self::Input) -> Value {
+        Value::list(record)
+    }
+}
+
+impl From<&Game> for Value {
+    fn from(game: &Game) -> Self {
+        Value::record([
+            ("id", game.id.into()),
+            ("subsets", Value::list(&game.subsets)),
+        ])
+    }
+}
+
+impl From<&HandfulCount> for Value {
+    fn from(count: &HandfulCount) -> Self {
+        Value::record([
+            ("red", count.red.into()),
+            ("green", count.green.into()),
+            ("blue", count.blue.into()),
+        ])
+    }
+}
+
+fn is_valid_game(game: &Game, bag_contents: &HandfulCount) -> bool {
+    game.subsets.iter().all(|subset| {
+        (subset.red <= bag_contents.red)
+            && (subset.green <= bag_contents.green)
+            && (subset.blue <= bag_contents.blue)
+    })
+}
+
+fn get_minimum_cube_count(game: &Game) -> HandfulCount {
+    game.subsets.iter().fold(
+        HandfulCount {
+            red: 0,
+            green: 0,
+            blue: 0,
+        },
+        |current, next| HandfulCount {
+            red: current.red.max(next.red),
+            green: current.green.max(next.green),
+            blue: current.blue.max(next.blue),
+        },
+    )
+}
+
+fn get_power(count: &HandfulCount) -> Result<u64, Overflow> {
+    [count.red, count.green, count.blue]
+        .into_iter()
+        .map(u64::from)
+        .checked_product()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn sums_more_than_fit_in_a_u16() {
+        let record: String = (1..=1000)
+            .map(|id| format!("Game {}: 100 red, 100 green, 100 blue\n", id))
+            .collect();
+        let record = Day::parse(&record).unwrap();
+        let mut parameters = Parameters::new(Day::PARAMETERS);
+        for name in ["bag_red", "bag_green", "bag_blue"] {
+            parameters.set(name, 100).unwrap();
+        }
+
+        assert_eq!(
+            Day::part_one(&record, &parameters),
+            Answer::from(500_500u64)
+        );
+        assert_eq!(
+            Day::part_two(&record, &parameters),
+            Answer::from(1_000_000_000u64)
+        );
+    }
+}
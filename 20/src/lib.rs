@@ -0,0 +1,655 @@
+use common::checked::Checked;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use module_network::ModuleNetwork;
+use module_network::ParseModuleNetworkError;
+use module_network::MACHINE_NAME;
+use std::fmt::Debug;
+
+trait Module: Debug {
+    fn process(&mut self, from: &ModuleName, pulse: Pulse) -> Option<Pulse>;
+
+    fn boxed_clone(&self) -> Box<dyn Module>;
+
+    fn kind(&self) -> &'static str;
+
+    /// What the module remembers between pulses.
+    fn state(&self) -> Value;
+}
+
+impl Clone for Box<dyn Module> {
+    fn clone(&self) -> Self {
+        self.boxed_clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct ModuleName(String);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Pulse {
+    High,
+    Low,
+}
+
+impl From<Pulse> for Value {
+    fn from(pulse: Pulse) -> Self {
+        match pulse {
+            Pulse::High => "high".into(),
+            Pulse::Low => "low".into(),
+        }
+    }
+}
+
+mod module_network {
+    use std::{collections::VecDeque, error::Error, fmt::Debug, fmt::Display, str::FromStr};
+
+    use common::diagnostic::{ParseError, Span};
+    use common::model::Value;
+
+    use graph::Graph;
+
+    use crate::{
+        broadcast::Broadcast, conjunction::Conjunction, flip_flop::FlipFlop, Module, ModuleName,
+        Pulse,
+    };
+
+    use std::collections::HashMap;
+
+    #[derive(Debug)]
+    pub enum ParseModuleNetworkError {
+        UnknownModuleType(Span),
+        FileFormatError(Span),
+    }
+
+    impl Display for ParseModuleNetworkError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                ParseModuleNetworkError::UnknownModuleType(span) => {
+                    write!(f, "unknown module type {:?}", span.snippet())
+                }
+                ParseModuleNetworkError::FileFormatError(_) => {
+                    write!(
+                        f,
+                        "expected a module and its destinations separated by \" -> \""
+                    )
+                }
+            }
+        }
+    }
+
+    impl Error for ParseModuleNetworkError {}
+
+    impl ParseError for ParseModuleNetworkError {
+        fn span(&self) -> Option<&Span> {
+            match self {
+                ParseModuleNetworkError::UnknownModuleType(span)
+                | ParseModuleNetworkError::FileFormatError(span) => Some(span),
+            }
+        }
+    }
+
+    type Modules = HashMap<ModuleName, Box<dyn Module>>;
+    type Connections = HashMap<ModuleName, Vec<ModuleName>>;
+
+    #[derive(Debug, Clone)]
+    pub struct ModuleNetwork {
+        modules: Modules,
+        connections: Connections,
+        /// Which loop each module that is part of a loop of modules is in.
+        /// Each loop counts button pushes, sending a low pulse out of the
+        /// loop when the count reaches its target.
+        loops: HashMap<ModuleName, usize>,
+        total_low_pulses: u64,
+        total_high_pulses: u64,
+        total_button_pushes: u64,
+    }
+
+    const BROADCASTER_NAME: &str = "broadcaster";
+    pub(crate) const MACHINE_NAME: &str = "rx";
+
+    /// What happened on one push of the button.
+    #[derive(Debug, Default)]
+    pub(crate) struct ButtonPush {
+        /// The loops that sent a low pulse out, by index.
+        pub(crate) loops_sent_low_pulse_out: Vec<usize>,
+        /// Whether the machine at the end was sent a low pulse.
+        pub(crate) turned_on_machine: bool,
+    }
+
+    impl ModuleNetwork {
+        fn new(modules: Modules, connections: Connections) -> ModuleNetwork {
+            let roots = connections.keys().cloned();
+            let loops = graph::strongly_connected_components(&Wiring(&connections), roots)
+                .into_iter()
+                .filter(|component| component.len() > 1)
+                .enumerate()
+                .flat_map(|(index, component)| {
+                    component.into_iter().map(move |module| (module, index))
+                })
+                .collect();
+
+            ModuleNetwork {
+                modules,
+                connections,
+                loops,
+                total_low_pulses: 0,
+                total_high_pulses: 0,
+                total_button_pushes: 0,
+            }
+        }
+
+        pub(crate) fn get_number_of_loops(&self) -> usize {
+            self.loops.values().max().map_or(0, |index| index + 1)
+        }
+
+        /// Whether any module sends pulses to `module`, which may not be a
+        /// module itself, like the machine at the end.
+        pub(crate) fn is_connected_to(&self, module: &str) -> bool {
+            self.connections
+                .values()
+                .flatten()
+                .any(|receiver| receiver.0 == module)
+        }
+
+        /// Whether a pulse from `sender` to `receiver` leaves one of the
+        /// loops.
+        fn leaves_loop(&self, sender: &ModuleName, receiver: &ModuleName) -> bool {
+            self.loops
+                .get(sender)
+                .is_some_and(|index| self.loops.get(receiver) != Some(index))
+        }
+
+        /// Sends pulses until they stop, noting what part two watches for.
+        pub(crate) fn push_button(&mut self) -> ButtonPush {
+            self.total_button_pushes += 1;
+            let mut button_push = ButtonPush::default();
+
+            const INITIAL_PULSE: Pulse = Pulse::Low;
+            let initial_receiver: ModuleName = ModuleName(BROADCASTER_NAME.to_string());
+
+            let initial_sender: ModuleName = ModuleName("button".to_string());
+
+            let mut pulse_queue = VecDeque::new();
+            pulse_queue.push_back((&initial_sender, &initial_receiver, INITIAL_PULSE));
+
+            while let Some((sender, receiver, pulse)) = pulse_queue.pop_front() {
+                match pulse {
+                    Pulse::High => self.total_high_pulses += 1,
+                    Pulse::Low => self.total_low_pulses += 1,
+                };
+
+                tracing::trace!(
+                    sender = sender.0,
+                    ?pulse,
+                    receiver = receiver.0,
+                    "sent pulse"
+                );
+                if pulse == Pulse::Low && receiver.0 == MACHINE_NAME {
+                    button_push.turned_on_machine = true;
+                }
+                if let Some(module) = self.modules.get_mut(receiver) {
+                    if let Some(next_pulse) = module.process(sender, pulse) {
+                        let next_receivers = self.connections.get(receiver).unwrap();
+
+                        for next_receiver in next_receivers {
+                            if next_pulse == Pulse::Low && self.leaves_loop(receiver, next_receiver)
+                            {
+                                tracing::debug!(
+                                    module = receiver.0,
+                                    button_pushes = self.total_button_pushes,
+                                    "loop sent a low pulse out"
+                                );
+                                button_push
+                                    .loops_sent_low_pulse_out
+                                    .push(self.loops[receiver]);
+                            }
+
+                            pulse_queue.push_back((receiver, next_receiver, next_pulse));
+                        }
+                    }
+                }
+            }
+
+            button_push
+        }
+
+        pub(crate) fn get_total_low_pulses_sent(&self) -> u64 {
+            self.total_low_pulses
+        }
+
+        pub(crate) fn get_total_high_pulses_sent(&self) -> u64 {
+            self.total_high_pulses
+        }
+
+        pub(crate) fn get_total_button_pushes(&self) -> u64 {
+            self.total_button_pushes
+        }
+
+        pub(crate) fn get_number_of_modules(&self) -> usize {
+            self.modules.len()
+        }
+
+        /// Every module keyed by name, in order, with its state and the
+        /// modules it sends pulses to.
+        pub(crate) fn to_value(&self) -> Value {
+            let mut modules: Vec<(&ModuleName, &Box<dyn Module>)> = self.modules.iter().collect();
+            modules.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+
+            Value::record([(
+                "modules",
+                Value::record(modules.into_iter().map(|(name, module)| {
+                    let destinations =
+                        self.connections
+                            .get(name)
+                            .map_or(Value::List(Vec::new()), |connections| {
+                                Value::list(
+                                    connections.iter().map(|connection| connection.0.as_str()),
+                                )
+                            });
+
+                    (
+                        name.0.as_str(),
+                        Value::record([
+                            ("kind", module.kind().into()),
+                            ("state", module.state()),
+                            ("destinations", destinations),
+                        ]),
+                    )
+                })),
+            )])
+        }
+    }
+
+    /// The modules as a graph, with each module leading to the modules it
+    /// sends pulses to.
+    struct Wiring<'a>(&'a Connections);
+
+    impl Graph for Wiring<'_> {
+        type Node = ModuleName;
+        type Cost = u32;
+
+        fn neighbours(&self, module: &ModuleName) -> Vec<(ModuleName, u32)> {
+            self.0.get(module).map_or(Vec::new(), |connections| {
+                connections
+                    .iter()
+                    .map(|connection| (connection.clone(), 1))
+                    .collect()
+            })
+        }
+    }
+
+    type IntermediateParseResult<'a> = Vec<(ModuleName, &'a str, Vec<ModuleName>)>;
+
+    impl FromStr for ModuleNetwork {
+        type Err = ParseModuleNetworkError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let intermediate_parse_result: Result<
+                IntermediateParseResult,
+                ParseModuleNetworkError,
+            > = s
+                .split('\n')
+                .filter(|line| !line.is_empty())
+                .map(|line| {
+                    let [module_str, connections_str] = line
+                        .split(" -> ")
+                        .collect::<Vec<&str>>()
+                        .try_into()
+                        .map_err(|_| ParseModuleNetworkError::FileFormatError(Span::of(line)))?;
+
+                    let connections: Vec<ModuleName> = connections_str
+                        .split(", ")
+                        .map(|connection_str| ModuleName(connection_str.to_string()))
+                        .collect();
+
+                    let module_name;
+                    let module_type_str;
+                    if module_str == BROADCASTER_NAME {
+                        module_name = ModuleName(module_str.to_string());
+                        module_type_str = module_str;
+                    } else {
+                        let type_length = module_str.chars().next().map_or(0, char::len_utf8);
+                        let name_str;
+                        (module_type_str, name_str) = module_str.split_at(type_length);
+                        module_name = ModuleName(name_str.to_string());
+                    }
+
+                    Ok((module_name, module_type_str, connections))
+                })
+                .collect();
+
+            let intermediate_parse = intermediate_parse_result?;
+
+            let modules = intermediate_parse
+                .iter()
+                .map(|(module_name, module_type_str, _)| -> Result<(ModuleName, Box<dyn Module>), ParseModuleNetworkError> {
+                    let module: Result<Box<dyn Module>, ParseModuleNetworkError> =
+                        match *module_type_str {
+                            BROADCASTER_NAME => Ok(Box::new(Broadcast::new())),
+                            "%" => Ok(Box::new(FlipFlop::new())),
+                            "&" => {
+                                let mut conjunction = Conjunction::new();
+                                intermediate_parse
+                                    .iter()
+                                    .filter(|(_, _, connections)| {
+                                        connections.contains(module_name)
+                                    })
+                                    .for_each(|(other_module, _, _)| {
+                                        conjunction.connect(other_module.clone())
+                                    });
+                                Ok(Box::new(conjunction))
+                            }
+                            _ => Err(ParseModuleNetworkError::UnknownModuleType(Span::of(
+                                module_type_str,
+                            ))),
+                        };
+
+                    Ok((module_name.clone(), module?))
+                })
+                .collect::<Result<HashMap<ModuleName, Box<dyn Module>>, ParseModuleNetworkError>>()?;
+
+            let connections = intermediate_parse
+                .into_iter()
+                .map(|(module_name, _, connections)| (module_name, connections))
+                .collect();
+
+            let module_network = ModuleNetwork::new(modules, connections);
+
+            Ok(module_network)
+        }
+    }
+}
+
+mod flip_flop {
+    use common::model::Value;
+
+    use super::Module;
+    use super::Pulse;
+    use crate::ModuleName;
+
+    #[derive(Debug, Clone)]
+    pub(crate) enum FlipFlopState {
+        On,
+        Off,
+    }
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct FlipFlop {
+        state: FlipFlopState,
+    }
+
+    const INITIAL_STATE: FlipFlopState = FlipFlopState::Off;
+
+    impl FlipFlop {
+        pub fn new() -> FlipFlop {
+            FlipFlop {
+                state: INITIAL_STATE,
+            }
+        }
+    }
+
+    impl Module for FlipFlop {
+        fn process(&mut self, _from: &ModuleName, pulse: Pulse) -> Option<Pulse> {
+            match pulse {
+                Pulse::High => None,
+                Pulse::Low => match self.state {
+                    FlipFlopState::On => {
+                        self.state = FlipFlopState::Off;
+                        Some(Pulse::Low)
+                    }
+                    FlipFlopState::Off => {
+                        self.state = FlipFlopState::On;
+                        Some(Pulse::High)
+                    }
+                },
+            }
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Module> {
+            Box::new(self.clone())
+        }
+
+        fn kind(&self) -> &'static str {
+            "flip_flop"
+        }
+
+        fn state(&self) -> Value {
+            match self.state {
+                FlipFlopState::On => "on".into(),
+                FlipFlopState::Off => "off".into(),
+            }
+        }
+    }
+}
+
+mod conjunction {
+    use common::model::Value;
+
+    use crate::ModuleName;
+
+    use super::Module;
+
+    use super::Pulse;
+
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct Conjunction {
+        memory: HashMap<ModuleName, Pulse>,
+    }
+
+    impl Conjunction {
+        pub fn new() -> Conjunction {
+            let memory = HashMap::new();
+
+            Conjunction { memory }
+        }
+
+        const DEFAULT_PULSE: Pulse = Pulse::Low;
+
+        pub fn connect(&mut self, module: ModuleName) {
+            let _ = &mut self.memory.insert(module, Conjunction::DEFAULT_PULSE);
+        }
+    }
+
+    impl Module for Conjunction {
+        fn process(&mut self, from: &ModuleName, pulse: Pulse) -> Option<Pulse> {
+            let last_pulse = self.memory.get_mut(from).unwrap();
+            *last_pulse = pulse;
+
+            if self.memory.values().all(|pulse| *pulse == Pulse::High) {
+                Some(Pulse::Low)
+            } else {
+                Some(Pulse::High)
+            }
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Module> {
+            Box::new(self.clone())
+        }
+
+        fn kind(&self) -> &'static str {
+            "conjunction"
+        }
+
+        /// The last pulse from each input, by input name.
+        fn state(&self) -> Value {
+            let mut memory: Vec<(&ModuleName, &Pulse)> = self.memory.iter().collect();
+            memory.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+
+            Value::record(
+                memory
+                    .into_iter()
+                    .map(|(input, pulse)| (input.0.as_str(), (*pulse).into())),
+            )
+        }
+    }
+}
+
+mod broadcast {
+    use common::model::Value;
+
+    use crate::ModuleName;
+
+    use super::Pulse;
+
+    use super::Module;
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct Broadcast {}
+
+    impl Broadcast {
+        pub(crate) fn new() -> Broadcast {
+            Broadcast {}
+        }
+    }
+
+    impl Module for Broadcast {
+        fn process(&mut self, _from: &ModuleName, pulse: Pulse) -> Option<Pulse> {
+            Some(pulse)
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Module> {
+            Box::new(self.clone())
+        }
+
+        fn kind(&self) -> &'static str {
+            "broadcast"
+        }
+
+        fn state(&self) -> Value {
+            Value::Null
+        }
+    }
+}
+
+pub struct Day;
+
+const PART_1_BUTTON_PUSHES: Parameter = Parameter {
+    name: "part_one_button_pushes",
+    description: "How many times the button is pushed in part one.",
+    default: 1000,
+    range: 0..=1_000_000,
+};
+
+const PART_2_BUTTON_PUSHES: Parameter = Parameter {
+    name: "part_two_button_pushes",
+    description: "The most times the button is pushed in part two while timing each loop.",
+    default: 20000,
+    range: 0..=1_000_000,
+};
+
+impl Solution for Day {
+    type Input = ModuleNetwork;
+    type Error = ParseModuleNetworkError;
+
+    const PARAMETERS: &'static [Parameter] = &[PART_1_BUTTON_PUSHES, PART_2_BUTTON_PUSHES];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let module_network: ModuleNetwork = input.parse()?;
+        tracing::debug!(
+            modules = module_network.get_number_of_modules(),
+            "parsed module network"
+        );
+
+        Ok(module_network)
+    }
+
+    fn part_one(module_network: &Self::Input, parameters: &Parameters) -> Answer {
+        let mut module_network = module_network.clone();
+
+        for _ in 0..parameters.get(&PART_1_BUTTON_PUSHES) {
+            module_network.push_button();
+        }
+
+        module_network
+            .get_total_low_pulses_sent()
+            .try_mul(module_network.get_total_high_pulses_sent())
+            .into()
+    }
+
+    /// The machine is fed by loops of modules that each count button pushes
+    /// and send a low pulse out when they reach their target, starting again
+    /// from zero. It turns on when every loop does so on the same push.
+    fn part_two(module_network: &Self::Input, parameters: &Parameters) -> Answer {
+        if !module_network.is_connected_to(MACHINE_NAME) {
+            return Answer::Unavailable;
+        }
+
+        let mut module_network = module_network.clone();
+        // The first two pushes each loop sends a low pulse out on.
+        let mut pulse_pushes: Vec<Vec<u64>> =
+            vec![Vec::new(); module_network.get_number_of_loops()];
+
+        for _ in 0..parameters.get(&PART_2_BUTTON_PUSHES) {
+            let button_push = module_network.push_button();
+            let button_pushes = module_network.get_total_button_pushes();
+
+            if button_push.turned_on_machine {
+                return button_pushes.into();
+            }
+
+            for index in button_push.loops_sent_low_pulse_out {
+                let pushes = &mut pulse_pushes[index];
+                if pushes.len() < 2 && pushes.last() != Some(&button_pushes) {
+                    pushes.push(button_pushes);
+                }
+            }
+
+            if pulse_pushes.iter().all(|pushes| pushes.len() == 2) {
+                break;
+            }
+        }
+
+        // Only a loop that starts again from zero sends its pulse out every
+        // time its first count comes round.
+        let periods: Option<Vec<u64>> = pulse_pushes
+            .iter()
+            .map(|pushes| match pushes[..] {
+                [first, second] if second == first * 2 => Some(first),
+                _ => None,
+            })
+            .collect();
+
+        match periods {
+            Some(periods) if !periods.is_empty() => number_theory::lcm_all(periods).into(),
+            _ => Answer::Unavailable,
+        }
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(module_network: &Self::Input) -> Value {
+        module_network.to_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn pulse_counts_multiply_past_a_u32() {
+        let flip_flops: Vec<String> = (0..300).map(|i| format!("f{}", i)).collect();
+        let mut configuration = format!("broadcaster -> {}\n", flip_flops.join(", "));
+        for flip_flop in &flip_flops {
+            configuration.push_str(&format!("%{} -> output\n", flip_flop));
+        }
+        let module_network = Day::parse(&configuration).unwrap();
+
+        assert_eq!(
+            Day::part_one(&module_network, &Parameters::default()),
+            Answer::from(451_000u64 * 150_000)
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug)]
+enum SchematicPart {
+    Number(u32),
+    Symbol(SymbolType),
+}
+
+#[derive(Debug)]
+enum SymbolType {
+    Star,
+    Other,
+}
+
+#[derive(Debug)]
+pub struct EngineSchematic {
+    parts: Vec<SchematicPart>,
+    part_lookup: HashMap<Position, usize>,
+}
+
+#[derive(Debug)]
+pub enum ParseEngineSchematicError {
+    InvalidPartNumber(Span, ParseIntError),
+}
+
+impl Display for ParseEngineSchematicError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseEngineSchematicError::InvalidPartNumber(span, _) => {
+                write!(f, "invalid part number {:?}", span.snippet())
+            }
+        }
+    }
+}
+
+impl Error for ParseEngineSchematicError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseEngineSchematicError::InvalidPartNumber(_, error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseEngineSchematicError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseEngineSchematicError::InvalidPartNumber(span, _) => Some(span),
+        }
+    }
+}
+
+impl FromStr for EngineSchematic {
+    type Err = ParseEngineSchematicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut part_lookup = HashMap::new();
+        let mut parts = Vec::new();
+
+        let mut x = 0;
+        let mut y = 0;
+
+        let mut input_iter = s.char_indices().peekable();
+        while let Some((start, character)) = input_iter.next() {
+            if character == '\n' {
+                y += 1;
+                x = 0;
+                continue;
+            }
+
+            if character == '.' {
+                x += 1;
+                continue;
+            }
+
+            let new_part_index = parts.len();
+
+            if character.is_numeric() {
+                let mut end = start + character.len_utf8();
+                let mut number_length = 1;
+
+                while let Some((i, digit)) =
+                    input_iter.next_if(|(_, maybe_digit)| maybe_digit.is_numeric())
+                {
+                    end = i + digit.len_utf8();
+                    number_length += 1;
+                }
+
+                let part_number_str = &s[start..end];
+                let part_number = part_number_str.parse::<u32>().map_err(|error| {
+                    ParseEngineSchematicError::InvalidPartNumber(Span::of(part_number_str), error)
+                })?;
+
+                parts.push(SchematicPart::Number(part_number));
+
+                for i in 0..number_length {
+                    let position = Position { x: x + i, y };
+
+                    part_lookup.insert(position, new_part_index);
+                }
+
+                x += number_length;
+                continue;
+            }
+
+            // Assume the character to be a symbol.
+            let symbol_type = if character == '*' {
+                SymbolType::Star
+            } else {
+                SymbolType::Other
+            };
+            parts.push(SchematicPart::Symbol(symbol_type));
+            part_lookup.insert(Position { x, y }, new_part_index);
+            x += 1;
+        }
+
+        Ok(EngineSchematic { parts, part_lookup })
+    }
+}
+
+#[derive(Debug)]
+struct Gear {
+    gear_ratio: u64,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = EngineSchematic;
+    type Error = ParseEngineSchematicError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let engine_schematic: EngineSchematic = input.parse()?;
+        tracing::debug!(
+            parts = engine_schematic.parts.len(),
+            "parsed engine schematic"
+        );
+
+        Ok(engine_schematic)
+    }
+
+    fn part_one(engine_schematic: &Self::Input, _: &Parameters) -> Answer {
+        let part_numbers = get_part_numbers(engine_schematic);
+        tracing::debug!(part_numbers = part_numbers.len(), "found part numbers");
+
+        part_numbers.into_iter().map(u64::from).checked_sum().into()
+    }
+
+    fn part_two(engine_schematic: &Self::Input, _: &Parameters) -> Answer {
+        let Ok(gears) = get_gears(engine_schematic) else {
+            return Answer::Overflow;
+        };
+        tracing::debug!(gears = gears.len(), "found gears");
+
+        gears
+            .iter()
+            .map(|gear| gear.gear_ratio)
+            .checked_sum()
+            .into()
+    }
+}
+
+/// Each part of the schematic along with the positions it covers.
+impl Inspect for Day {
+    fn inspect(engine_schematic: &Self::Input) -> Value {
+        let mut positions: Vec<Vec<&Position>> = vec![Vec::new(); engine_schematic.parts.len()];
+        for (position, &part_index) in &engine_schematic.part_lookup {
+            positions[part_index].push(position);
+        }
+
+        let parts = engine_schematic
+            .parts
+            .iter()
+            .zip(positions)
+            .map(|(part, mut positions)| {
+                positions.sort_by_key(|position| (position.y, position.x));
+
+                let (kind, value) = match part {
+                    SchematicPart::Number(number) => ("number", (*number).into()),
+                    SchematicPart::Symbol(SymbolType::Star) => ("star", Value::Null),
+                    SchematicPart::Symbol(SymbolType::Other) => ("symbol", Value::Null),
+                };
+
+                Value::record([
+                    ("kind", kind.into()),
+                    ("value", value),
+                    (
+                        "positions",
+                        Value::list(positions.iter().map(|position| (position.x, position.y))),
+                    ),
+                ])
+            });
+
+        Value::record([("parts", Value::list(parts))])
+    }
+}
+
+fn get_part_numbers(schematic: &EngineSchematic) -> Vec<u32> {
+    let mut part_indexes: HashSet<usize> = HashSet::new();
+
+    for (position, part_index) in schematic.part_lookup.iter() {
+        if let SchematicPart::Symbol(_) = schematic.parts[*part_index] {
+            let adjacent_part_indexes = get_adjacent_part_indexes(&schematic.part_lookup, position);
+            part_indexes.extend(adjacent_part_indexes)
+        }
+    }
+
+    part_indexes
+        .into_iter()
+        .filter_map(|index| get_part_number(schematic, index))
+        .collect()
+}
+
+/// The number of the part at `index`, or `None` if it is a symbol, which
+/// can be next to another symbol.
+fn get_part_number(schematic: &EngineSchematic, index: usize) -> Option<u32> {
+    match schematic.parts[index] {
+        SchematicPart::Number(part_number) => Some(part_number),
+        SchematicPart::Symbol(_) => None,
+    }
+}
+
+fn get_adjacent_part_indexes(
+    part_lookup: &HashMap<Position, usize>,
+    position: &Position,
+) -> Vec<usize> {
+    let mut adjacent_part_indexes = HashSet::new();
+
+    for dx in -1..2 {
+        let x = position.x as i32 + dx;
+
+        if x.is_negative() {
+            continue;
+        }
+
+        for dy in -1..2 {
+            let y = position.y as i32 + dy;
+
+            if y.is_negative() {
+                continue;
+            }
+
+            if (dx == 0) && (dy == 0) {
+                continue;
+            }
+
+            let search_position = Position {
+                x: x as usize,
+                y: y as usize,
+            };
+
+            match part_lookup.get(&search_position) {
+                Some(part_index) => adjacent_part_indexes.insert(*part_index),
+                _ => continue,
+            };
+        }
+    }
+
+    adjacent_part_indexes.into_iter().collect()
+}
+
+fn get_gears(schematic: &EngineSchematic) -> Result<Vec<Gear>, Overflow> {
+    let mut gears = Vec::new();
+
+    for (position, part_index) in schematic.part_lookup.iter() {
+        if let SchematicPart::Symbol(SymbolType::Star) = schematic.parts[*part_index] {
+            let adjacent_part_numbers: Vec<u32> =
+                get_adjacent_part_indexes(&schematic.part_lookup, position)
+                    .into_iter()
+                    .filter_map(|index| get_part_number(schematic, index))
+                    .collect();
+
+            if adjacent_part_numbers.len() != 2 {
+                continue;
+            }
+
+            let gear_ratio = adjacent_part_numbers
+                .into_iter()
+                .map(u64::from)
+                .checked_product()?;
+
+            gears.push(Gear { gear_ratio });
+        }
+    }
+
+    Ok(gears)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn sums_more_than_fit_in_a_u32() {
+        let schematic = Day::parse("4000000000*4000000000\n").unwrap();
+
+        assert_eq!(
+            Day::part_one(&schematic, &Parameters::default()),
+            Answer::from(8_000_000_000u64)
+        );
+        assert_eq!(
+            Day::part_two(&schematic, &Parameters::default()),
+            Answer::from(16_000_000_000_000_000_000u64)
+        );
+    }
+
+    #[test]
+    fn gear_ratios_can_overflow() {
+        let schematic = Day::parse(&"4000000000*4000000000\n.\n".repeat(2)).unwrap();
+
+        assert_eq!(
+            Day::part_two(&schematic, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+
+    #[test]
+    fn skips_symbols_next_to_symbols() {
+        let schematic = Day::parse("12*#\n..*3\n").unwrap();
+
+        assert_eq!(
+            Day::part_one(&schematic, &Parameters::default()),
+            Answer::from(15u64)
+        );
+        assert_eq!(
+            Day::part_two(&schematic, &Parameters::default()),
+            Answer::from(72u64)
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use common::checked::Checked;
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug)]
+struct Numbers {
+    values: Vec<u32>,
+}
+
+#[derive(Debug)]
+pub struct ParseNumbersError(Span, ParseIntError);
+
+impl Display for ParseNumbersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid number {:?}", self.0.snippet())
+    }
+}
+
+impl Error for ParseNumbersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.1)
+    }
+}
+
+impl ParseError for ParseNumbersError {
+    fn span(&self) -> Option<&Span> {
+        Some(&self.0)
+    }
+}
+
+impl FromStr for Numbers {
+    type Err = ParseNumbersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_result: Result<Vec<u32>, ParseNumbersError> = s
+            .split_whitespace()
+            .map(|num| {
+                num.parse()
+                    .map_err(|error| ParseNumbersError(Span::of(num), error))
+            })
+            .collect();
+
+        let values = parse_result?;
+
+        Ok(Numbers { values })
+    }
+}
+
+#[derive(Debug)]
+pub struct Scratchcard {
+    id: u8,
+    winning_numbers: Numbers,
+    player_numbers: Numbers,
+}
+
+#[derive(Debug)]
+pub enum ParseScratchcardError {
+    MissingScratchcardId(Span),
+    MissingNumbers(Span),
+    MissingPlayerNumbers(Span),
+    ParseScratchcardIdError(Span, ParseIntError),
+    ParseWinningNumbersError(ParseNumbersError),
+    ParsePlayerNumbersError(ParseNumbersError),
+}
+
+impl Display for ParseScratchcardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseScratchcardError::MissingScratchcardId(_) => {
+                write!(f, "expected \"Card\" and an id")
+            }
+            ParseScratchcardError::MissingNumbers(_) => {
+                write!(f, "expected the numbers after \": \"")
+            }
+            ParseScratchcardError::MissingPlayerNumbers(_) => {
+                write!(f, "expected the player numbers after \" | \"")
+            }
+            ParseScratchcardError::ParseScratchcardIdError(_, _) => {
+                write!(f, "invalid scratchcard id")
+            }
+            ParseScratchcardError::ParseWinningNumbersError(_) => {
+                write!(f, "invalid winning numbers")
+            }
+            ParseScratchcardError::ParsePlayerNumbersError(_) => {
+                write!(f, "invalid player numbers")
+            }
+        }
+    }
+}
+
+impl Error for ParseScratchcardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseScratchcardError::MissingScratchcardId(_)
+            | ParseScratchcardError::MissingNumbers(_)
+            | ParseScratchcardError::MissingPlayerNumbers(_) => None,
+            ParseScratchcardError::ParseScratchcardIdError(_, error) => Some(error),
+            ParseScratchcardError::ParseWinningNumbersError(error)
+            | ParseScratchcardError::ParsePlayerNumbersError(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseScratchcardError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseScratchcardError::MissingScratchcardId(span)
+            | ParseScratchcardError::MissingNumbers(span)
+            | ParseScratchcardError::MissingPlayerNumbers(span)
+            | ParseScratchcardError::ParseScratchcardIdError(span, _) => Some(span),
+            ParseScratchcardError::ParseWinningNumbersError(error)
+            | ParseScratchcardError::ParsePlayerNumbersError(error) => error.span(),
+        }
+    }
+}
+
+impl FromStr for Scratchcard {
+    type Err = ParseScratchcardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (card_side, number_side) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseScratchcardError::MissingNumbers(Span::end_of(s)))?;
+
+        let id_str = card_side
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(|| ParseScratchcardError::MissingScratchcardId(Span::of(card_side)))?;
+        let id = id_str.parse().map_err(|parse_int_error| {
+            ParseScratchcardError::ParseScratchcardIdError(Span::of(id_str), parse_int_error)
+        })?;
+
+        let (winning_side, player_side) = number_side.split_once(" | ").ok_or_else(|| {
+            ParseScratchcardError::MissingPlayerNumbers(Span::end_of(number_side))
+        })?;
+
+        let winning_numbers = winning_side.parse().map_err(|parse_int_error| {
+            ParseScratchcardError::ParseWinningNumbersError(parse_int_error)
+        })?;
+
+        let player_numbers = player_side.parse().map_err(|parse_int_error| {
+            ParseScratchcardError::ParsePlayerNumbersError(parse_int_error)
+        })?;
+
+        Ok(Scratchcard {
+            id,
+            winning_numbers,
+            player_numbers,
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Scratchcard>;
+    type Error = ParseScratchcardError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let lines: Vec<&str> = input.split('\n').filter(|line| line != &"").collect();
+
+        let scratchcards: Vec<Scratchcard> = lines
+            .iter()
+            .map(|s| Scratchcard::from_str(s))
+            .collect::<Result<Vec<Scratchcard>, ParseScratchcardError>>(
+        )?;
+
+        tracing::debug!(scratchcards = scratchcards.len(), "parsed scratchcards");
+
+        Ok(scratchcards)
+    }
+
+    fn part_one(scratchcards: &Self::Input, _: &Parameters) -> Answer {
+        scratchcards
+            .iter()
+            .map(get_scratchcard_points)
+            .collect::<Result<Vec<u64>, Overflow>>()
+            .and_then(|points| points.into_iter().checked_sum())
+            .into()
+    }
+
+    fn part_two(scratchcards: &Self::Input, _: &Parameters) -> Answer {
+        let matches: Vec<usize> = scratchcards.iter().map(get_number_of_matches).collect();
+        tracing::debug!(
+            winners = matches.iter().filter(|&&matches| matches > 0).count(),
+            "counted matches"
+        );
+
+        matches
+            .iter()
+            .enumerate()
+            .rev()
+            .try_fold(
+                vec![1u64; scratchcards.len()],
+                |mut cards_won, (index, number_of_matches)| {
+                    let max_index = scratchcards.len() - 1;
+
+                    if index == max_index {
+                        return Ok(cards_won);
+                    }
+
+                    let start_index = index + 1;
+                    let last_index = (start_index + number_of_matches).min(max_index + 1);
+
+                    let new_cards = cards_won
+                        .get((index + 1)..last_index)
+                        .unwrap()
+                        .iter()
+                        .cloned()
+                        .checked_sum()?;
+
+                    cards_won[index] = cards_won[index].try_add(new_cards)?;
+                    Ok(cards_won)
+                },
+            )
+            .and_then(|copies| copies.into_iter().checked_sum())
+            .into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(scratchcards: &Self::Input) -> Value {
+        Value::list(scratchcards)
+    }
+}
+
+impl From<&Scratchcard> for Value {
+    fn from(scratchcard: &Scratchcard) -> Self {
+        Value::record([
+            ("id", scratchcard.id.into()),
+            (
+                "winning_numbers",
+                Value::list(&scratchcard.winning_numbers.values),
+            ),
+            (
+                "player_numbers",
+                Value::list(&scratchcard.player_numbers.values),
+            ),
+        ])
+    }
+}
+
+fn get_scratchcard_points(scratchcard: &Scratchcard) -> Result<u64, Overflow> {
+    let number_of_matches = get_number_of_matches(scratchcard);
+
+    if number_of_matches == 0 {
+        return Ok(0);
+    }
+
+    const BASE_2: u64 = 2;
+
+    u32::try_from(number_of_matches - 1)
+        .ok()
+        .and_then(|exponent| BASE_2.checked_pow(exponent))
+        .ok_or(Overflow)
+}
+
+fn get_number_of_matches(scratchcard: &Scratchcard) -> usize {
+    scratchcard
+        .player_numbers
+        .values
+        .iter()
+        .filter(|player_number| scratchcard.winning_numbers.values.contains(player_number))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    fn scratchcard(id: usize, matches: u32) -> String {
+        let numbers: Vec<String> = (1..=matches).map(|number| number.to_string()).collect();
+        let numbers = numbers.join(" ");
+
+        format!("Card {}: {} | {}\n", id, numbers, numbers)
+    }
+
+    #[test]
+    fn points_can_go_past_a_u32() {
+        let scratchcards = Day::parse(&scratchcard(1, 40)).unwrap();
+
+        assert_eq!(
+            Day::part_one(&scratchcards, &Parameters::default()),
+            Answer::from(1u64 << 39)
+        );
+    }
+
+    #[test]
+    fn copies_can_overflow() {
+        let scratchcards: String = (1..=100).map(|id| scratchcard(id, 99)).collect();
+        let scratchcards = Day::parse(&scratchcards).unwrap();
+
+        assert_eq!(
+            Day::part_one(&scratchcards, &Parameters::default()),
+            Answer::Overflow
+        );
+        assert_eq!(
+            Day::part_two(&scratchcards, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+}
@@ -0,0 +1,678 @@
+use common::checked::Checked;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use interval::Interval;
+use interval::IntervalSet;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds_to_be_planted: Vec<SeedId>,
+
+    seed_to_soil_map: AlmanacMap<SeedId, SoilId>,
+    soil_to_fertiliser_map: AlmanacMap<SoilId, FertiliserId>,
+    fertiliser_to_water_map: AlmanacMap<FertiliserId, WaterId>,
+    water_to_light_map: AlmanacMap<WaterId, LightId>,
+    light_to_temperature_map: AlmanacMap<LightId, TemperatureId>,
+    temperature_to_humidity_map: AlmanacMap<TemperatureId, HumidityId>,
+    humidity_to_location_map: AlmanacMap<HumidityId, LocationId>,
+}
+
+#[derive(Debug)]
+pub enum ParseAlmanacError {
+    AlmanacFormatError(Span),
+    InvalidSeed(Span, ParseIntError),
+    UnpairedSeed(Span),
+    MissingMap(Span),
+    AlmanacMapError(ParseAlmanacMapItemError),
+}
+
+impl Display for ParseAlmanacError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseAlmanacError::AlmanacFormatError(_) => write!(f, "missing the seeds to plant"),
+            ParseAlmanacError::InvalidSeed(span, _) => {
+                write!(f, "invalid seed number {:?}", span.snippet())
+            }
+            ParseAlmanacError::UnpairedSeed(_) => write!(
+                f,
+                "expected the seeds in pairs of a first seed and a range length"
+            ),
+            ParseAlmanacError::MissingMap(_) => write!(f, "expected seven almanac maps"),
+            ParseAlmanacError::AlmanacMapError(_) => write!(f, "invalid almanac map"),
+        }
+    }
+}
+
+impl Error for ParseAlmanacError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseAlmanacError::AlmanacFormatError(_)
+            | ParseAlmanacError::UnpairedSeed(_)
+            | ParseAlmanacError::MissingMap(_) => None,
+            ParseAlmanacError::InvalidSeed(_, error) => Some(error),
+            ParseAlmanacError::AlmanacMapError(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseAlmanacError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseAlmanacError::AlmanacFormatError(span)
+            | ParseAlmanacError::InvalidSeed(span, _)
+            | ParseAlmanacError::UnpairedSeed(span)
+            | ParseAlmanacError::MissingMap(span) => Some(span),
+            ParseAlmanacError::AlmanacMapError(error) => error.span(),
+        }
+    }
+}
+
+impl From<ParseAlmanacMapItemError> for ParseAlmanacError {
+    fn from(value: ParseAlmanacMapItemError) -> Self {
+        ParseAlmanacError::AlmanacMapError(value)
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseAlmanacError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parse_iterator = s.split("\n\n");
+
+        let seeds: &str = parse_iterator
+            .next()
+            .ok_or_else(|| ParseAlmanacError::AlmanacFormatError(Span::of(s)))?;
+
+        let seeds_to_be_planted: Vec<SeedId> = seeds
+            .split_whitespace()
+            .skip(1)
+            .map(|s| {
+                s.parse()
+                    .map_err(|error| ParseAlmanacError::InvalidSeed(Span::of(s), error))
+            })
+            .collect::<Result<Vec<SeedId>, ParseAlmanacError>>()?;
+
+        // Part two reads the seeds as ranges, so they have to pair up.
+        if !seeds_to_be_planted.len().is_multiple_of(2) {
+            return Err(ParseAlmanacError::UnpairedSeed(Span::of(seeds)));
+        }
+
+        let mut next_map = || {
+            parse_iterator
+                .next()
+                .ok_or_else(|| ParseAlmanacError::MissingMap(Span::end_of(s)))
+        };
+
+        let seed_to_soil_map = next_map()?.parse()?;
+        let soil_to_fertiliser_map = next_map()?.parse()?;
+        let fertiliser_to_water_map = next_map()?.parse()?;
+        let water_to_light_map = next_map()?.parse()?;
+        let light_to_temperature_map = next_map()?.parse()?;
+        let temperature_to_humidity_map = next_map()?.parse()?;
+        let humidity_to_location_map = next_map()?.parse()?;
+
+        Ok(Almanac {
+            seeds_to_be_planted,
+            seed_to_soil_map,
+            soil_to_fertiliser_map,
+            fertiliser_to_water_map,
+            water_to_light_map,
+            light_to_temperature_map,
+            temperature_to_humidity_map,
+            humidity_to_location_map,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AlmanacMap<SourceCategory, DestinationCategory> {
+    items: Vec<AlmanacMapItem<SourceCategory, DestinationCategory>>,
+}
+
+fn try_map_across_range(
+    source_number: u64,
+    source_range_start: u64,
+    destination_range_start: u64,
+    range_length: u64,
+) -> Result<Option<u64>, Overflow> {
+    if source_number >= source_range_start {
+        let distance_into_range = source_number - source_range_start;
+
+        if distance_into_range < range_length {
+            let destination_number = destination_range_start.try_add(distance_into_range)?;
+
+            return Ok(Some(destination_number));
+        }
+    }
+    Ok(None)
+}
+
+impl<SourceCategory: Ord + AlmanacNumber, DestinationCategory: Ord + AlmanacNumber>
+    AlmanacMap<SourceCategory, DestinationCategory>
+{
+    fn get(&self, source_id: &SourceCategory) -> Result<DestinationCategory, Overflow> {
+        let items = &self.items;
+
+        for map_item in items {
+            let source_number = source_id.get_value();
+            let source_range_start = map_item.source_range_start.get_value();
+            let destination_range_start = map_item.destination_range_start.get_value();
+
+            let maybe_destination_number = try_map_across_range(
+                source_number,
+                source_range_start,
+                destination_range_start,
+                map_item.range_length,
+            )?;
+
+            if let Some(destination_number) = maybe_destination_number {
+                return Ok(DestinationCategory::new(destination_number));
+            }
+        }
+
+        Ok(DestinationCategory::new(source_id.get_value()))
+    }
+
+    /// Where each of the `sources` ends up. Numbers that no item covers map
+    /// to themselves.
+    ///
+    /// Each call is traced, so `aoc run 5 --folded-stacks day_5.folded` shows how long
+    /// part two spends on each of the seven maps.
+    #[tracing::instrument(level = "debug", skip_all, fields(items = self.items.len()))]
+    fn get_all(&self, sources: &IntervalSet<u64>) -> Result<IntervalSet<u64>, Overflow> {
+        let mut unmapped = sources.clone();
+        let mut destinations = IntervalSet::new();
+
+        for map_item in &self.items {
+            let source_range_start = map_item.source_range_start.get_value();
+            let destination_range_start = map_item.destination_range_start.get_value();
+            let source_range = IntervalSet::from(Interval::starting_at(
+                source_range_start,
+                map_item.range_length,
+            )?);
+
+            for range in &unmapped.intersection(&source_range) {
+                let start = destination_range_start.try_add(range.start - source_range_start)?;
+
+                destinations.insert(Interval::starting_at(start, range.end - range.start)?);
+            }
+
+            unmapped = unmapped.difference(&source_range);
+        }
+
+        Ok(destinations.union(&unmapped))
+    }
+}
+
+impl<SourceCategory, DestinationCategory> FromStr
+    for AlmanacMap<SourceCategory, DestinationCategory>
+where
+    SourceCategory: FromStr<Err = ParseIntError>,
+    DestinationCategory: FromStr<Err = ParseIntError>,
+{
+    type Err = ParseAlmanacMapItemError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = s
+            .split('\n')
+            .filter(|s| s != &"")
+            .skip(1)
+            .map(|item_s| item_s.parse::<AlmanacMapItem<SourceCategory, DestinationCategory>>())
+            .collect::<Result<
+                Vec<AlmanacMapItem<SourceCategory, DestinationCategory>>,
+                ParseAlmanacMapItemError,
+            >>()?;
+
+        Ok(AlmanacMap { items })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AlmanacMapItem<SourceCategory, DestinationCategory> {
+    source_range_start: SourceCategory,
+    destination_range_start: DestinationCategory,
+    range_length: u64,
+}
+
+#[derive(Debug)]
+pub enum ParseAlmanacMapItemError {
+    MissingNumber(Span),
+    InvalidNumber(Span, ParseIntError),
+}
+
+impl Display for ParseAlmanacMapItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseAlmanacMapItemError::MissingNumber(_) => write!(
+                f,
+                "expected a destination start, source start and range length"
+            ),
+            ParseAlmanacMapItemError::InvalidNumber(span, _) => {
+                write!(f, "invalid number {:?} in almanac map", span.snippet())
+            }
+        }
+    }
+}
+
+impl Error for ParseAlmanacMapItemError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseAlmanacMapItemError::MissingNumber(_) => None,
+            ParseAlmanacMapItemError::InvalidNumber(_, error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseAlmanacMapItemError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseAlmanacMapItemError::MissingNumber(span)
+            | ParseAlmanacMapItemError::InvalidNumber(span, _) => Some(span),
+        }
+    }
+}
+
+fn parse_map_number<T: FromStr<Err = ParseIntError>>(
+    item: &str,
+    s: Option<&str>,
+) -> Result<T, ParseAlmanacMapItemError> {
+    let s = s.ok_or_else(|| ParseAlmanacMapItemError::MissingNumber(Span::end_of(item)))?;
+
+    s.parse()
+        .map_err(|error| ParseAlmanacMapItemError::InvalidNumber(Span::of(s), error))
+}
+
+impl<SourceCategory, DestinationCategory> FromStr
+    for AlmanacMapItem<SourceCategory, DestinationCategory>
+where
+    SourceCategory: FromStr<Err = ParseIntError>,
+    DestinationCategory: FromStr<Err = ParseIntError>,
+{
+    type Err = ParseAlmanacMapItemError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+
+        let destination_range_start = parse_map_number(s, split.next())?;
+        let source_range_start = parse_map_number(s, split.next())?;
+        let range_length = parse_map_number(s, split.next())?;
+
+        Ok(AlmanacMapItem {
+            destination_range_start,
+            source_range_start,
+            range_length,
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Almanac;
+    type Error = ParseAlmanacError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let almanac: Almanac = input.parse()?;
+        tracing::debug!(seeds = almanac.seeds_to_be_planted.len(), "parsed almanac");
+
+        Ok(almanac)
+    }
+
+    fn part_one(almanac: &Self::Input, _: &Parameters) -> Answer {
+        almanac
+            .seeds_to_be_planted
+            .iter()
+            .map(|seed_id| get_location_id(seed_id, almanac).map(|id| id.get_value()))
+            .collect::<Result<Vec<u64>, Overflow>>()
+            .map(|locations| locations.into_iter().min())
+            .into()
+    }
+
+    fn part_two(almanac: &Self::Input, _: &Parameters) -> Answer {
+        find_lowest_location_in_ranges(almanac).into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(almanac: &Self::Input) -> Value {
+        Value::record([
+            (
+                "seeds",
+                Value::list(
+                    almanac
+                        .seeds_to_be_planted
+                        .iter()
+                        .map(AlmanacNumber::get_value),
+                ),
+            ),
+            ("seed_to_soil", (&almanac.seed_to_soil_map).into()),
+            (
+                "soil_to_fertiliser",
+                (&almanac.soil_to_fertiliser_map).into(),
+            ),
+            (
+                "fertiliser_to_water",
+                (&almanac.fertiliser_to_water_map).into(),
+            ),
+            ("water_to_light", (&almanac.water_to_light_map).into()),
+            (
+                "light_to_temperature",
+                (&almanac.light_to_temperature_map).into(),
+            ),
+            (
+                "temperature_to_humidity",
+                (&almanac.temperature_to_humidity_map).into(),
+            ),
+            (
+                "humidity_to_location",
+                (&almanac.humidity_to_location_map).into(),
+            ),
+        ])
+    }
+}
+
+impl<S: AlmanacNumber, D: AlmanacNumber> From<&AlmanacMap<S, D>> for Value {
+    fn from(map: &AlmanacMap<S, D>) -> Self {
+        Value::list(map.items.iter().map(|item| {
+            Value::record([
+                (
+                    "source_range_start",
+                    item.source_range_start.get_value().into(),
+                ),
+                (
+                    "destination_range_start",
+                    item.destination_range_start.get_value().into(),
+                ),
+                ("range_length", item.range_length.into()),
+            ])
+        }))
+    }
+}
+
+/// The seeds come in pairs of the first seed in a range and how many seeds
+/// are in it.
+fn find_lowest_location_in_ranges(almanac: &Almanac) -> Result<Option<u64>, Overflow> {
+    let seed_ranges = almanac
+        .seeds_to_be_planted
+        .chunks_exact(2)
+        .map(|seed_ids| Interval::starting_at(seed_ids[0].get_value(), seed_ids[1].get_value()))
+        .collect::<Result<IntervalSet<u64>, Overflow>>()?;
+
+    let soil_ranges = almanac.seed_to_soil_map.get_all(&seed_ranges)?;
+    let fertiliser_ranges = almanac.soil_to_fertiliser_map.get_all(&soil_ranges)?;
+    let water_ranges = almanac
+        .fertiliser_to_water_map
+        .get_all(&fertiliser_ranges)?;
+    let light_ranges = almanac.water_to_light_map.get_all(&water_ranges)?;
+    let temperature_ranges = almanac.light_to_temperature_map.get_all(&light_ranges)?;
+    let humidity_ranges = almanac
+        .temperature_to_humidity_map
+        .get_all(&temperature_ranges)?;
+    let location_ranges = almanac.humidity_to_location_map.get_all(&humidity_ranges)?;
+
+    tracing::debug!(
+        ranges = location_ranges.iter().len(),
+        "mapped the seed ranges to locations"
+    );
+
+    Ok(location_ranges.iter().next().map(|range| range.start))
+}
+
+fn get_location_id(seed_id: &SeedId, almanac: &Almanac) -> Result<LocationId, Overflow> {
+    let soil_id = almanac.seed_to_soil_map.get(seed_id)?;
+    let fertiliser_id = almanac.soil_to_fertiliser_map.get(&soil_id)?;
+    let water_id = almanac.fertiliser_to_water_map.get(&fertiliser_id)?;
+    let light_id = almanac.water_to_light_map.get(&water_id)?;
+    let temperature_id = almanac.light_to_temperature_map.get(&light_id)?;
+    let humidity_id = almanac.temperature_to_humidity_map.get(&temperature_id)?;
+
+    almanac.humidity_to_location_map.get(&humidity_id)
+}
+
+trait AlmanacNumber {
+    fn new(value: u64) -> Self;
+    fn get_value(&self) -> u64;
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct SeedId {
+    value: u64,
+}
+
+impl AlmanacNumber for SeedId {
+    fn new(value: u64) -> SeedId {
+        SeedId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for SeedId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(SeedId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct SoilId {
+    value: u64,
+}
+
+impl AlmanacNumber for SoilId {
+    fn new(value: u64) -> SoilId {
+        SoilId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for SoilId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(SoilId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct FertiliserId {
+    value: u64,
+}
+
+impl AlmanacNumber for FertiliserId {
+    fn new(value: u64) -> FertiliserId {
+        FertiliserId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for FertiliserId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(FertiliserId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct WaterId {
+    value: u64,
+}
+
+impl AlmanacNumber for WaterId {
+    fn new(value: u64) -> WaterId {
+        WaterId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for WaterId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(WaterId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct LightId {
+    value: u64,
+}
+
+impl AlmanacNumber for LightId {
+    fn new(value: u64) -> LightId {
+        LightId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for LightId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(LightId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct TemperatureId {
+    value: u64,
+}
+
+impl AlmanacNumber for TemperatureId {
+    fn new(value: u64) -> TemperatureId {
+        TemperatureId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for TemperatureId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(TemperatureId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct HumidityId {
+    value: u64,
+}
+
+impl AlmanacNumber for HumidityId {
+    fn new(value: u64) -> HumidityId {
+        HumidityId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for HumidityId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(HumidityId { value })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+struct LocationId {
+    value: u64,
+}
+
+impl AlmanacNumber for LocationId {
+    fn new(value: u64) -> LocationId {
+        LocationId { value }
+    }
+
+    fn get_value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl FromStr for LocationId {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.parse()?;
+
+        Ok(LocationId { value })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn locations_can_overflow() {
+        let mut input =
+            String::from("seeds: 5 1\n\nseed-to-soil map:\n18446744073709551615 0 10\n");
+        for map in [
+            "soil-to-fertilizer",
+            "fertilizer-to-water",
+            "water-to-light",
+            "light-to-temperature",
+            "temperature-to-humidity",
+            "humidity-to-location",
+        ] {
+            input.push_str(&format!("\n{} map:\n0 0 1\n", map));
+        }
+        let almanac = Day::parse(&input).unwrap();
+
+        assert_eq!(
+            Day::part_one(&almanac, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+}
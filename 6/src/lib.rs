@@ -0,0 +1,221 @@
+use common::checked::CheckedIterator;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+
+struct Race {
+    time: u64,
+    record_distance: u64,
+}
+
+fn parse_document_row(s: &str) -> Result<Vec<u64>, ParseDocumentError> {
+    s.split_whitespace()
+        .skip(1)
+        .map(|s| {
+            s.parse::<u64>()
+                .map_err(|error| ParseDocumentError::InvalidNumber(Span::of(s), error))
+        })
+        .collect()
+}
+
+fn parse_part_2_document_row(s: &str) -> Result<u64, ParseDocumentError> {
+    s.split_whitespace()
+        .skip(1)
+        .collect::<String>()
+        .parse()
+        .map_err(|error| ParseDocumentError::InvalidNumber(Span::of(s), error))
+}
+
+pub struct Document {
+    races: Vec<Race>,
+    single_race: Race,
+}
+
+#[derive(Debug)]
+pub enum ParseDocumentError {
+    MissingTimes(Span),
+    MissingRecordDistances(Span),
+    InvalidNumber(Span, ParseIntError),
+}
+
+impl Display for ParseDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDocumentError::MissingTimes(_) => write!(f, "missing the race times"),
+            ParseDocumentError::MissingRecordDistances(_) => {
+                write!(f, "missing the record distances")
+            }
+            ParseDocumentError::InvalidNumber(span, _) => {
+                write!(f, "invalid number {:?}", span.snippet())
+            }
+        }
+    }
+}
+
+impl Error for ParseDocumentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDocumentError::InvalidNumber(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseDocumentError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseDocumentError::MissingTimes(span)
+            | ParseDocumentError::MissingRecordDistances(span)
+            | ParseDocumentError::InvalidNumber(span, _) => Some(span),
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Document;
+    type Error = ParseDocumentError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut lines = input.split('\n');
+        let times_row = lines
+            .next()
+            .ok_or_else(|| ParseDocumentError::MissingTimes(Span::of(input)))?;
+        let record_distances_row = lines
+            .next()
+            .ok_or_else(|| ParseDocumentError::MissingRecordDistances(Span::end_of(input)))?;
+
+        let times = parse_document_row(times_row)?;
+        let record_distances = parse_document_row(record_distances_row)?;
+
+        let races: Vec<Race> = times
+            .into_iter()
+            .zip(record_distances)
+            .map(|(time, record_distance)| Race {
+                time,
+                record_distance,
+            })
+            .collect();
+
+        let single_race = Race {
+            time: parse_part_2_document_row(times_row)?,
+            record_distance: parse_part_2_document_row(record_distances_row)?,
+        };
+
+        Ok(Document { races, single_race })
+    }
+
+    fn part_one(document: &Self::Input, _: &Parameters) -> Answer {
+        let ways_of_winning_each_race: Vec<u64> = document
+            .races
+            .iter()
+            .map(|race| calculate_number_of_ways_of_winning(race.record_distance, race.time))
+            .collect();
+
+        tracing::debug!(
+            races = ways_of_winning_each_race.len(),
+            "found ways of winning"
+        );
+        ways_of_winning_each_race
+            .into_iter()
+            .checked_product()
+            .into()
+    }
+
+    fn part_two(document: &Self::Input, _: &Parameters) -> Answer {
+        let race = &document.single_race;
+        calculate_number_of_ways_of_winning(race.record_distance, race.time).into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(document: &Self::Input) -> Value {
+        Value::record([
+            ("races", Value::list(&document.races)),
+            ("single_race", (&document.single_race).into()),
+        ])
+    }
+}
+
+impl From<&Race> for Value {
+    fn from(race: &Race) -> Self {
+        Value::record([
+            ("time", race.time.into()),
+            ("record_distance", race.record_distance.into()),
+        ])
+    }
+}
+
+/// How many whole milliseconds the button can be held for to go further
+/// than `record_distance` in a race lasting `total_time`, which is zero if
+/// the record can't be beaten.
+fn calculate_number_of_ways_of_winning(record_distance: u64, total_time: u64) -> u64 {
+    // Big enough for the square of any time.
+    let total_time = u128::from(total_time);
+    let record_distance = u128::from(record_distance);
+
+    // Holding for `held` leaves `total_time - held` to move at `held`
+    // millimetres per millisecond.
+    let beats_record = |held: u128| held * (total_time - held) > record_distance;
+
+    let Some(discriminant) = (total_time * total_time).checked_sub(4 * record_distance) else {
+        return 0;
+    };
+    let root_discriminant = number_theory::isqrt(discriminant);
+
+    // The square root is rounded down, so this is at most a millisecond short
+    // of the shortest hold that wins.
+    let mut min_time_taken_holding_button = (total_time - root_discriminant) / 2;
+    while min_time_taken_holding_button <= total_time / 2
+        && !beats_record(min_time_taken_holding_button)
+    {
+        min_time_taken_holding_button += 1;
+    }
+
+    if min_time_taken_holding_button > total_time / 2 {
+        return 0;
+    }
+
+    // Distances are symmetric, so the longest hold that wins mirrors the
+    // shortest.
+    let max_time_taken_holding_button = total_time - min_time_taken_holding_button;
+
+    tracing::trace!(
+        min_time_taken_holding_button,
+        max_time_taken_holding_button,
+        "found the times that beat the record"
+    );
+
+    (max_time_taken_holding_button - min_time_taken_holding_button + 1) as u64
+}
+
+#[cfg(test)]
+mod test {
+    use crate::calculate_number_of_ways_of_winning;
+
+    #[test]
+    fn counts_exactly_at_the_edges() {
+        // Holding for 2 or 5 milliseconds exactly equals a distance of 10.
+        assert_eq!(calculate_number_of_ways_of_winning(9, 7), 4);
+        assert_eq!(calculate_number_of_ways_of_winning(10, 7), 2);
+        assert_eq!(calculate_number_of_ways_of_winning(12, 7), 0);
+        assert_eq!(calculate_number_of_ways_of_winning(13, 7), 0);
+        assert_eq!(
+            calculate_number_of_ways_of_winning(0, u64::MAX),
+            u64::MAX - 1
+        );
+        assert_eq!(
+            calculate_number_of_ways_of_winning(u64::MAX, u64::MAX),
+            u64::MAX - 3
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use common::checked::Checked;
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Hand {
+    hand_type: HandType,
+    cards: [Label; NUM_CARDS_IN_HAND],
+}
+
+impl Hand {
+    fn with_joker_rule(self) -> Hand {
+        let cards: [Label; NUM_CARDS_IN_HAND] = self
+            .cards
+            .iter()
+            .cloned()
+            .map(|label| match label {
+                Label::Jack => Label::Joker,
+                rest => rest,
+            })
+            .collect::<Vec<Label>>()
+            .try_into()
+            .unwrap();
+
+        let hand_type = determine_hand_type(&cards).unwrap();
+
+        Hand { hand_type, cards }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseHandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards: [Label; NUM_CARDS_IN_HAND] = s
+            .char_indices()
+            .map(|(i, c)| {
+                c.try_into().map_err(|error| {
+                    ParseHandError::ParseLabelError(Span::of(&s[i..i + c.len_utf8()]), error)
+                })
+            })
+            .collect::<Result<Vec<Label>, Self::Err>>()?
+            .try_into()
+            .map_err(|_| ParseHandError::NotFiveCards(Span::of(s)))?;
+
+        let hand_type = determine_hand_type(&cards)
+            .ok_or_else(|| ParseHandError::UnknownHandType(Span::of(s)))?;
+
+        Ok(Hand { hand_type, cards })
+    }
+}
+
+fn determine_hand_type(cards: &[Label; NUM_CARDS_IN_HAND]) -> Option<HandType> {
+    let mut card_counts: HashMap<Label, usize> = HashMap::new();
+
+    for card in cards {
+        *card_counts.entry(*card).or_default() += 1;
+    }
+
+    if let Some(num_jokers) = card_counts.remove(&Label::Joker) {
+        let (max_label, _): (&Label, &usize) = card_counts
+            .iter()
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+            .unwrap_or((&Label::Joker, &0));
+
+        tracing::trace!(?max_label, num_jokers, "jokers join the most common label");
+
+        card_counts
+            .entry(*max_label)
+            .and_modify(|value| *value += num_jokers)
+            .or_insert(num_jokers);
+    }
+
+    let mut counts: Vec<usize> = card_counts.values().cloned().collect();
+    counts.sort();
+
+    match counts[..] {
+        [5] => Some(HandType::FiveOfAKind),
+        [1, 4] => Some(HandType::FourOfAKind),
+        [2, 3] => Some(HandType::FullHouse),
+        [1, 1, 3] => Some(HandType::ThreeOfAKind),
+        [1, 2, 2] => Some(HandType::TwoPair),
+        [1, 1, 1, 2] => Some(HandType::OnePair),
+        [1, 1, 1, 1, 1] => Some(HandType::HighCard),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseHandError {
+    ParseLabelError(Span, ParseLabelError),
+    NotFiveCards(Span),
+    UnknownHandType(Span),
+}
+
+impl Display for ParseHandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHandError::ParseLabelError(_, _) => write!(f, "invalid card in hand"),
+            ParseHandError::NotFiveCards(span) => write!(
+                f,
+                "hand {:?} does not have {} cards",
+                span.snippet(),
+                NUM_CARDS_IN_HAND
+            ),
+            ParseHandError::UnknownHandType(span) => {
+                write!(
+                    f,
+                    "unable to determine the type of hand {:?}",
+                    span.snippet()
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseHandError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseHandError::ParseLabelError(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseHandError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseHandError::ParseLabelError(span, _)
+            | ParseHandError::NotFiveCards(span)
+            | ParseHandError::UnknownHandType(span) => Some(span),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    fn name(&self) -> &'static str {
+        match self {
+            HandType::HighCard => "high_card",
+            HandType::OnePair => "one_pair",
+            HandType::TwoPair => "two_pair",
+            HandType::ThreeOfAKind => "three_of_a_kind",
+            HandType::FullHouse => "full_house",
+            HandType::FourOfAKind => "four_of_a_kind",
+            HandType::FiveOfAKind => "five_of_a_kind",
+        }
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+enum Label {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+#[derive(Debug)]
+pub enum ParseLabelError {
+    UnknownLabel(char),
+}
+
+impl Display for ParseLabelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseLabelError::UnknownLabel(label) => write!(f, "unknown card label {:?}", label),
+        }
+    }
+}
+
+impl Error for ParseLabelError {}
+
+impl TryFrom<char> for Label {
+    type Error = ParseLabelError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Label::Two),
+            '3' => Ok(Label::Three),
+            '4' => Ok(Label::Four),
+            '5' => Ok(Label::Five),
+            '6' => Ok(Label::Six),
+            '7' => Ok(Label::Seven),
+            '8' => Ok(Label::Eight),
+            '9' => Ok(Label::Nine),
+            'T' => Ok(Label::Ten),
+            'J' => Ok(Label::Jack),
+            'Q' => Ok(Label::Queen),
+            'K' => Ok(Label::King),
+            'A' => Ok(Label::Ace),
+            _ => Err(ParseLabelError::UnknownLabel(value)),
+        }
+    }
+}
+
+impl Label {
+    /// The card as it is written in the input. A joker is still written as
+    /// the jack it replaced.
+    fn symbol(&self) -> char {
+        match self {
+            Label::Joker | Label::Jack => 'J',
+            Label::Two => '2',
+            Label::Three => '3',
+            Label::Four => '4',
+            Label::Five => '5',
+            Label::Six => '6',
+            Label::Seven => '7',
+            Label::Eight => '8',
+            Label::Nine => '9',
+            Label::Ten => 'T',
+            Label::Queen => 'Q',
+            Label::King => 'K',
+            Label::Ace => 'A',
+        }
+    }
+}
+
+const NUM_CARDS_IN_HAND: usize = 5;
+
+type Bid = u64;
+
+#[derive(Debug)]
+pub enum ParseHandsError {
+    MissingHand(Span),
+    MissingBid(Span),
+    InvalidHand(ParseHandError),
+    InvalidBid(Span, ParseIntError),
+}
+
+impl Display for ParseHandsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseHandsError::MissingHand(_) => write!(f, "missing hand"),
+            ParseHandsError::MissingBid(_) => write!(f, "missing bid"),
+            ParseHandsError::InvalidHand(_) => write!(f, "invalid hand"),
+            ParseHandsError::InvalidBid(span, _) => write!(f, "invalid bid {:?}", span.snippet()),
+        }
+    }
+}
+
+impl Error for ParseHandsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseHandsError::InvalidHand(error) => Some(error),
+            ParseHandsError::InvalidBid(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseHandsError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseHandsError::MissingHand(span)
+            | ParseHandsError::MissingBid(span)
+            | ParseHandsError::InvalidBid(span, _) => Some(span),
+            ParseHandsError::InvalidHand(error) => error.span(),
+        }
+    }
+}
+
+fn parse_hands(input: &str) -> Result<Vec<(Hand, Bid)>, ParseHandsError> {
+    let hands: Vec<(Hand, Bid)> = input
+        .split('\n')
+        .filter(|line| !line.is_empty())
+        .map(|s| {
+            let mut split = s.split_whitespace();
+            let hand = split
+                .next()
+                .ok_or_else(|| ParseHandsError::MissingHand(Span::of(s)))?
+                .parse()
+                .map_err(ParseHandsError::InvalidHand)?;
+            let bid_str = split
+                .next()
+                .ok_or_else(|| ParseHandsError::MissingBid(Span::end_of(s)))?;
+            let bid = bid_str
+                .parse()
+                .map_err(|error| ParseHandsError::InvalidBid(Span::of(bid_str), error))?;
+            Ok((hand, bid))
+        })
+        .collect::<Result<_, _>>()?;
+
+    tracing::debug!(hands = hands.len(), "parsed hands");
+
+    Ok(hands)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Hand, Bid)>;
+    type Error = ParseHandsError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_hands(input)
+    }
+
+    fn part_one(hands: &Self::Input, _: &Parameters) -> Answer {
+        let mut hands = hands.clone();
+
+        hands.sort_by(|a, b| a.0.cmp(&b.0));
+
+        calculate_total_winnings(&hands).into()
+    }
+
+    fn part_two(hands: &Self::Input, _: &Parameters) -> Answer {
+        let mut joker_hands: Vec<(Hand, Bid)> = hands
+            .iter()
+            .cloned()
+            .map(|(hand, bid)| (hand.with_joker_rule(), bid))
+            .collect();
+
+        joker_hands.sort_by(|a, b| a.0.cmp(&b.0));
+
+        calculate_total_winnings(&joker_hands).into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(hands: &Self::Input) -> Value {
+        Value::list(hands.iter().map(|(hand, bid)| {
+            Value::record([
+                (
+                    "cards",
+                    hand.cards
+                        .iter()
+                        .map(Label::symbol)
+                        .collect::<String>()
+                        .into(),
+                ),
+                ("hand_type", hand.hand_type.name().into()),
+                ("bid", bid.into()),
+            ])
+        }))
+    }
+}
+
+fn calculate_total_winnings(sorted_hands: &[(Hand, Bid)]) -> Result<Bid, Overflow> {
+    sorted_hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| ((i + 1) as Bid, hand))
+        .inspect(|(rank, (_, bid))| tracing::trace!(rank, bid, "ranked hand"))
+        .map(|(rank, (_, bid))| bid.try_mul(rank))
+        .collect::<Result<Vec<Bid>, Overflow>>()?
+        .into_iter()
+        .checked_sum()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+    use crate::Hand;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn card_order() {
+        assert!("33332".parse::<Hand>().unwrap() > "2AAAA".parse::<Hand>().unwrap());
+        assert!("77888".parse::<Hand>().unwrap() > "77788".parse::<Hand>().unwrap());
+    }
+
+    #[test]
+    fn winnings_can_overflow() {
+        let hands = Day::parse("AAAAA 10000000000000000000\n23456 1\n").unwrap();
+
+        assert_eq!(
+            Day::part_one(&hands, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+}
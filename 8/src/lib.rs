@@ -0,0 +1,584 @@
+use common::checked::Checked;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use number_theory::Congruence;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::iter;
+use std::str::FromStr;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[derive(Debug)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct ParseInstructionError(char);
+
+impl Display for ParseInstructionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown instruction {:?}", self.0)
+    }
+}
+
+impl Error for ParseInstructionError {}
+
+impl TryFrom<char> for Instruction {
+    type Error = ParseInstructionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(ParseInstructionError(value)),
+        }
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct NodeId {
+    value: String,
+}
+
+#[derive(Debug)]
+struct Node {
+    id: NodeId,
+    left: NodeId,
+    right: NodeId,
+}
+
+#[derive(Debug)]
+pub struct ParseNodeError(Span);
+
+impl Display for ParseNodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid node {:?}", self.0.snippet())
+    }
+}
+
+impl Error for ParseNodeError {}
+
+impl ParseError for ParseNodeError {
+    fn span(&self) -> Option<&Span> {
+        Some(&self.0)
+    }
+}
+
+impl FromStr for Node {
+    type Err = ParseNodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split(" = ");
+
+        let id_value = split
+            .next()
+            .ok_or_else(|| ParseNodeError(Span::of(s)))?
+            .to_string();
+        let id = NodeId { value: id_value };
+
+        let nodes = split.next().ok_or_else(|| ParseNodeError(Span::of(s)))?;
+
+        let mut nodes_split = nodes
+            .strip_prefix('(')
+            .and_then(|nodes| nodes.strip_suffix(')'))
+            .ok_or_else(|| ParseNodeError(Span::of(nodes)))?
+            .split(", ");
+
+        let left_value = nodes_split
+            .next()
+            .ok_or_else(|| ParseNodeError(Span::of(nodes)))?
+            .to_string();
+        let left = NodeId { value: left_value };
+
+        let right_value = nodes_split
+            .next()
+            .ok_or_else(|| ParseNodeError(Span::of(nodes)))?
+            .to_string();
+
+        let right = NodeId { value: right_value };
+
+        Ok(Node { id, left, right })
+    }
+}
+
+#[derive(Debug)]
+struct Network {
+    nodes: HashMap<NodeId, Node>,
+}
+
+impl Network {
+    fn go_right(&self, current: &NodeId) -> Option<NodeId> {
+        let current_node = self.nodes.get(current)?;
+
+        Some(current_node.right.clone())
+    }
+
+    fn go_left(&self, current: &NodeId) -> Option<NodeId> {
+        let current_node = self.nodes.get(current)?;
+
+        Some(current_node.left.clone())
+    }
+
+    fn contains(&self, node: &NodeId) -> bool {
+        self.nodes.contains_key(node)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseNetworkError {
+    ParseNodeError(ParseNodeError),
+}
+
+impl Display for ParseNetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseNetworkError::ParseNodeError(_) => write!(f, "invalid network"),
+        }
+    }
+}
+
+impl Error for ParseNetworkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseNetworkError::ParseNodeError(error) => Some(error),
+        }
+    }
+}
+
+impl ParseError for ParseNetworkError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseNetworkError::ParseNodeError(error) => error.span(),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = ParseNetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nodes = s
+            .split('\n')
+            .map(|node_str| node_str.parse())
+            .map(|res: Result<Node, ParseNodeError>| res.map(|node: Node| (node.id.clone(), node)))
+            .collect::<Result<HashMap<NodeId, Node>, ParseNodeError>>()
+            .map_err(ParseNetworkError::ParseNodeError)?;
+
+        Ok(Network { nodes })
+    }
+}
+
+pub struct Document {
+    instructions: Vec<Instruction>,
+    network: Network,
+}
+
+#[derive(Debug)]
+pub enum ParseDocumentError {
+    MissingInstructions(Span),
+    MissingNetwork(Span),
+    ParseInstructionError(Span, ParseInstructionError),
+    ParseNetworkError(ParseNetworkError),
+}
+
+impl Display for ParseDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDocumentError::MissingInstructions(_) => write!(f, "missing instructions"),
+            ParseDocumentError::MissingNetwork(_) => write!(f, "missing network"),
+            ParseDocumentError::ParseInstructionError(_, _) => write!(f, "invalid instructions"),
+            ParseDocumentError::ParseNetworkError(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for ParseDocumentError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDocumentError::ParseInstructionError(_, error) => Some(error),
+            ParseDocumentError::ParseNetworkError(error) => error.source(),
+            _ => None,
+        }
+    }
+}
+
+impl ParseError for ParseDocumentError {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseDocumentError::MissingInstructions(span)
+            | ParseDocumentError::MissingNetwork(span)
+            | ParseDocumentError::ParseInstructionError(span, _) => Some(span),
+            ParseDocumentError::ParseNetworkError(error) => error.span(),
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Document;
+    type Error = ParseDocumentError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let mut split = input.split("\n\n");
+
+        let instructions_str = split
+            .next()
+            .ok_or_else(|| ParseDocumentError::MissingInstructions(Span::of(input)))?;
+        let instructions: Vec<Instruction> = instructions_str
+            .char_indices()
+            .map(|(i, c)| {
+                Instruction::try_from(c).map_err(|error| {
+                    ParseDocumentError::ParseInstructionError(
+                        Span::of(&instructions_str[i..i + c.len_utf8()]),
+                        error,
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let network: Network = split
+            .next()
+            .ok_or_else(|| ParseDocumentError::MissingNetwork(Span::end_of(input)))?
+            .parse()
+            .map_err(ParseDocumentError::ParseNetworkError)?;
+        tracing::debug!(
+            instructions = instructions.len(),
+            nodes = network.nodes.len(),
+            "parsed document"
+        );
+
+        Ok(Document {
+            instructions,
+            network,
+        })
+    }
+
+    fn part_one(document: &Self::Input, _: &Parameters) -> Answer {
+        let Document {
+            instructions,
+            network,
+        } = document;
+
+        let start = NodeId {
+            value: "AAA".to_string(),
+        };
+        let end = NodeId {
+            value: "ZZZ".to_string(),
+        };
+
+        // The part two examples have no "AAA" to "ZZZ" route to follow.
+        if network.contains(&start) && network.contains(&end) {
+            let steps = traverse_network(&start, &end, instructions, network);
+
+            return steps.into();
+        }
+
+        Answer::Unavailable
+    }
+
+    fn part_two(document: &Self::Input, _: &Parameters) -> Answer {
+        let Document {
+            instructions,
+            network,
+        } = document;
+
+        let start_node_ids: Vec<NodeId> = network
+            .nodes
+            .keys()
+            .filter(|node_id| node_id.value.ends_with('A'))
+            .cloned()
+            .collect();
+
+        tracing::debug!(starts = start_node_ids.len(), "found start nodes");
+
+        #[cfg(not(feature = "parallel"))]
+        let start_node_ids = start_node_ids.into_iter();
+        #[cfg(feature = "parallel")]
+        let start_node_ids = start_node_ids.into_par_iter();
+
+        let end_steps: Vec<EndSteps> = start_node_ids
+            .map(|start_node| find_end_steps(&start_node, instructions, network))
+            .collect();
+
+        // A ghost that never reaches an end node keeps the others waiting
+        // forever.
+        if end_steps.is_empty() || end_steps.iter().any(EndSteps::is_empty) {
+            return Answer::Unavailable;
+        }
+
+        find_first_common_end_step(&end_steps).into()
+    }
+}
+
+/// The network is keyed by node, in order, with the nodes each one leads to.
+impl Inspect for Day {
+    fn inspect(document: &Self::Input) -> Value {
+        let instructions: String = document
+            .instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Left => 'L',
+                Instruction::Right => 'R',
+            })
+            .collect();
+
+        let mut nodes: Vec<&Node> = document.network.nodes.values().collect();
+        nodes.sort_by(|a, b| a.id.value.cmp(&b.id.value));
+
+        let network = Value::record(nodes.into_iter().map(|node| {
+            (
+                node.id.value.as_str(),
+                Value::record([
+                    ("left", node.left.value.as_str().into()),
+                    ("right", node.right.value.as_str().into()),
+                ]),
+            )
+        }));
+
+        Value::record([("instructions", instructions.into()), ("network", network)])
+    }
+}
+
+fn traverse_network(
+    start_node: &NodeId,
+    end_node: &NodeId,
+    instructions: &[Instruction],
+    network: &Network,
+) -> u32 {
+    let mut current_node = start_node.clone();
+    let mut instructions_cycle = instructions.iter().cycle();
+
+    let mut steps = 0;
+    while &current_node != end_node {
+        let next_instruction = instructions_cycle.next().unwrap();
+        let next_node = match next_instruction {
+            Instruction::Left => network.go_left(&current_node),
+            Instruction::Right => network.go_right(&current_node),
+        };
+
+        current_node = next_node.unwrap();
+        steps += 1;
+    }
+
+    steps
+}
+
+/// Every state a ghost passes through from `start_node`: the node it is on
+/// and which instruction it follows next. Ends if a node leads nowhere.
+fn states<'a>(
+    start_node: &NodeId,
+    instructions: &'a [Instruction],
+    network: &'a Network,
+) -> impl Iterator<Item = (NodeId, usize)> + Clone + 'a {
+    iter::successors(Some((start_node.clone(), 0)), |(node, index)| {
+        let next_node = match instructions.get(*index)? {
+            Instruction::Left => network.go_left(node),
+            Instruction::Right => network.go_right(node),
+        }?;
+
+        Some((next_node, (index + 1) % instructions.len()))
+    })
+}
+
+/// The steps on which a ghost is on an end node.
+struct EndSteps {
+    /// Those before the ghost starts going round its loop, in order.
+    before_loop: Vec<u64>,
+    /// The step the ghost starts going round its loop, or walks off the
+    /// network.
+    loop_start: u64,
+    /// From `loop_start` onwards, the ghost is on an end node on exactly the
+    /// steps in one of these.
+    in_loop: Vec<Congruence>,
+}
+
+impl EndSteps {
+    fn is_empty(&self) -> bool {
+        self.before_loop.is_empty() && self.in_loop.is_empty()
+    }
+
+    fn contains(&self, step: u64) -> bool {
+        if step < self.loop_start {
+            self.before_loop.binary_search(&step).is_ok()
+        } else {
+            self.in_loop
+                .iter()
+                .any(|congruence| step % congruence.modulus == congruence.remainder)
+        }
+    }
+}
+
+/// When a ghost starting at `start_node` lands on an end node. Once it is
+/// going round a loop, that may be more often than once per loop.
+fn find_end_steps(
+    start_node: &NodeId,
+    instructions: &[Instruction],
+    network: &Network,
+) -> EndSteps {
+    let end_steps = |states: &mut dyn Iterator<Item = (NodeId, usize)>| -> Vec<u64> {
+        states
+            .enumerate()
+            .filter(|(_, (node, _))| node.value.ends_with('Z'))
+            .map(|(step, _)| step as u64)
+            .collect()
+    };
+
+    let Some(cycle) = graph::brent(states(start_node, instructions, network)) else {
+        // The ghost walks off the network, so only reaches the end nodes on
+        // its way.
+        return EndSteps {
+            before_loop: end_steps(&mut states(start_node, instructions, network)),
+            loop_start: states(start_node, instructions, network).count() as u64,
+            in_loop: Vec::new(),
+        };
+    };
+
+    let before_loop = end_steps(&mut states(start_node, instructions, network).take(cycle.start));
+    let end_offsets: Vec<usize> = end_steps(
+        &mut states(start_node, instructions, network)
+            .skip(cycle.start)
+            .take(cycle.length),
+    )
+    .into_iter()
+    .map(|offset| offset as usize)
+    .collect();
+
+    // The shortest shift round the loop that lands every end node on another.
+    let period = (1..=cycle.length)
+        .filter(|period| cycle.length.is_multiple_of(*period))
+        .find(|period| {
+            end_offsets.iter().all(|offset| {
+                end_offsets
+                    .binary_search(&((offset + period) % cycle.length))
+                    .is_ok()
+            })
+        })
+        .unwrap_or(cycle.length);
+
+    let in_loop = end_offsets
+        .iter()
+        .take_while(|offset| **offset < period)
+        .map(|offset| Congruence {
+            remainder: ((cycle.start + offset) % period) as u64,
+            modulus: period as u64,
+        })
+        .collect();
+
+    tracing::debug!(
+        start = start_node.value,
+        cycle_start = cycle.start,
+        cycle_length = cycle.length,
+        ends = end_offsets.len(),
+        period,
+        "found cycle"
+    );
+
+    EndSteps {
+        before_loop,
+        loop_start: cycle.start as u64,
+        in_loop,
+    }
+}
+
+/// The first step on which every ghost is on an end node, or `None` if
+/// there isn't one.
+fn find_first_common_end_step(end_steps: &[EndSteps]) -> Result<Option<u64>, Overflow> {
+    let Some(last_to_loop) = end_steps.iter().max_by_key(|steps| steps.loop_start) else {
+        return Ok(None);
+    };
+
+    // Before every ghost is going round its loop, the step has to be one of
+    // those the last ghost to start looping reaches an end node on first.
+    if let Some(step) = last_to_loop
+        .before_loop
+        .iter()
+        .find(|step| end_steps.iter().all(|steps| steps.contains(**step)))
+    {
+        return Ok(Some(*step));
+    }
+
+    // After that, each ghost is on an end node on the steps in any of its
+    // congruences, so every way of picking one congruence per ghost is
+    // combined.
+    let mut combined = vec![Congruence {
+        remainder: 0,
+        modulus: 1,
+    }];
+    for steps in end_steps {
+        let mut next = Vec::new();
+
+        for congruence in &combined {
+            for in_loop in &steps.in_loop {
+                if let Some(both) = number_theory::chinese_remainder([*congruence, *in_loop])? {
+                    next.push(both);
+                }
+            }
+        }
+
+        combined = next;
+    }
+
+    let loop_start = last_to_loop.loop_start;
+    combined
+        .into_iter()
+        .map(|congruence| first_step_from(loop_start, congruence))
+        .try_fold(None, |first: Option<u64>, step| {
+            let step = step?;
+            Ok(Some(first.map_or(step, |first| first.min(step))))
+        })
+}
+
+/// The first step in `congruence` that is at least `start`.
+fn first_step_from(start: u64, congruence: Congruence) -> Result<u64, Overflow> {
+    if start <= congruence.remainder {
+        return Ok(congruence.remainder);
+    }
+
+    let behind = (congruence.remainder + congruence.modulus - start % congruence.modulus)
+        % congruence.modulus;
+
+    start.try_add(behind)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn waits_for_ghosts_to_reach_their_end_nodes() {
+        let document = Day::parse("LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)").unwrap();
+
+        assert_eq!(
+            Day::part_two(&document, &Parameters::default()),
+            Answer::from(Some(6u64))
+        );
+    }
+
+    #[test]
+    fn has_no_answer_if_a_ghost_never_reaches_an_end_node() {
+        let document = Day::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").unwrap();
+
+        assert_eq!(
+            Day::part_two(&document, &Parameters::default()),
+            Answer::Unavailable
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use common::checked::Checked;
+use common::checked::CheckedIterator;
+use common::checked::Overflow;
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+
+#[derive(Debug)]
+pub struct ParseValueError(Span, ParseIntError);
+
+impl Display for ParseValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid value {:?}", self.0.snippet())
+    }
+}
+
+impl Error for ParseValueError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.1)
+    }
+}
+
+impl ParseError for ParseValueError {
+    fn span(&self) -> Option<&Span> {
+        Some(&self.0)
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i128>>;
+    type Error = ParseValueError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let lines: Vec<&str> = input.split('\n').filter(|line| !line.is_empty()).collect();
+        let sequences = lines
+            .iter()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|v| {
+                        v.parse()
+                            .map_err(|error| ParseValueError(Span::of(v), error))
+                    })
+                    .collect::<Result<Vec<i128>, ParseValueError>>()
+            })
+            .collect::<Result<Vec<Vec<i128>>, ParseValueError>>()?;
+
+        tracing::debug!(sequences = sequences.len(), "parsed report");
+
+        Ok(sequences)
+    }
+
+    fn part_one(sequences: &Self::Input, _: &Parameters) -> Answer {
+        sequences
+            .iter()
+            .map(|sequence| find_next_value(sequence))
+            .inspect(|next_value| tracing::trace!(?next_value, "extrapolated"))
+            .collect::<Result<Vec<i128>, Overflow>>()
+            .and_then(|next_values| next_values.into_iter().checked_sum())
+            .into()
+    }
+
+    fn part_two(sequences: &Self::Input, _: &Parameters) -> Answer {
+        let reverse_sequences = sequences
+            .iter()
+            .cloned()
+            .map(|seq| seq.into_iter().rev().collect::<Vec<i128>>())
+            .collect::<Vec<Vec<i128>>>();
+
+        reverse_sequences
+            .iter()
+            .map(|sequence| find_next_value(sequence))
+            .inspect(|next_value| tracing::trace!(?next_value, "extrapolated"))
+            .collect::<Result<Vec<i128>, Overflow>>()
+            .and_then(|next_values| next_values.into_iter().checked_sum())
+            .into()
+    }
+}
+
+impl Inspect for Day {
+    fn inspect(sequences: &Self::Input) -> Value {
+        Value::list(sequences.iter().map(Value::list))
+    }
+}
+
+fn find_next_value(sequence: &[i128]) -> Result<i128, Overflow> {
+    let mut differences: Vec<Vec<i128>> = Vec::new();
+
+    let mut most_recent_sequence: Vec<i128> = sequence.to_vec();
+    while !most_recent_sequence.iter().all(|v| *v == 0) {
+        let mut difference_col = Vec::new();
+
+        for i in 0..most_recent_sequence.len() - 1 {
+            let difference = most_recent_sequence[i + 1].try_sub(most_recent_sequence[i])?;
+
+            difference_col.push(difference);
+        }
+
+        differences.push(most_recent_sequence);
+        most_recent_sequence = difference_col;
+    }
+
+    let d_1: Vec<i128> = differences
+        .iter()
+        .map(|seq| seq.first().unwrap())
+        .cloned()
+        .collect();
+
+    let polnominal_degree = d_1.len();
+    tracing::trace!(polnominal_degree, "found the differences");
+
+    let f = |n: i128| -> Result<i128, Overflow> {
+        (0..(polnominal_degree))
+            .map(|i: usize| -> Result<i128, Overflow> {
+                d_1[i].try_mul(number_theory::binomial(n, i as i128)?)
+            })
+            .collect::<Result<Vec<i128>, Overflow>>()?
+            .into_iter()
+            .checked_sum()
+    };
+
+    f(sequence.len() as i128)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Day;
+
+    use common::parameters::Parameters;
+    use common::Answer;
+    use common::Solution;
+
+    #[test]
+    fn high_degree_sequences_do_not_overflow() {
+        let powers_of_two: Vec<String> = (0..40).map(|power| (1u64 << power).to_string()).collect();
+        let sequences = Day::parse(&powers_of_two.join(" ")).unwrap();
+
+        // The only polynomial of degree 39 through the first 40 powers of two.
+        assert_eq!(
+            Day::part_one(&sequences, &Parameters::default()),
+            Answer::from((1i128 << 40) - 1)
+        );
+    }
+
+    #[test]
+    fn large_differences_overflow() {
+        let alternating: Vec<&str> = (0..80)
+            .map(|i| {
+                if i % 2 == 0 {
+                    "1000000000000000000"
+                } else {
+                    "-1000000000000000000"
+                }
+            })
+            .collect();
+        let sequences = Day::parse(&alternating.join(" ")).unwrap();
+
+        assert_eq!(
+            Day::part_one(&sequences, &Parameters::default()),
+            Answer::Overflow
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use common::input;
+
+use days::Day;
+use days::DAYS;
+use days::PARTS;
+
+use std::hint::black_box;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+/// Benchmarks parsing and both parts of a day against its committed
+/// `input.txt`, as one group per day so `cargo bench -- "day 12"` picks out a
+/// single day.
+fn bench_day(c: &mut Criterion, day: &Day) {
+    let input = input::read_input(&day.get_default_input_path())
+        .unwrap_or_else(|error| panic!("{}", error));
+    let parsed_input = day
+        .parse(&input)
+        .unwrap_or_else(|error| panic!("committed input should parse: {}", error));
+    let parameters = day.default_parameters();
+
+    let mut group = c.benchmark_group(format!("day {}", day.number));
+    group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&input))));
+    for part in PARTS {
+        group.bench_function(part.name(), |b| {
+            b.iter(|| day.solve(part, black_box(parsed_input.as_ref()), &parameters))
+        });
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    for day in &DAYS {
+        bench_day(c, day);
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // Some parts take a good fraction of a second, so keep the full suite
+    // to a few minutes rather than Criterion's default 100 samples.
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
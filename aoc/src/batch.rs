@@ -0,0 +1,292 @@
+use crate::report::PartReport;
+
+use common::checked::Overflow;
+use common::input;
+use common::parameters::Parameters;
+use common::Answer;
+
+use days::Day;
+use days::Part;
+
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+/// How one input in the batch went. Parts solved before a failure are kept.
+pub(crate) struct BatchResult {
+    pub(crate) path: PathBuf,
+    pub(crate) parse_elapsed: Duration,
+    pub(crate) parts: Vec<PartReport>,
+    pub(crate) failure: Option<String>,
+}
+
+impl BatchResult {
+    fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+}
+
+/// Solves `parts` for every file in `directory`, in name order. A panic only
+/// fails the input that caused it.
+pub(crate) fn run(
+    day: &Day,
+    parts: &[Part],
+    parameters: &Parameters,
+    directory: &Path,
+) -> io::Result<Vec<BatchResult>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+
+    // Panics are reported in the table, so the default hook printing each
+    // one as it happens would only get in the way.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let results = paths
+        .into_iter()
+        .map(|path| match input::read_input(&path) {
+            Ok(input) => solve(day, parts, parameters, path, &input),
+            Err(error) => BatchResult {
+                path,
+                parse_elapsed: Duration::ZERO,
+                parts: Vec::new(),
+                failure: Some(error.to_string()),
+            },
+        })
+        .collect();
+
+    panic::set_hook(hook);
+
+    Ok(results)
+}
+
+fn solve(
+    day: &Day,
+    parts: &[Part],
+    parameters: &Parameters,
+    path: PathBuf,
+    input: &str,
+) -> BatchResult {
+    let mut result = BatchResult {
+        path,
+        parse_elapsed: Duration::ZERO,
+        parts: Vec::new(),
+        failure: None,
+    };
+
+    let start = Instant::now();
+    let parsed_input = match panic::catch_unwind(|| day.parse(input)) {
+        Ok(Ok(parsed_input)) => parsed_input,
+        Ok(Err(error)) => {
+            result.failure = Some(format!("unable to parse: {}", error));
+            return result;
+        }
+        Err(payload) => {
+            result.failure = Some(format!("parsing panicked: {}", panic_message(&payload)));
+            return result;
+        }
+    };
+    result.parse_elapsed = start.elapsed();
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+            day.solve(*part, parsed_input.as_ref(), parameters)
+        }));
+        let elapsed = start.elapsed();
+
+        match answer {
+            Ok(Answer::Overflow) => {
+                result.failure = Some(format!("{}: {}", part.name(), Overflow));
+                return result;
+            }
+            Ok(answer) => result.parts.push(PartReport {
+                part: *part,
+                answer,
+                elapsed,
+            }),
+            Err(payload) => {
+                result.failure = Some(format!(
+                    "{} panicked: {}",
+                    part.name(),
+                    panic_message(&payload)
+                ));
+                return result;
+            }
+        }
+    }
+
+    result
+}
+
+pub(crate) fn panic_message(payload: &Box<dyn Any + Send>) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
+
+/// Prints a row per input with its answers, how long it took and whether it
+/// failed, followed by the full reason for each failure.
+pub(crate) fn print_table(results: &[BatchResult], parts: &[Part]) {
+    let mut header = vec![String::from("input")];
+    header.extend(parts.iter().map(|part| part.name().to_string()));
+    header.push(String::from("time"));
+    header.push(String::from("status"));
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| table_row(result, parts))
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+    }
+
+    for result in results {
+        if let Some(failure) = &result.failure {
+            eprintln!("\nerror: {}: {}", result.path.display(), failure);
+        }
+    }
+}
+
+/// The cells for one input. A failed input has no time, as the time up to
+/// the failure would be misleading.
+fn table_row(result: &BatchResult, parts: &[Part]) -> Vec<String> {
+    let mut row = vec![file_name(&result.path)];
+    row.extend(parts.iter().map(|part| {
+        result
+            .parts
+            .iter()
+            .find(|report| report.part == *part)
+            .map_or(String::new(), |report| report.answer.to_string())
+    }));
+
+    match &result.failure {
+        Some(_) => row.extend([String::new(), String::from("failed")]),
+        None => row.extend([
+            format!("{:.2?}", result.total_elapsed()),
+            String::from("ok"),
+        ]),
+    }
+
+    row
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::batch;
+
+    use common::Answer;
+
+    use days::PARTS;
+
+    #[test]
+    fn solves_each_part() {
+        let day = days::get_day(9).unwrap();
+        let input = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+        let result = batch::solve(
+            day,
+            &PARTS,
+            &day.default_parameters(),
+            "a.txt".into(),
+            input,
+        );
+
+        assert_eq!(result.failure, None);
+        assert_eq!(result.parts[0].answer, Answer::from(114));
+        assert_eq!(result.parts[1].answer, Answer::from(2));
+    }
+
+    #[test]
+    fn a_panic_fails_only_its_input() {
+        let day = days::get_day(10).unwrap();
+        let no_start = "...\n.F7\n.LJ\n";
+
+        let result = batch::solve(
+            day,
+            &PARTS,
+            &day.default_parameters(),
+            "b.txt".into(),
+            no_start,
+        );
+
+        assert!(result.parts.is_empty());
+        assert!(result.failure.unwrap().starts_with("part one panicked: "));
+    }
+
+    #[test]
+    fn leaves_the_time_out_for_failed_inputs() {
+        let day = days::get_day(9).unwrap();
+        let solve = |input| {
+            batch::solve(
+                day,
+                &PARTS,
+                &day.default_parameters(),
+                "d.txt".into(),
+                input,
+            )
+        };
+
+        let failed = batch::table_row(&solve("1 x"), &PARTS);
+        let solved = batch::table_row(&solve("0 3 6\n"), &PARTS);
+
+        assert_eq!(failed, ["d.txt", "", "", "", "failed"]);
+        assert!(!solved[3].is_empty());
+        assert_eq!(solved[4], "ok");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let day = days::get_day(9).unwrap();
+
+        let result = batch::solve(
+            day,
+            &PARTS,
+            &day.default_parameters(),
+            "c.txt".into(),
+            "1 x",
+        );
+
+        assert!(result.failure.unwrap().starts_with("unable to parse: "));
+    }
+}
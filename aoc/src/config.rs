@@ -0,0 +1,157 @@
+use common::parameters::Parameters;
+use common::parameters::SetParameterError;
+
+use days::Day;
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Read from the current directory when no other config file is given.
+pub(crate) const CONFIG_FILE: &str = "aoc.toml";
+
+/// Parameter values for each day, with a table per day:
+///
+/// ```toml
+/// [11]
+/// part_two_expansion = 10
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct Config {
+    path: PathBuf,
+    days: BTreeMap<u8, BTreeMap<String, u64>>,
+}
+
+#[derive(Debug)]
+pub(crate) enum LoadConfigError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    InvalidEntry(PathBuf, String),
+}
+
+impl Display for LoadConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadConfigError::Read(path, _) => {
+                write!(f, "unable to read config from {}", path.display())
+            }
+            LoadConfigError::Parse(path, _) => {
+                write!(f, "unable to parse config in {}", path.display())
+            }
+            LoadConfigError::InvalidEntry(path, key) => write!(
+                f,
+                "{:?} in {} should be a day number with a table of whole numbers",
+                key,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for LoadConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadConfigError::Read(_, error) => Some(error),
+            LoadConfigError::Parse(_, error) => Some(error),
+            LoadConfigError::InvalidEntry(_, _) => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ConfigParameterError {
+    path: PathBuf,
+    day: u8,
+    error: SetParameterError,
+}
+
+impl Display for ConfigParameterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} in {}: {}",
+            self.day,
+            self.path.display(),
+            self.error
+        )
+    }
+}
+
+impl Error for ConfigParameterError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl Config {
+    /// Reads the config from `path`. A missing file is only an error when it
+    /// was asked for, otherwise every parameter keeps its default.
+    pub(crate) fn load(path: Option<&Path>) -> Result<Config, LoadConfigError> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => (PathBuf::from(CONFIG_FILE), false),
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound && !required => {
+                return Ok(Config {
+                    path,
+                    ..Config::default()
+                })
+            }
+            Err(error) => return Err(LoadConfigError::Read(path, error)),
+        };
+
+        let table: toml::Table = contents
+            .parse()
+            .map_err(|error| LoadConfigError::Parse(path.clone(), error))?;
+
+        let days = table
+            .into_iter()
+            .map(|(key, values)| {
+                let invalid_entry = || LoadConfigError::InvalidEntry(path.clone(), key.clone());
+
+                let day: u8 = key.parse().map_err(|_| invalid_entry())?;
+                let toml::Value::Table(values) = values else {
+                    return Err(invalid_entry());
+                };
+
+                let values = values
+                    .into_iter()
+                    .map(|(name, value)| match value {
+                        toml::Value::Integer(value) => u64::try_from(value)
+                            .map(|value| (name, value))
+                            .map_err(|_| invalid_entry()),
+                        _ => Err(invalid_entry()),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok((day, values))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Config { path, days })
+    }
+
+    /// The day's parameters with the values from the config applied.
+    pub(crate) fn parameters(&self, day: &Day) -> Result<Parameters, ConfigParameterError> {
+        let mut parameters = day.default_parameters();
+
+        for (name, value) in self.days.get(&day.number).into_iter().flatten() {
+            parameters
+                .set(name, *value)
+                .map_err(|error| ConfigParameterError {
+                    path: self.path.clone(),
+                    day: day.number,
+                    error,
+                })?;
+        }
+
+        Ok(parameters)
+    }
+}
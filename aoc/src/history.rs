@@ -0,0 +1,414 @@
+use common::parameters::Parameters;
+
+use days::workspace_root;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::time::SystemTime;
+
+use serde_json::json;
+use serde_json::Value;
+
+/// Kept next to the days and out of version control, like the local
+/// answers files.
+pub(crate) const HISTORY_FILE: &str = "history.local.jsonl";
+
+/// Every answer the runner has given, one JSON record per line, so a
+/// refactor that changes an answer is caught on the next run.
+pub(crate) struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+/// One answer to one part, and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Record {
+    pub(crate) key: RecordKey,
+    pub(crate) answer: String,
+    pub(crate) commit: String,
+    /// Seconds since the Unix epoch.
+    pub(crate) recorded_at: u64,
+    pub(crate) verified: bool,
+}
+
+/// What has to match for two answers to be expected to agree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RecordKey {
+    pub(crate) day: u8,
+    pub(crate) part: u8,
+    pub(crate) input_hash: String,
+    /// The parameters that were changed from their defaults, like
+    /// `part_two_expansion=10`, or empty for the puzzle as written.
+    pub(crate) parameters: String,
+}
+
+impl RecordKey {
+    pub(crate) fn new(day: u8, part: u8, input_hash: &str, parameters: &Parameters) -> RecordKey {
+        let parameters = parameters
+            .changed()
+            .map(|(parameter, value)| format!("{}={}", parameter.name, value))
+            .collect::<Vec<String>>()
+            .join(",");
+
+        RecordKey {
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            parameters,
+        }
+    }
+}
+
+/// A new answer that disagrees with the one recorded before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Mismatch {
+    pub(crate) previous: Record,
+    pub(crate) answer: String,
+    pub(crate) commit: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = &self.previous.key;
+
+        writeln!(
+            f,
+            "day {} part {} gave a different answer than before",
+            key.day, key.part
+        )?;
+        writeln!(f, "  now:    {} at {}", self.answer, self.commit)?;
+        write!(
+            f,
+            "  before: {} at {}",
+            self.previous.answer, self.previous.commit
+        )?;
+        if self.previous.verified {
+            write!(f, ", which was verified")?;
+        }
+        writeln!(f)?;
+        write!(f, "  input:  {}", key.input_hash)?;
+        if !key.parameters.is_empty() {
+            write!(f, " with {}", key.parameters)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum HistoryError {
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    InvalidRecord(PathBuf, usize),
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Read(path, _) => {
+                write!(f, "unable to read answer history from {}", path.display())
+            }
+            HistoryError::Write(path, _) => {
+                write!(f, "unable to write answer history to {}", path.display())
+            }
+            HistoryError::InvalidRecord(path, line_number) => write!(
+                f,
+                "invalid answer history record on line {} of {}",
+                line_number,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for HistoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HistoryError::Read(_, error) | HistoryError::Write(_, error) => Some(error),
+            HistoryError::InvalidRecord(_, _) => None,
+        }
+    }
+}
+
+impl History {
+    pub(crate) fn get_default_path() -> PathBuf {
+        workspace_root().join(HISTORY_FILE)
+    }
+
+    /// Reads the history from `path`, which is empty until the first answer
+    /// is recorded.
+    pub(crate) fn load(path: &Path) -> Result<History, HistoryError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(HistoryError::Read(path.to_path_buf(), error)),
+        };
+
+        let records = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .ok()
+                    .and_then(|value| Record::from_json(&value))
+                    .ok_or_else(|| HistoryError::InvalidRecord(path.to_path_buf(), i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History {
+            path: path.to_path_buf(),
+            records,
+        })
+    }
+
+    /// Writes the history to a temporary file next to it and then renames
+    /// that over it, so being interrupted part way leaves the old history
+    /// whole rather than truncated.
+    pub(crate) fn save(&self) -> Result<(), HistoryError> {
+        let contents: String = self
+            .records
+            .iter()
+            .map(|record| format!("{}\n", record.to_json()))
+            .collect();
+
+        let mut temporary_path = self.path.clone().into_os_string();
+        temporary_path.push(".tmp");
+
+        fs::write(&temporary_path, contents)
+            .and_then(|()| fs::rename(&temporary_path, &self.path))
+            .map_err(|error| HistoryError::Write(self.path.clone(), error))
+    }
+
+    pub(crate) fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Records a new answer, returning how it differs from the answer it
+    /// should agree with: the verified one if there is one, otherwise the most
+    /// recent.
+    pub(crate) fn record(
+        &mut self,
+        key: RecordKey,
+        answer: String,
+        commit: &str,
+    ) -> Option<Mismatch> {
+        let previous = self.get_reference(&key).cloned();
+        let mismatch = previous
+            .filter(|previous| previous.answer != answer)
+            .map(|previous| Mismatch {
+                previous,
+                answer: answer.clone(),
+                commit: commit.to_string(),
+            });
+
+        // Running the same commit again only needs recording once, as long as
+        // nothing different was recorded in between.
+        let already_recorded = self
+            .records
+            .iter()
+            .rev()
+            .find(|record| record.key == key)
+            .is_some_and(|record| record.answer == answer && record.commit == commit);
+        if !already_recorded {
+            let verified = self
+                .records
+                .iter()
+                .any(|record| record.key == key && record.answer == answer && record.verified);
+
+            self.records.push(Record {
+                key,
+                answer,
+                commit: commit.to_string(),
+                recorded_at: now(),
+                verified,
+            });
+        }
+
+        mismatch
+    }
+
+    /// Marks the most recent answer for `key` as verified, so later answers
+    /// are checked against it. Any other answer that had been verified for
+    /// the same key no longer is.
+    pub(crate) fn mark_verified(&mut self, key: &RecordKey) -> Option<&Record> {
+        let answer = self
+            .records
+            .iter()
+            .rev()
+            .find(|record| &record.key == key)?
+            .answer
+            .clone();
+
+        for record in self.records.iter_mut().filter(|record| &record.key == key) {
+            record.verified = record.answer == answer;
+        }
+
+        self.records.iter().rev().find(|record| &record.key == key)
+    }
+
+    fn get_reference(&self, key: &RecordKey) -> Option<&Record> {
+        let mut matching = self
+            .records
+            .iter()
+            .rev()
+            .filter(|record| &record.key == key);
+
+        matching
+            .clone()
+            .find(|record| record.verified)
+            .or_else(|| matching.next())
+    }
+}
+
+impl Record {
+    fn to_json(&self) -> Value {
+        json!({
+            "day": self.key.day,
+            "part": self.key.part,
+            "input_hash": self.key.input_hash,
+            "parameters": self.key.parameters,
+            "answer": self.answer,
+            "commit": self.commit,
+            "recorded_at": self.recorded_at,
+            "verified": self.verified,
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Record> {
+        let string = |field: &str| value.get(field)?.as_str().map(str::to_string);
+
+        Some(Record {
+            key: RecordKey {
+                day: value.get("day")?.as_u64()?.try_into().ok()?,
+                part: value.get("part")?.as_u64()?.try_into().ok()?,
+                input_hash: string("input_hash")?,
+                parameters: string("parameters")?,
+            },
+            answer: string("answer")?,
+            commit: string("commit")?,
+            recorded_at: value.get("recorded_at")?.as_u64()?,
+            verified: value.get("verified")?.as_bool()?,
+        })
+    }
+}
+
+/// The workspace's current commit, looked up when the run happens, marked
+/// `-dirty` if tracked files have changed since, or `unknown` outside a git
+/// checkout.
+pub(crate) fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        process::Command::new("git")
+            .args(args)
+            .current_dir(workspace_root())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(commit) = git(&["rev-parse", "--short=12", "HEAD"]) else {
+        return String::from("unknown");
+    };
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if changes.is_empty() => commit,
+        _ => format!("{}-dirty", commit),
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::history::{History, RecordKey};
+
+    use common::parameters::Parameters;
+
+    use std::fs;
+    use std::process;
+
+    fn key() -> RecordKey {
+        RecordKey::new(11, 2, "abc", &Parameters::default())
+    }
+
+    fn history() -> History {
+        History {
+            path: "history.local.jsonl".into(),
+            records: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn a_changed_answer_is_a_mismatch() {
+        let mut history = history();
+
+        assert_eq!(history.record(key(), "42".to_string(), "aaa"), None);
+        assert_eq!(history.record(key(), "42".to_string(), "bbb"), None);
+
+        let mismatch = history.record(key(), "43".to_string(), "ccc").unwrap();
+        assert_eq!(mismatch.previous.answer, "42");
+        assert_eq!(mismatch.previous.commit, "bbb");
+        assert_eq!(history.records().len(), 3);
+    }
+
+    #[test]
+    fn verified_answers_are_checked_first() {
+        let mut history = history();
+
+        history.record(key(), "42".to_string(), "aaa");
+        history.mark_verified(&key()).unwrap();
+        history.record(key(), "43".to_string(), "bbb");
+
+        let mismatch = history.record(key(), "43".to_string(), "ccc").unwrap();
+        assert_eq!(mismatch.previous.answer, "42");
+        assert!(mismatch.previous.verified);
+    }
+
+    #[test]
+    fn saves_by_replacing_the_file() {
+        let directory = std::env::temp_dir().join(format!("aoc-history-{}", process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("history.local.jsonl");
+        fs::write(&path, "not a record\n").unwrap();
+
+        let mut history = History {
+            path: path.clone(),
+            records: Vec::new(),
+        };
+        history.record(key(), "42".to_string(), "aaa");
+        history.save().unwrap();
+
+        let saved = History::load(&path).unwrap();
+        let leftovers = fs::read_dir(&directory).unwrap().count();
+        fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(saved.records(), history.records());
+        assert_eq!(leftovers, 1);
+    }
+
+    #[test]
+    fn records_survive_a_round_trip() {
+        let mut history = history();
+        history.record(key(), "42".to_string(), "aaa");
+        history.mark_verified(&key());
+
+        let record = &history.records()[0];
+        let json = record.to_json().to_string();
+
+        assert_eq!(
+            crate::history::Record::from_json(&serde_json::from_str(&json).unwrap()).as_ref(),
+            Some(record)
+        );
+    }
+}
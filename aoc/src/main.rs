@@ -0,0 +1,740 @@
+mod batch;
+mod config;
+mod history;
+mod render;
+mod report;
+mod scaffold;
+mod serve;
+mod trace;
+mod visualise;
+mod watch;
+
+use config::Config;
+use history::History;
+use history::Mismatch;
+use history::RecordKey;
+use report::DayReport;
+use report::Format;
+use report::PartReport;
+use visualise::PlayOptions;
+use watch::WatchOptions;
+
+use common::checked::Overflow;
+use common::input;
+use common::parameters::Parameters;
+use common::Answer;
+
+use days::Day;
+use days::ParsedInput;
+use days::Part;
+use days::DAYS;
+use days::PARTS;
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+use clap::Parser;
+use clap::Subcommand;
+use clap::ValueEnum;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Write how long was spent in each traced span as folded stacks, one
+    /// line of semicolon-separated span names and a duration per stack, for a
+    /// flamegraph.
+    #[arg(long, global = true, value_name = "PATH")]
+    folded_stacks: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with --all.
+    Run {
+        /// The day to solve.
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only solve the given part.
+        #[arg(long, value_enum)]
+        part: Option<PartArg>,
+
+        /// The puzzle input, or - for stdin. Defaults to the day's input.txt.
+        #[arg(conflicts_with = "all")]
+        input: Option<PathBuf>,
+
+        /// Solve every day against its input.txt.
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+
+        /// Report how long parsing and each part took on stderr.
+        #[arg(long)]
+        time: bool,
+
+        /// How to print the results. JSON always includes timings, the input
+        /// path and a hash of the input.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Also save a picture of the day's model, as out.png for grids or
+        /// out.svg for the day 18 trench, drawn with the same parameters the
+        /// day is solved with.
+        #[arg(long, value_name = "PATH", conflicts_with = "all")]
+        render: Option<PathBuf>,
+
+        /// Which part's model --render draws, for days where it differs
+        /// between the parts, like day 18's far bigger part two trench.
+        #[arg(long, value_enum, value_name = "PART", default_value_t = PartArg::One, requires = "render")]
+        render_part: PartArg,
+
+        /// Change one of the day's parameters, such as
+        /// --set part_two_expansion=10. Run `aoc parameters` to list them.
+        #[arg(long = "set", value_name = "NAME=VALUE", value_parser = parse_assignment, conflicts_with = "all")]
+        assignments: Vec<(String, String)>,
+
+        /// Where to read parameters for each day from. Defaults to aoc.toml in
+        /// the current directory, if there is one.
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+
+        /// Neither record the answers in the answer history nor check them
+        /// against it, for answers from code that is still being written.
+        #[arg(long)]
+        no_history: bool,
+    },
+    /// Solve a day for every input in a directory, such as other people's
+    /// puzzle inputs, and tabulate the answers. Fails if any input does.
+    Batch {
+        /// The day to solve.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The directory of puzzle inputs.
+        directory: PathBuf,
+
+        /// Only solve the given part.
+        #[arg(long, value_enum)]
+        part: Option<PartArg>,
+
+        /// Change one of the day's parameters, such as
+        /// --set part_two_expansion=10. Run `aoc parameters` to list them.
+        #[arg(long = "set", value_name = "NAME=VALUE", value_parser = parse_assignment)]
+        assignments: Vec<(String, String)>,
+
+        /// Where to read parameters for each day from. Defaults to aoc.toml in
+        /// the current directory, if there is one.
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+    },
+    /// Rebuild and re-run a day, and check its expected answers, whenever its
+    /// source, inputs or answers files change.
+    Watch {
+        /// The day to watch.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Build with optimisations, for days that are slow without them.
+        #[arg(long)]
+        release: bool,
+    },
+    /// Look back at the answers the runner has given.
+    History {
+        #[command(subcommand)]
+        command: HistoryCommand,
+    },
+    /// List the parameters each day can be run with, and their defaults.
+    Parameters {
+        /// Only list the given day's parameters.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+    /// Answer solve requests over HTTP from other programs on this machine.
+    /// POST a puzzle input to /days/{n}/parts/{p} to solve it, or GET /days
+    /// to list what can be solved.
+    Serve {
+        /// The port to listen on.
+        #[arg(long, default_value_t = 8023)]
+        port: u16,
+    },
+    /// Play a day's simulation in the terminal.
+    Visualise {
+        /// The day to visualise.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The puzzle input, or - for stdin. Defaults to the day's test.txt.
+        input: Option<PathBuf>,
+
+        /// Frames per second.
+        #[arg(long, default_value_t = 20.0, value_parser = parse_speed)]
+        speed: f64,
+
+        /// Draw every cell in the terminal's default colour.
+        #[arg(long)]
+        no_colour: bool,
+    },
+    /// Start a new day: create its crate from the template with an empty
+    /// test.txt and input.txt, and add it to the workspace and the runner.
+    New {
+        /// The day to create.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum HistoryCommand {
+    /// List every recorded answer, oldest first.
+    List {
+        /// Only list the given day's answers.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only list the given part's answers.
+        #[arg(long, value_enum)]
+        part: Option<PartArg>,
+    },
+    /// Mark the latest answer to a part as right, so every later run is
+    /// checked against it. Only answers with the default parameters can be
+    /// verified.
+    Verify {
+        /// The day the answer is for.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part the answer is for.
+        #[arg(value_enum)]
+        part: PartArg,
+
+        /// The puzzle input the answer is for. Defaults to the day's
+        /// input.txt.
+        input: Option<PathBuf>,
+    },
+}
+
+fn parse_assignment(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .ok_or_else(|| String::from("expected a parameter name and value like name=10"))
+}
+
+fn parse_speed(s: &str) -> Result<f64, String> {
+    match s.parse::<f64>() {
+        Ok(speed) if speed > 0.0 && speed.is_finite() => Ok(speed),
+        Ok(_) => Err(String::from(
+            "speed must be a positive number of frames per second",
+        )),
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum PartArg {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl From<PartArg> for Part {
+    fn from(value: PartArg) -> Self {
+        match value {
+            PartArg::One => Part::One,
+            PartArg::Two => Part::Two,
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(error) = trace::init(cli.folded_stacks.as_deref()) {
+        eprintln!("error: unable to write folded stacks: {}", error);
+        process::exit(1);
+    }
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            all,
+            time,
+            format,
+            render,
+            render_part,
+            assignments,
+            config,
+            no_history,
+        } => {
+            let parts: Vec<Part> = match part {
+                Some(part) => vec![part.into()],
+                None => PARTS.to_vec(),
+            };
+
+            let config = match Config::load(config.as_deref()) {
+                Ok(config) => config,
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    process::exit(1);
+                }
+            };
+
+            let mut history = (!no_history).then(load_history_or_exit);
+            let commit = history::current_commit();
+            let mut mismatches = Vec::new();
+
+            let mut reports = Vec::new();
+
+            if all {
+                for day in DAYS.iter() {
+                    if format == Format::Text {
+                        println!("Day {}", day.number);
+                    }
+                    let parameters = get_parameters_or_exit(day, &config, &[]);
+                    reports.push(run_day(
+                        day,
+                        &parts,
+                        &parameters,
+                        day.get_default_input_path(),
+                        format,
+                        time,
+                    ));
+                    if let Some(history) = &mut history {
+                        mismatches.extend(record_answers(
+                            history,
+                            reports.last().unwrap(),
+                            &parameters,
+                            &commit,
+                        ));
+                    }
+                }
+            } else {
+                let day_number = day.unwrap();
+                let Some(day) = days::get_day(day_number) else {
+                    eprintln!("error: day {} has not been solved yet", day_number);
+                    process::exit(1);
+                };
+                let input_path = input.unwrap_or_else(|| day.get_default_input_path());
+
+                let parameters = get_parameters_or_exit(day, &config, &assignments);
+
+                if let Some(image_path) = render {
+                    render_day(
+                        day,
+                        &input_path,
+                        &parameters,
+                        render_part.into(),
+                        &image_path,
+                    );
+                }
+
+                reports.push(run_day(day, &parts, &parameters, input_path, format, time));
+                if let Some(history) = &mut history {
+                    mismatches.extend(record_answers(
+                        history,
+                        reports.last().unwrap(),
+                        &parameters,
+                        &commit,
+                    ));
+                }
+            }
+
+            if format == Format::Json {
+                report::print_json(&reports);
+            }
+
+            if let Some(Err(error)) = history.map(|history| history.save()) {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+
+            let overflows: Vec<(u8, Part)> = reports
+                .iter()
+                .flat_map(|report| {
+                    report
+                        .parts
+                        .iter()
+                        .filter(|part| part.answer == Answer::Overflow)
+                        .map(|part| (report.day, part.part))
+                })
+                .collect();
+            for (day, part) in &overflows {
+                eprintln!("\nerror: day {} {}: {}", day, part.name(), Overflow);
+            }
+
+            if !mismatches.is_empty() {
+                for mismatch in &mismatches {
+                    eprintln!("\nerror: {}", mismatch);
+                }
+                eprintln!(
+                    "\n{} answer(s) changed, run `aoc history list` to see what was recorded before",
+                    mismatches.len()
+                );
+                process::exit(1);
+            }
+
+            if !overflows.is_empty() {
+                process::exit(1);
+            }
+        }
+        Command::Batch {
+            day,
+            directory,
+            part,
+            assignments,
+            config,
+        } => {
+            let Some(day) = days::get_day(day) else {
+                eprintln!("error: day {} has not been solved yet", day);
+                process::exit(1);
+            };
+            let parts: Vec<Part> = match part {
+                Some(part) => vec![part.into()],
+                None => PARTS.to_vec(),
+            };
+
+            let config = match Config::load(config.as_deref()) {
+                Ok(config) => config,
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    process::exit(1);
+                }
+            };
+            let parameters = get_parameters_or_exit(day, &config, &assignments);
+
+            let results = match batch::run(day, &parts, &parameters, &directory) {
+                Ok(results) => results,
+                Err(error) => {
+                    eprintln!("error: unable to read {}: {}", directory.display(), error);
+                    process::exit(1);
+                }
+            };
+            batch::print_table(&results, &parts);
+
+            let failures = results
+                .iter()
+                .filter(|result| result.failure.is_some())
+                .count();
+            if failures > 0 {
+                eprintln!("\n{} of {} input(s) failed", failures, results.len());
+                process::exit(1);
+            }
+        }
+        Command::Watch { day, release } => {
+            let Some(day) = days::get_day(day) else {
+                eprintln!("error: day {} has not been solved yet", day);
+                process::exit(1);
+            };
+
+            if let Err(error) =
+                watch::watch(&day.get_directory(), day.number, WatchOptions { release })
+            {
+                eprintln!("error: unable to watch day {}: {}", day.number, error);
+                process::exit(1);
+            }
+        }
+        Command::History {
+            command: HistoryCommand::List { day, part },
+        } => {
+            let history = load_history_or_exit();
+            let part = part.map(|part| Part::from(part).number());
+
+            println!(
+                "{:<4} {:<5} {:<20} {:<18} {:<14} {:<8} parameters",
+                "day", "part", "answer", "commit", "input", "verified"
+            );
+            for record in history.records().iter().filter(|record| {
+                day.is_none_or(|day| record.key.day == day)
+                    && part.is_none_or(|part| record.key.part == part)
+            }) {
+                println!(
+                    "{:<4} {:<5} {:<20} {:<18} {:<14} {:<8} {}",
+                    record.key.day,
+                    record.key.part,
+                    record.answer,
+                    record.commit,
+                    &record.key.input_hash[..record.key.input_hash.len().min(12)],
+                    if record.verified { "yes" } else { "" },
+                    record.key.parameters
+                );
+            }
+        }
+        Command::History {
+            command: HistoryCommand::Verify { day, part, input },
+        } => {
+            let Some(day) = days::get_day(day) else {
+                eprintln!("error: day {} has not been solved yet", day);
+                process::exit(1);
+            };
+            let part = Part::from(part);
+            let input_path = input.unwrap_or_else(|| day.get_default_input_path());
+            let input_hash = report::input_hash(&read_input_or_exit(&input_path));
+
+            let mut history = load_history_or_exit();
+            let key = RecordKey::new(
+                day.number,
+                part.number(),
+                &input_hash,
+                &day.default_parameters(),
+            );
+
+            let Some(record) = history.mark_verified(&key) else {
+                eprintln!(
+                    "error: no answer to day {} {} has been recorded for {}, run it first",
+                    day.number,
+                    part.name(),
+                    input_path.display()
+                );
+                process::exit(1);
+            };
+            println!(
+                "verified {} as the answer to day {} {}",
+                record.answer,
+                day.number,
+                part.name()
+            );
+
+            if let Err(error) = history.save() {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+        }
+        Command::Parameters { day } => {
+            let days: Vec<&Day> = DAYS
+                .iter()
+                .filter(|candidate| day.is_none_or(|day| candidate.number == day))
+                .filter(|day| !day.parameters.is_empty())
+                .collect();
+
+            if let (Some(day), true) = (day, days.is_empty()) {
+                println!("day {} has no parameters", day);
+            }
+
+            for day in days {
+                println!("Day {}", day.number);
+
+                for parameter in day.parameters {
+                    let assignment = format!("{} = {}", parameter.name, parameter.default);
+                    println!("  {:<40} {}", assignment, parameter.description);
+                }
+            }
+        }
+        Command::Serve { port } => {
+            if let Err(error) = serve::serve(port) {
+                eprintln!("error: unable to serve on port {}: {}", port, error);
+                process::exit(1);
+            }
+        }
+        Command::Visualise {
+            day,
+            input,
+            speed,
+            no_colour,
+        } => {
+            let Some(day) = days::get_day(day) else {
+                eprintln!("error: day {} has not been solved yet", day);
+                process::exit(1);
+            };
+            let input_path = input.unwrap_or_else(|| day.get_default_test_input_path());
+            let parsed_input = parse_or_exit(day, &read_input_or_exit(&input_path));
+
+            let Some(frames) = day.frames(parsed_input.as_ref()) else {
+                let simulated: Vec<String> = DAYS
+                    .iter()
+                    .filter(|day| day.is_simulated())
+                    .map(|day| day.number.to_string())
+                    .collect();
+                eprintln!(
+                    "error: day {} has nothing to visualise, try one of {}",
+                    day.number,
+                    simulated.join(", ")
+                );
+                process::exit(1);
+            };
+
+            let options = PlayOptions {
+                speed,
+                colour: !no_colour,
+            };
+            if let Err(error) = visualise::play(frames, options) {
+                eprintln!("error: {}", error);
+                process::exit(1);
+            }
+        }
+        Command::New { day } => {
+            let directory = match scaffold::create(day) {
+                Ok(directory) => directory,
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    process::exit(1);
+                }
+            };
+
+            println!("created day {} in {}", day, directory.display());
+            println!("put the example in test.txt and its answers in answers.toml");
+        }
+    }
+}
+
+fn render_day(
+    day: &Day,
+    input_path: &Path,
+    parameters: &Parameters,
+    part: Part,
+    image_path: &Path,
+) {
+    let parsed_input = parse_or_exit(day, &read_input_or_exit(input_path));
+
+    let Some(image) = day.render(parsed_input.as_ref(), parameters, part) else {
+        let rendered: Vec<String> = DAYS
+            .iter()
+            .filter(|day| day.is_rendered())
+            .map(|day| day.number.to_string())
+            .collect();
+        eprintln!(
+            "error: day {} has nothing to render, try one of {}",
+            day.number,
+            rendered.join(", ")
+        );
+        process::exit(1);
+    };
+
+    if let Err(error) = render::save(&image, image_path) {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+/// The day's parameters from the config, then with each `--set` applied on
+/// top.
+fn get_parameters_or_exit(
+    day: &Day,
+    config: &Config,
+    assignments: &[(String, String)],
+) -> Parameters {
+    let mut parameters = match config.parameters(day) {
+        Ok(parameters) => parameters,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    };
+
+    for (name, value) in assignments {
+        if let Err(error) = parameters.set_str(name, value) {
+            eprintln!("error: --set {}={}: {}", name, value, error);
+            process::exit(1);
+        }
+    }
+
+    parameters
+}
+
+fn load_history_or_exit() -> History {
+    match History::load(&History::get_default_path()) {
+        Ok(history) => history,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+/// Records each answer in the report, returning those that differ from the
+/// answer given before.
+fn record_answers(
+    history: &mut History,
+    report: &DayReport,
+    parameters: &Parameters,
+    commit: &str,
+) -> Vec<Mismatch> {
+    report
+        .parts
+        .iter()
+        .filter(|part| !matches!(part.answer, Answer::Unavailable | Answer::Overflow))
+        .filter_map(|part| {
+            let key = RecordKey::new(
+                report.day,
+                part.part.number(),
+                &report.input_hash,
+                parameters,
+            );
+
+            history.record(key, part.answer.to_string(), commit)
+        })
+        .collect()
+}
+
+fn read_input_or_exit(input_path: &Path) -> String {
+    match input::read_input(input_path) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_or_exit(day: &Day, input: &str) -> ParsedInput {
+    match day.parse(input) {
+        Ok(parsed_input) => parsed_input,
+        Err(error) => {
+            eprintln!("error: unable to parse day {} input: {}", day.number, error);
+            process::exit(1);
+        }
+    }
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    parameters: &Parameters,
+    input_path: PathBuf,
+    format: Format,
+    time: bool,
+) -> DayReport {
+    let input = read_input_or_exit(&input_path);
+
+    let start = Instant::now();
+    let parsed_input = parse_or_exit(day, &input);
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = day.solve(*part, parsed_input.as_ref(), parameters);
+            let elapsed = start.elapsed();
+
+            if format == Format::Text {
+                println!("{}", answer);
+            }
+
+            PartReport {
+                part: *part,
+                answer,
+                elapsed,
+            }
+        })
+        .collect();
+
+    let report = DayReport {
+        day: day.number,
+        input_path,
+        input_hash: report::input_hash(&input),
+        parse_elapsed,
+        parts,
+    };
+
+    if format == Format::Text && time {
+        report.print_timings();
+    }
+
+    report
+}
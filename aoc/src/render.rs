@@ -0,0 +1,110 @@
+use common::frame::Frame;
+use common::image::Image;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Small grids are scaled up so each cell is a square of pixels and the
+/// image is at least this wide or tall.
+const MIN_IMAGE_SIZE: usize = 1024;
+
+const BACKGROUND: [u8; 3] = [0, 0, 0];
+
+#[derive(Debug)]
+pub(crate) enum SaveImageError {
+    WrongExtension(&'static str),
+    EmptyImage,
+    Io(io::Error),
+    Png(png::EncodingError),
+}
+
+impl Display for SaveImageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveImageError::WrongExtension(extension) => {
+                write!(f, "this day can only be saved as a .{} file", extension)
+            }
+            SaveImageError::EmptyImage => write!(f, "there is nothing to draw"),
+            SaveImageError::Io(error) => write!(f, "unable to write the image: {}", error),
+            SaveImageError::Png(error) => write!(f, "unable to encode the image: {}", error),
+        }
+    }
+}
+
+impl Error for SaveImageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SaveImageError::WrongExtension(_) | SaveImageError::EmptyImage => None,
+            SaveImageError::Io(error) => Some(error),
+            SaveImageError::Png(error) => Some(error),
+        }
+    }
+}
+
+/// Saves the image as a PNG or SVG, whichever it is drawn as. The path's
+/// extension has to match, so a picture is never saved under the wrong type.
+pub(crate) fn save(image: &Image, path: &Path) -> Result<(), SaveImageError> {
+    let extension = image.extension();
+    if path.extension().and_then(|extension| extension.to_str()) != Some(extension) {
+        return Err(SaveImageError::WrongExtension(extension));
+    }
+
+    match image {
+        Image::Raster(frame) => save_png(frame, path),
+        Image::Vector(svg) => fs::write(path, svg.to_string()).map_err(SaveImageError::Io),
+    }
+}
+
+fn save_png(frame: &Frame, path: &Path) -> Result<(), SaveImageError> {
+    let height = frame.rows().len();
+    let width = frame.rows().iter().map(Vec::len).max().unwrap_or(0);
+    if width == 0 || height == 0 {
+        return Err(SaveImageError::EmptyImage);
+    }
+
+    let cell_size = (MIN_IMAGE_SIZE / width.max(height)).max(1);
+
+    let mut pixels = Vec::with_capacity(width * height * cell_size * cell_size * 3);
+    for row in frame.rows() {
+        let mut line = Vec::with_capacity(width * cell_size * 3);
+
+        for x in 0..width {
+            let colour = row
+                .get(x)
+                .and_then(|cell| cell.colour)
+                .map(|colour| {
+                    let (red, green, blue) = colour.rgb();
+                    [red, green, blue]
+                })
+                .unwrap_or(BACKGROUND);
+
+            for _ in 0..cell_size {
+                line.extend_from_slice(&colour);
+            }
+        }
+
+        for _ in 0..cell_size {
+            pixels.extend_from_slice(&line);
+        }
+    }
+
+    let file = File::create(path).map_err(SaveImageError::Io)?;
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(file),
+        (width * cell_size) as u32,
+        (height * cell_size) as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(SaveImageError::Png)?;
+    writer
+        .write_image_data(&pixels)
+        .map_err(SaveImageError::Png)?;
+    writer.finish().map_err(SaveImageError::Png)
+}
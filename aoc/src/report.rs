@@ -0,0 +1,115 @@
+use common::input;
+use common::Answer;
+
+use days::Part;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde_json::json;
+use serde_json::Value;
+use sha2::Digest;
+use sha2::Sha256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    /// One answer per line.
+    Text,
+    /// A single JSON document once every day has been solved.
+    Json,
+}
+
+/// Everything a run found out about one day.
+pub(crate) struct DayReport {
+    pub(crate) day: u8,
+    pub(crate) input_path: PathBuf,
+    pub(crate) input_hash: String,
+    pub(crate) parse_elapsed: Duration,
+    pub(crate) parts: Vec<PartReport>,
+}
+
+pub(crate) struct PartReport {
+    pub(crate) part: Part,
+    pub(crate) answer: Answer,
+    pub(crate) elapsed: Duration,
+}
+
+/// The SHA-256 of the input after normalising line endings, so the same
+/// puzzle input hashes the same however it was saved.
+pub(crate) fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input::normalise(input)))
+}
+
+impl DayReport {
+    /// Writes how long parsing and each part took to stderr.
+    pub(crate) fn print_timings(&self) {
+        let timings = std::iter::once(("parse", self.parse_elapsed)).chain(
+            self.parts
+                .iter()
+                .map(|part| (part.part.name(), part.elapsed)),
+        );
+
+        for (phase, elapsed) in timings {
+            eprintln!("{:<8} {:>10.2?}", phase, elapsed);
+        }
+        eprintln!("{:<8} {:>10.2?}", "total", self.total_elapsed());
+    }
+
+    fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+
+    fn to_json(&self) -> Value {
+        let parts: Vec<Value> = self
+            .parts
+            .iter()
+            .map(|part| {
+                json!({
+                    "part": part.part.number(),
+                    "answer": answer_to_json(&part.answer),
+                    "status": answer_status(&part.answer),
+                    "elapsed_ms": milliseconds(part.elapsed),
+                })
+            })
+            .collect();
+
+        json!({
+            "day": self.day,
+            "input_path": self.input_path.display().to_string(),
+            "input_hash": self.input_hash,
+            "parse_elapsed_ms": milliseconds(self.parse_elapsed),
+            "parts": parts,
+        })
+    }
+}
+
+/// Answers are strings so large numbers survive JSON parsers that read every
+/// number as a double, and `null` when there is nothing to solve or the
+/// answer overflowed. `answer_status` tells those two apart.
+pub(crate) fn answer_to_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Unavailable | Answer::Overflow => Value::Null,
+        answer => Value::String(answer.to_string()),
+    }
+}
+
+/// Whether there is an answer, or why not: `"ok"`, `"unavailable"` or
+/// `"overflow"`.
+pub(crate) fn answer_status(answer: &Answer) -> &'static str {
+    match answer {
+        Answer::Unavailable => "unavailable",
+        Answer::Overflow => "overflow",
+        Answer::Unsigned(_) | Answer::Signed(_) | Answer::Text(_) => "ok",
+    }
+}
+
+pub(crate) fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub(crate) fn print_json(reports: &[DayReport]) {
+    let days: Vec<Value> = reports.iter().map(DayReport::to_json).collect();
+
+    println!("{:#}", json!({ "days": days }));
+}
@@ -0,0 +1,382 @@
+use days::workspace_root;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+const CARGO_TOML_TEMPLATE: &str = include_str!("../template/Cargo.toml.template");
+const LIB_RS_TEMPLATE: &str = include_str!("../template/lib.rs.template");
+const ANSWERS_RS_TEMPLATE: &str = include_str!("../template/answers.rs.template");
+const ANSWERS_TOML_TEMPLATE: &str = include_str!("../template/answers.toml.template");
+const FUZZ_TARGET_TEMPLATE: &str = include_str!("../template/fuzz_target.rs.template");
+
+const NUMBER_NAMES: [&str; 20] = [
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+];
+
+#[derive(Debug)]
+pub(crate) enum NewDayError {
+    AlreadyExists(PathBuf),
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+    /// A file the day is registered in doesn't list the other days in the
+    /// expected way, or already lists this one.
+    Unrecognised(PathBuf, u8),
+}
+
+impl Display for NewDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NewDayError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            NewDayError::Read(path, _) => write!(f, "unable to read {}", path.display()),
+            NewDayError::Write(path, _) => write!(f, "unable to write {}", path.display()),
+            NewDayError::Unrecognised(path, day) => write!(
+                f,
+                "unable to find where to add day {} in {}",
+                day,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for NewDayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NewDayError::Read(_, error) | NewDayError::Write(_, error) => Some(error),
+            NewDayError::AlreadyExists(_) | NewDayError::Unrecognised(_, _) => None,
+        }
+    }
+}
+
+/// The day's package name, spelled out like the others.
+fn package_name(day: u8) -> String {
+    match day {
+        1..=20 => NUMBER_NAMES[day as usize - 1].to_string(),
+        _ => format!("twenty-{}", NUMBER_NAMES[day as usize - 21]),
+    }
+}
+
+/// Fills in a template's placeholders for `day`.
+fn fill(template: &str, day: u8) -> String {
+    let package = package_name(day);
+
+    template
+        .replace("{{package}}", &package)
+        .replace("{{crate}}", &package.replace('-', "_"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Each file in a new day's directory, with its contents.
+fn template_files(day: u8) -> [(&'static str, String); 6] {
+    let fill = |template: &str| fill(template, day);
+
+    [
+        ("Cargo.toml", fill(CARGO_TOML_TEMPLATE)),
+        ("src/lib.rs", fill(LIB_RS_TEMPLATE)),
+        ("tests/answers.rs", fill(ANSWERS_RS_TEMPLATE)),
+        ("answers.toml", fill(ANSWERS_TOML_TEMPLATE)),
+        ("test.txt", String::new()),
+        ("input.txt", String::new()),
+    ]
+}
+
+/// Adds `line` among the lines that `day_of` finds a day in, keeping them in
+/// day order. Lines starting with `.` continue the day before them. `None` if
+/// there are no such lines or one is already for `day`.
+fn insert_in_day_order(
+    contents: &str,
+    day: u8,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line)?)))
+        .collect();
+
+    if days.iter().any(|(_, existing)| *existing == day) {
+        return None;
+    }
+
+    let (last_index, _) = days.last()?;
+    let after_last = last_index
+        + 1
+        + lines[last_index + 1..]
+            .iter()
+            .take_while(|line| line.trim_start().starts_with('.'))
+            .count();
+    let index = days
+        .iter()
+        .find(|(_, existing)| *existing > day)
+        .map_or(after_last, |(index, _)| *index);
+    lines.insert(index, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the day's directory to the workspace `members`.
+fn add_workspace_member(manifest: &str, day: u8) -> Option<String> {
+    insert_in_day_order(manifest, day, &format!("    \"{}\",", day), |line| {
+        line.trim()
+            .strip_prefix('"')?
+            .strip_suffix("\",")?
+            .parse()
+            .ok()
+    })
+}
+
+/// Adds the day's crate to the dependencies of the days crate.
+fn add_registry_dependency(manifest: &str, day: u8) -> Option<String> {
+    let line = format!("{} = {{ path = \"../{}\" }}", package_name(day), day);
+
+    insert_in_day_order(manifest, day, &line, |line| {
+        line.split_once("{ path = \"../")?
+            .1
+            .strip_suffix("\" }")?
+            .parse()
+            .ok()
+    })
+}
+
+/// Adds the day to `DAYS`, growing the array to fit.
+fn add_registration(source: &str, day: u8) -> Option<String> {
+    let crate_name = package_name(day).replace('-', "_");
+    let line = format!(
+        "    Day::new::<{}::Day>({}).inspected::<{}::Day>(),",
+        crate_name, day, crate_name
+    );
+    let day_of = |line: &str| {
+        line.trim()
+            .strip_prefix("Day::")?
+            .split_once(">(")?
+            .1
+            .split_once(')')?
+            .0
+            .parse()
+            .ok()
+    };
+
+    let source = insert_in_day_order(source, day, &line, day_of)?;
+    let count = source.lines().filter_map(day_of).count();
+
+    let (before, after) = source.split_once("pub const DAYS: [Day; ")?;
+    let (_, after) = after.split_once(']')?;
+
+    Some(format!(
+        "{}pub const DAYS: [Day; {}]{}",
+        before, count, after
+    ))
+}
+
+/// Adds a `[[bin]]` target for the day to the fuzz manifest, keeping the
+/// targets in day order.
+fn add_fuzz_target(manifest: &str, day: u8) -> Option<String> {
+    const SEPARATOR: &str = "\n[[bin]]\n";
+
+    let target = format!(
+        "name = \"day_{:02}\"\npath = \"fuzz_targets/day_{:02}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        day, day
+    );
+    let day_of = |target: &str| -> Option<u8> {
+        target
+            .strip_prefix("name = \"day_")?
+            .split_once('"')?
+            .0
+            .parse()
+            .ok()
+    };
+
+    let mut sections: Vec<&str> = manifest.split(SEPARATOR).collect();
+    let days = sections[1..]
+        .iter()
+        .map(|target| day_of(target))
+        .collect::<Option<Vec<u8>>>()?;
+
+    if days.is_empty() || days.contains(&day) {
+        return None;
+    }
+
+    let index = days
+        .iter()
+        .position(|existing| *existing > day)
+        .unwrap_or(days.len());
+    sections.insert(index + 1, &target);
+
+    Some(sections.join(SEPARATOR))
+}
+
+type Edit = fn(&str, u8) -> Option<String>;
+
+/// Creates the crate for `day` from the template, with empty `test.txt` and
+/// `input.txt`, and registers it with the workspace and in `DAYS`, which the
+/// runner, its benchmarks and the Python bindings share. Also adds a fuzz
+/// target for the day's parser. Nothing is written unless every file it is
+/// registered in could be edited.
+pub(crate) fn create(day: u8) -> Result<PathBuf, NewDayError> {
+    let workspace_root = workspace_root();
+    let directory = days::day_directory(day);
+    let fuzz_target_path = workspace_root.join(format!("fuzz/fuzz_targets/day_{:02}.rs", day));
+
+    if directory.exists() {
+        return Err(NewDayError::AlreadyExists(directory));
+    }
+    if fuzz_target_path.exists() {
+        return Err(NewDayError::AlreadyExists(fuzz_target_path));
+    }
+
+    let edits: [(PathBuf, Edit); 4] = [
+        (workspace_root.join("Cargo.toml"), add_workspace_member),
+        (
+            workspace_root.join("days/Cargo.toml"),
+            add_registry_dependency,
+        ),
+        (workspace_root.join("days/src/lib.rs"), add_registration),
+        (workspace_root.join("fuzz/Cargo.toml"), add_fuzz_target),
+    ];
+    let edited = edits
+        .into_iter()
+        .map(|(path, edit)| {
+            let contents = fs::read_to_string(&path)
+                .map_err(|error| NewDayError::Read(path.clone(), error))?;
+
+            match edit(&contents, day) {
+                Some(contents) => Ok((path, contents)),
+                None => Err(NewDayError::Unrecognised(path, day)),
+            }
+        })
+        .collect::<Result<Vec<(PathBuf, String)>, NewDayError>>()?;
+
+    for (file_name, contents) in template_files(day) {
+        write(&directory.join(file_name), &contents)?;
+    }
+    write(&fuzz_target_path, &fill(FUZZ_TARGET_TEMPLATE, day))?;
+    for (path, contents) in edited {
+        write(&path, &contents)?;
+    }
+
+    Ok(directory)
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), NewDayError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|error| NewDayError::Write(path.to_path_buf(), error))?;
+    }
+
+    fs::write(path, contents).map_err(|error| NewDayError::Write(path.to_path_buf(), error))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::scaffold::{
+        add_fuzz_target, add_registration, add_registry_dependency, add_workspace_member, fill,
+        package_name, template_files, FUZZ_TARGET_TEMPLATE,
+    };
+
+    #[test]
+    fn names_packages_like_the_other_days() {
+        assert_eq!(package_name(1), "one");
+        assert_eq!(package_name(20), "twenty");
+        assert_eq!(package_name(21), "twenty-one");
+        assert_eq!(package_name(25), "twenty-five");
+    }
+
+    #[test]
+    fn fills_in_every_placeholder() {
+        for (file_name, contents) in template_files(23) {
+            assert!(!contents.contains("{{"), "{} was not filled in", file_name);
+        }
+
+        let [cargo_toml, _, answers_rs, ..] = template_files(23);
+        assert!(cargo_toml.1.contains("name = \"twenty-three\""));
+        assert!(answers_rs.1.contains("twenty_three::Day"));
+
+        let fuzz_target = fill(FUZZ_TARGET_TEMPLATE, 23);
+        assert!(!fuzz_target.contains("{{"));
+        assert!(fuzz_target.contains("days::get_day(23)"));
+    }
+
+    #[test]
+    fn registers_the_day_in_order() {
+        let manifest = "[workspace]\nmembers = [\n    \"1\",\n    \"3\",\n    \"aoc\",\n]\n";
+        assert_eq!(
+            add_workspace_member(manifest, 2).unwrap(),
+            "[workspace]\nmembers = [\n    \"1\",\n    \"2\",\n    \"3\",\n    \"aoc\",\n]\n"
+        );
+        assert_eq!(
+            add_workspace_member(manifest, 4).unwrap(),
+            "[workspace]\nmembers = [\n    \"1\",\n    \"3\",\n    \"4\",\n    \"aoc\",\n]\n"
+        );
+        assert_eq!(add_workspace_member(manifest, 3), None);
+
+        let manifest = "[dependencies]\ncommon = { path = \"../common\" }\none = { path = \"../1\" }\n\n[features]\n";
+        assert_eq!(
+            add_registry_dependency(manifest, 21).unwrap(),
+            "[dependencies]\ncommon = { path = \"../common\" }\none = { path = \"../1\" }\ntwenty-one = { path = \"../21\" }\n\n[features]\n"
+        );
+
+        let source = "pub const DAYS: [Day; 2] = [\n    Day::new::<one::Day>(1),\n    Day::simulated::<ten::Day>(10)\n        .rendered::<ten::Day>(),\n];\n";
+        assert_eq!(
+            add_registration(source, 21).unwrap(),
+            "pub const DAYS: [Day; 3] = [\n    Day::new::<one::Day>(1),\n    Day::simulated::<ten::Day>(10)\n        .rendered::<ten::Day>(),\n    Day::new::<twenty_one::Day>(21).inspected::<twenty_one::Day>(),\n];\n"
+        );
+        assert_eq!(
+            add_registration(source, 2).unwrap(),
+            "pub const DAYS: [Day; 3] = [\n    Day::new::<one::Day>(1),\n    Day::new::<two::Day>(2).inspected::<two::Day>(),\n    Day::simulated::<ten::Day>(10)\n        .rendered::<ten::Day>(),\n];\n"
+        );
+        assert_eq!(add_registration(source, 10), None);
+        assert_eq!(add_registration("", 21), None);
+
+        let target = |day: u8| {
+            format!(
+                "[[bin]]\nname = \"day_{:02}\"\npath = \"fuzz_targets/day_{:02}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+                day, day
+            )
+        };
+        let manifest = format!(
+            "[workspace]\nmembers = [\".\"]\n\n{}\n{}",
+            target(1),
+            target(3)
+        );
+        assert_eq!(
+            add_fuzz_target(&manifest, 2).unwrap(),
+            format!(
+                "[workspace]\nmembers = [\".\"]\n\n{}\n{}\n{}",
+                target(1),
+                target(2),
+                target(3)
+            )
+        );
+        assert_eq!(
+            add_fuzz_target(&manifest, 21).unwrap(),
+            format!("{}\n{}", manifest, target(21))
+        );
+        assert_eq!(add_fuzz_target(&manifest, 3), None);
+        assert_eq!(add_fuzz_target("[workspace]\n", 21), None);
+    }
+}
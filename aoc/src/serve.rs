@@ -0,0 +1,396 @@
+use crate::batch;
+use crate::report;
+
+use days::Part;
+use days::DAYS;
+
+use std::io;
+use std::io::Read;
+use std::panic;
+use std::panic::AssertUnwindSafe;
+use std::time::Instant;
+
+use serde_json::json;
+use serde_json::Value;
+use tiny_http::Header;
+use tiny_http::Method;
+use tiny_http::Request;
+use tiny_http::Response;
+use tiny_http::Server;
+
+/// Only other programs on the same machine can reach the server.
+const HOST: &str = "127.0.0.1";
+
+/// Far more than any puzzle input, but stops a client filling the memory.
+const MAX_BODY_LENGTH: usize = 1024 * 1024;
+
+/// Listens on `port` until interrupted, answering one request at a time. A
+/// panicking solver only fails the request that caused it.
+///
+/// `GET /days` lists the days and their parameters. `POST /days/{n}/parts/{p}`
+/// solves a part for the puzzle input in the body, with any of the day's
+/// parameters changed in the query string, like `?part_two_expansion=10`.
+pub(crate) fn serve(port: u16) -> io::Result<()> {
+    let server = bind(port)?;
+    eprintln!("listening on http://{}", server.server_addr());
+
+    handle_requests(&server);
+
+    Ok(())
+}
+
+fn bind(port: u16) -> io::Result<Server> {
+    Server::http((HOST, port)).map_err(io::Error::other)
+}
+
+fn handle_requests(server: &Server) {
+    for request in server.incoming_requests() {
+        if let Err(error) = handle_request(request) {
+            eprintln!("error: unable to respond: {}", error);
+        }
+    }
+}
+
+fn handle_request(mut request: Request) -> io::Result<()> {
+    let (status, json) = match read_body(&mut request) {
+        Ok(body) => respond(request.method(), request.url(), &body),
+        Err(response) => response,
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .expect("the content type header should be valid");
+    let response = Response::from_string(json.to_string())
+        .with_status_code(status)
+        .with_header(content_type);
+
+    request.respond(response)
+}
+
+/// The request's body, or the response to give if it can't be read.
+fn read_body(request: &mut Request) -> Result<String, (u16, Value)> {
+    let too_large = || {
+        error_response(
+            413,
+            format!("the body is longer than {} bytes", MAX_BODY_LENGTH),
+        )
+    };
+
+    if request
+        .body_length()
+        .is_some_and(|length| length > MAX_BODY_LENGTH)
+    {
+        return Err(too_large());
+    }
+
+    // One byte over the limit is enough to tell a body without a length is
+    // too long.
+    let mut body = String::new();
+    request
+        .as_reader()
+        .take(MAX_BODY_LENGTH as u64 + 1)
+        .read_to_string(&mut body)
+        .map_err(|error| error_response(400, format!("unable to read the body: {}", error)))?;
+
+    if body.len() > MAX_BODY_LENGTH {
+        return Err(too_large());
+    }
+
+    Ok(body)
+}
+
+/// The status code and JSON body to answer a request with.
+fn respond(method: &Method, url: &str, body: &str) -> (u16, Value) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    match (method, segments.as_slice()) {
+        (Method::Get, ["days"]) => (200, list_days()),
+        (Method::Post, ["days", day, "parts", part]) => solve(day, part, query, body),
+        (_, ["days"] | ["days", _, "parts", _]) => {
+            error_response(405, format!("{} is not allowed on {}", method, path))
+        }
+        _ => error_response(404, format!("nothing at {}", path)),
+    }
+}
+
+fn list_days() -> Value {
+    let days: Vec<Value> = DAYS
+        .iter()
+        .map(|day| {
+            let parameters: Vec<Value> = day
+                .parameters
+                .iter()
+                .map(|parameter| {
+                    json!({
+                        "name": parameter.name,
+                        "description": parameter.description,
+                        "default": parameter.default,
+                    })
+                })
+                .collect();
+
+            json!({
+                "day": day.number,
+                "parameters": parameters,
+            })
+        })
+        .collect();
+
+    json!({ "days": days })
+}
+
+fn solve(day: &str, part: &str, query: &str, body: &str) -> (u16, Value) {
+    let Some(day) = day.parse().ok().and_then(days::get_day) else {
+        return error_response(404, format!("day {} has not been solved yet", day));
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return error_response(404, format!("there is no part {}", part)),
+    };
+
+    let mut parameters = day.default_parameters();
+    for assignment in query.split('&').filter(|s| !s.is_empty()) {
+        let (name, value) = assignment.split_once('=').unwrap_or((assignment, ""));
+
+        if let Err(error) = parameters.set_str(name, value) {
+            return error_response(400, format!("{}: {}", assignment, error));
+        }
+    }
+
+    let start = Instant::now();
+    let parsed_input = match panic::catch_unwind(|| day.parse(body)) {
+        Ok(Ok(parsed_input)) => parsed_input,
+        Ok(Err(error)) => {
+            return error_response(
+                400,
+                format!("unable to parse day {} input: {}", day.number, error),
+            )
+        }
+        Err(payload) => {
+            return error_response(
+                500,
+                format!(
+                    "parsing day {} input panicked: {}",
+                    day.number,
+                    batch::panic_message(&payload)
+                ),
+            )
+        }
+    };
+    let parse_elapsed = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| {
+        day.solve(part, parsed_input.as_ref(), &parameters)
+    })) {
+        Ok(answer) => answer,
+        Err(payload) => {
+            return error_response(
+                500,
+                format!(
+                    "day {} {} panicked: {}",
+                    day.number,
+                    part.name(),
+                    batch::panic_message(&payload)
+                ),
+            )
+        }
+    };
+    let elapsed = start.elapsed();
+
+    (
+        200,
+        json!({
+            "day": day.number,
+            "part": part.number(),
+            "answer": report::answer_to_json(&answer),
+            "status": report::answer_status(&answer),
+            "input_hash": report::input_hash(body),
+            "parse_elapsed_ms": report::milliseconds(parse_elapsed),
+            "elapsed_ms": report::milliseconds(elapsed),
+        }),
+    )
+}
+
+fn error_response(status: u16, error: String) -> (u16, Value) {
+    (status, json!({ "error": error }))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::serve;
+
+    use std::io::Read;
+    use std::io::Write;
+    use std::net::TcpStream;
+    use std::thread;
+
+    use serde_json::Value;
+    use tiny_http::Method;
+
+    const DAY_9_EXAMPLE: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n";
+
+    #[test]
+    fn solves_a_part() {
+        let (status, json) = serve::respond(&Method::Post, "/days/9/parts/1", DAY_9_EXAMPLE);
+
+        assert_eq!(status, 200);
+        assert_eq!(json["day"], 9);
+        assert_eq!(json["part"], 1);
+        assert_eq!(json["answer"], "114");
+        assert_eq!(json["status"], "ok");
+        assert!(json["elapsed_ms"].is_f64());
+    }
+
+    #[test]
+    fn tells_missing_answers_from_overflows() {
+        let unwinnable = serve::respond(&Method::Post, "/days/6/parts/1", "Time: 3\nDistance: 2");
+        let no_route = serve::respond(&Method::Post, "/days/8/parts/1", "L\n\nBBB = (BBB, BBB)");
+        let alternating: Vec<&str> = (0..80)
+            .map(|i| {
+                if i % 2 == 0 {
+                    "1000000000000000000"
+                } else {
+                    "-1000000000000000000"
+                }
+            })
+            .collect();
+        let overflowing = serve::respond(&Method::Post, "/days/9/parts/1", &alternating.join(" "));
+
+        assert_eq!(unwinnable.1["answer"], "0");
+        assert_eq!(unwinnable.1["status"], "ok");
+        assert_eq!(no_route.1["answer"], Value::Null);
+        assert_eq!(no_route.1["status"], "unavailable");
+        assert_eq!(overflowing.1["answer"], Value::Null);
+        assert_eq!(overflowing.1["status"], "overflow");
+    }
+
+    #[test]
+    fn lists_days() {
+        let (status, json) = serve::respond(&Method::Get, "/days", "");
+
+        assert_eq!(status, 200);
+        assert_eq!(json["days"].as_array().unwrap().len(), 20);
+        assert_eq!(
+            json["days"][10]["parameters"][0]["name"],
+            "part_one_expansion"
+        );
+    }
+
+    #[test]
+    fn changes_parameters_from_the_query_string() {
+        let galaxies = "#..\n...\n..#\n";
+
+        let (_, default) = serve::respond(&Method::Post, "/days/11/parts/1", galaxies);
+        let (_, expanded) = serve::respond(
+            &Method::Post,
+            "/days/11/parts/1?part_one_expansion=1",
+            galaxies,
+        );
+
+        assert_eq!(default["answer"], "6");
+        assert_eq!(expanded["answer"], "4");
+    }
+
+    #[test]
+    fn reports_bad_requests() {
+        let not_found = serve::respond(&Method::Post, "/days/25/parts/1", "");
+        let bad_input = serve::respond(&Method::Post, "/days/9/parts/1", "1 x");
+        let bad_parameter = serve::respond(&Method::Post, "/days/9/parts/1?nope=1", "");
+        let wrong_method = serve::respond(&Method::Get, "/days/9/parts/1", "");
+
+        assert_eq!(not_found.0, 404);
+        assert_eq!(bad_input.0, 400);
+        assert!(bad_input.1["error"].as_str().unwrap().contains("line 1"));
+        assert_eq!(bad_parameter.0, 400);
+        assert_eq!(wrong_method.0, 405);
+    }
+
+    #[test]
+    fn reports_a_panicking_solver() {
+        let no_start = "...\n.F7\n.LJ\n";
+
+        let (status, json) = serve::respond(&Method::Post, "/days/10/parts/1", no_start);
+
+        assert_eq!(status, 500);
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("day 10 part one panicked: "));
+    }
+
+    #[test]
+    fn answers_over_http() {
+        let server = serve::bind(0).unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || serve::handle_requests(&server));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /days/9/parts/2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+            DAY_9_EXAMPLE.len(),
+            DAY_9_EXAMPLE
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let json: Value = serde_json::from_str(body).unwrap();
+
+        assert!(head.starts_with("HTTP/1.1 200"));
+        assert!(head.contains("Content-Type: application/json"));
+        assert_eq!(json["answer"], "2");
+    }
+
+    #[test]
+    fn keeps_answering_after_a_panic() {
+        let server = serve::bind(0).unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || serve::handle_requests(&server));
+
+        let post = |path: &str, body: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            write!(
+                stream,
+                "POST {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+                path,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let panicked = post("/days/10/parts/1", "...\n.F7\n.LJ\n");
+        let answered = post("/days/9/parts/1", DAY_9_EXAMPLE);
+
+        assert!(panicked.starts_with("HTTP/1.1 500"));
+        assert!(answered.starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn refuses_a_body_that_is_too_long() {
+        let server = serve::bind(0).unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || serve::handle_requests(&server));
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /days/9/parts/1 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n",
+            serve::MAX_BODY_LENGTH + 1
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 413"));
+    }
+}
@@ -0,0 +1,46 @@
+use std::fs::File;
+use std::io;
+use std::io::IsTerminal;
+use std::path::Path;
+
+use tracing::Level;
+use tracing_flame::FlameLayer;
+use tracing_subscriber::filter;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::Layer;
+
+/// Logs to stderr at the levels picked by `RUST_LOG`, such as
+/// `RUST_LOG=five=debug`, along with how long each span took once it closes.
+///
+/// With `folded_stacks`, the time spent in every span from debug upwards is
+/// also written there, one line per stack, for a flamegraph tool like
+/// `inferno-flamegraph` to draw.
+pub(crate) fn init(folded_stacks: Option<&Path>) -> io::Result<()> {
+    let log = tracing_subscriber::fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_span_events(FmtSpan::CLOSE)
+        .with_filter(EnvFilter::from_default_env());
+
+    let flame = folded_stacks
+        .map(|path| -> io::Result<_> {
+            // Written unbuffered, as the runner can exit part way through a
+            // run without dropping anything that would flush a buffer.
+            let file = File::create(path)?;
+
+            Ok(FlameLayer::new(file)
+                .with_threads_collapsed(true)
+                .with_file_and_line(false)
+                .with_filter(filter::filter_fn(|metadata| {
+                    metadata.is_span() && *metadata.level() <= Level::DEBUG
+                })))
+        })
+        .transpose()?;
+
+    tracing_subscriber::registry().with(log).with(flame).init();
+
+    Ok(())
+}
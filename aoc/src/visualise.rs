@@ -0,0 +1,283 @@
+use common::frame::Cell;
+use common::frame::Colour;
+use common::frame::Frame;
+
+use std::io;
+use std::io::IsTerminal;
+use std::io::Write;
+use std::time::Duration;
+use std::time::Instant;
+
+use crossterm::cursor;
+use crossterm::event;
+use crossterm::event::Event;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
+use crossterm::execute;
+use crossterm::queue;
+use crossterm::style;
+use crossterm::terminal;
+use crossterm::terminal::ClearType;
+
+/// Terminals cannot keep up with redrawing a large frame much faster than
+/// this, so higher speeds skip frames instead.
+const MAX_DRAWS_PER_SECOND: f64 = 60.0;
+
+const HELP: &str = "space pause · → step · + faster · - slower · q quit";
+
+pub(crate) struct PlayOptions {
+    /// Frames per second.
+    pub(crate) speed: f64,
+    pub(crate) colour: bool,
+}
+
+/// Plays frames in the terminal until the viewer quits. When stdout is not a
+/// terminal every frame is printed in turn instead, without colour.
+pub(crate) fn play(frames: impl Iterator<Item = Frame>, options: PlayOptions) -> io::Result<()> {
+    let mut stdout = io::stdout();
+
+    if !stdout.is_terminal() {
+        return print_frames(&mut stdout, frames);
+    }
+
+    let _screen = Screen::enter(&mut stdout)?;
+    Player::new(frames, options).run(&mut stdout)
+}
+
+fn print_frames(out: &mut impl Write, frames: impl Iterator<Item = Frame>) -> io::Result<()> {
+    for frame in frames {
+        writeln!(out, "{}", frame.caption())?;
+        writeln!(out, "{}", frame)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// Switches to the alternate screen in raw mode for as long as it is alive,
+/// so the terminal is restored however playing ends.
+struct Screen;
+
+impl Screen {
+    fn enter(out: &mut impl Write) -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+enum Action {
+    Quit,
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+}
+
+struct Player<I> {
+    frames: I,
+    frame: Frame,
+    frame_number: usize,
+    finished: bool,
+    paused: bool,
+    options: PlayOptions,
+}
+
+impl<I: Iterator<Item = Frame>> Player<I> {
+    fn new(mut frames: I, options: PlayOptions) -> Player<I> {
+        let frame = frames.next();
+
+        Player {
+            finished: frame.is_none(),
+            frame: frame.unwrap_or_default(),
+            frames,
+            frame_number: 1,
+            paused: false,
+            options,
+        }
+    }
+
+    fn run(mut self, out: &mut impl Write) -> io::Result<()> {
+        loop {
+            let drawn_at = Instant::now();
+            self.draw(out)?;
+
+            let action = if self.paused || self.finished {
+                wait_for_action(None)?
+            } else {
+                let draw_interval =
+                    Duration::from_secs_f64(1.0 / self.options.speed.min(MAX_DRAWS_PER_SECOND));
+                wait_for_action(Some(draw_interval.saturating_sub(drawn_at.elapsed())))?
+            };
+
+            match action {
+                Some(Action::Quit) => return Ok(()),
+                Some(Action::TogglePause) => self.paused = !self.paused,
+                Some(Action::Step) => {
+                    self.paused = true;
+                    self.advance(1);
+                }
+                Some(Action::Faster) => self.options.speed *= 2.0,
+                Some(Action::Slower) => self.options.speed /= 2.0,
+                None => self.advance(self.frames_per_draw()),
+            }
+        }
+    }
+
+    fn frames_per_draw(&self) -> usize {
+        (self.options.speed / MAX_DRAWS_PER_SECOND).round().max(1.0) as usize
+    }
+
+    fn advance(&mut self, number_of_frames: usize) {
+        for _ in 0..number_of_frames {
+            match self.frames.next() {
+                Some(frame) => {
+                    self.frame = frame;
+                    self.frame_number += 1;
+                }
+                None => {
+                    self.finished = true;
+                    return;
+                }
+            }
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (columns, rows) = terminal::size()?;
+        let frame_rows = rows.saturating_sub(2);
+
+        for (y, row) in self
+            .frame
+            .rows()
+            .iter()
+            .take(frame_rows as usize)
+            .enumerate()
+        {
+            queue!(out, cursor::MoveTo(0, y as u16))?;
+            draw_row(
+                out,
+                &row[..row.len().min(columns as usize)],
+                self.options.colour,
+            )?;
+            queue!(out, terminal::Clear(ClearType::UntilNewLine))?;
+        }
+        queue!(out, terminal::Clear(ClearType::FromCursorDown))?;
+
+        let mut status = format!(
+            "frame {} · {} frames/s",
+            self.frame_number, self.options.speed
+        );
+        if self.finished {
+            status.push_str(" · finished");
+        } else if self.paused {
+            status.push_str(" · paused");
+        }
+        if !self.frame.caption().is_empty() {
+            status = format!("{} · {}", self.frame.caption(), status);
+        }
+
+        queue!(
+            out,
+            cursor::MoveTo(0, frame_rows),
+            style::Print(truncate(&status, columns)),
+            cursor::MoveTo(0, frame_rows + 1),
+            style::Print(truncate(HELP, columns)),
+        )?;
+
+        out.flush()
+    }
+}
+
+/// Draws a row with one colour change per run of same-coloured cells.
+fn draw_row(out: &mut impl Write, row: &[Cell], colour: bool) -> io::Result<()> {
+    let mut start = 0;
+
+    while start < row.len() {
+        let run_colour = row[start].colour.filter(|_| colour);
+        let length = row[start..]
+            .iter()
+            .take_while(|cell| cell.colour.filter(|_| colour) == run_colour)
+            .count();
+        let symbols: String = row[start..start + length]
+            .iter()
+            .map(|cell| cell.symbol)
+            .collect();
+
+        match run_colour {
+            Some(run_colour) => queue!(
+                out,
+                style::SetForegroundColor(terminal_colour(run_colour)),
+                style::Print(symbols),
+                style::ResetColor
+            )?,
+            None => queue!(out, style::Print(symbols))?,
+        }
+
+        start += length;
+    }
+
+    Ok(())
+}
+
+fn terminal_colour(colour: Colour) -> style::Color {
+    match colour {
+        Colour::Red => style::Color::Red,
+        Colour::Green => style::Color::Green,
+        Colour::Yellow => style::Color::Yellow,
+        Colour::Blue => style::Color::Blue,
+        Colour::Magenta => style::Color::Magenta,
+        Colour::Cyan => style::Color::Cyan,
+        Colour::Grey => style::Color::DarkGrey,
+        Colour::Rgb(r, g, b) => style::Color::Rgb { r, g, b },
+    }
+}
+
+fn truncate(line: &str, columns: u16) -> String {
+    line.chars().take(columns as usize).collect()
+}
+
+/// Waits up to `timeout`, or forever if there is none, for a key press.
+fn wait_for_action(timeout: Option<Duration>) -> io::Result<Option<Action>> {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+        if let Some(deadline) = deadline {
+            if !event::poll(deadline.saturating_duration_since(Instant::now()))? {
+                return Ok(None);
+            }
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if let Some(action) = key_action(key) {
+                return Ok(Some(action));
+            }
+        }
+    }
+}
+
+fn key_action(key: KeyEvent) -> Option<Action> {
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Action::Quit),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+        KeyCode::Char(' ') | KeyCode::Char('p') => Some(Action::TogglePause),
+        KeyCode::Right | KeyCode::Char('.') | KeyCode::Char('s') => Some(Action::Step),
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Action::Faster),
+        KeyCode::Char('-') | KeyCode::Down => Some(Action::Slower),
+        _ => None,
+    }
+}
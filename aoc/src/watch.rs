@@ -0,0 +1,196 @@
+use days::workspace_root;
+
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::ExitStatus;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::Event;
+use notify::EventKind;
+use notify::RecursiveMode;
+use notify::Watcher;
+
+/// Editors tend to save a file as several writes in quick succession, so
+/// changes are gathered up for this long before re-running.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+pub(crate) struct WatchOptions {
+    pub(crate) release: bool,
+}
+
+/// Re-runs the day and checks its expected answers whenever its source,
+/// inputs or answers files change, or the source of a shared crate it
+/// depends on, until interrupted.
+pub(crate) fn watch(day_directory: &Path, day: u8, options: WatchOptions) -> notify::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+
+    watcher.watch(&day_directory.join("src"), RecursiveMode::Recursive)?;
+    watcher.watch(day_directory, RecursiveMode::NonRecursive)?;
+
+    for crate_directory in local_dependencies(day_directory) {
+        watcher.watch(&crate_directory.join("src"), RecursiveMode::Recursive)?;
+        watcher.watch(&crate_directory, RecursiveMode::NonRecursive)?;
+    }
+
+    run(day_directory, day, &options, &BTreeSet::new());
+
+    while let Ok(event) = receiver.recv() {
+        let mut changed = BTreeSet::new();
+        collect_changes(event, &mut changed);
+
+        while let Ok(event) = receiver.recv_timeout(SETTLE_TIME) {
+            collect_changes(event, &mut changed);
+        }
+
+        if !changed.is_empty() {
+            run(day_directory, day, &options, &changed);
+        }
+    }
+
+    Ok(())
+}
+
+fn collect_changes(event: notify::Result<Event>, changed: &mut BTreeSet<PathBuf>) {
+    let Ok(event) = event else {
+        return;
+    };
+
+    if matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        changed.extend(event.paths.into_iter().filter(|path| is_watched(path)));
+    }
+}
+
+/// The directories of the crates in the workspace that the crate in
+/// `crate_directory` depends on, directly or through each other.
+fn local_dependencies(crate_directory: &Path) -> BTreeSet<PathBuf> {
+    let mut found = BTreeSet::new();
+    let mut unvisited = vec![crate_directory.to_path_buf()];
+
+    while let Some(directory) = unvisited.pop() {
+        let Some(manifest) = fs::read_to_string(directory.join("Cargo.toml"))
+            .ok()
+            .and_then(|manifest| manifest.parse::<toml::Table>().ok())
+        else {
+            continue;
+        };
+
+        let paths = ["dependencies", "dev-dependencies"]
+            .into_iter()
+            .filter_map(|section| manifest.get(section)?.as_table())
+            .flat_map(|dependencies| dependencies.values())
+            .filter_map(|dependency| dependency.get("path")?.as_str());
+
+        for path in paths {
+            // Canonical, so each crate is only visited once however it is
+            // reached.
+            if let Ok(dependency) = directory.join(path).canonicalize() {
+                if found.insert(dependency.clone()) {
+                    unvisited.push(dependency);
+                }
+            }
+        }
+    }
+
+    found
+}
+
+/// Source files, inputs and answers files. Everything else in the day's
+/// directory, like build output or editor swap files, is ignored.
+fn is_watched(path: &Path) -> bool {
+    let extension = path.extension().and_then(OsStr::to_str);
+    let file_name = path.file_name().and_then(OsStr::to_str).unwrap_or("");
+
+    match extension {
+        Some("rs") => true,
+        Some("txt") => file_name.starts_with("test") || file_name == "input.txt",
+        Some("toml") => file_name.starts_with("answers") || file_name == "Cargo.toml",
+        _ => false,
+    }
+}
+
+fn run(day_directory: &Path, day: u8, options: &WatchOptions, changed: &BTreeSet<PathBuf>) {
+    // Clears the screen so only the latest run is shown.
+    print!("\x1b[2J\x1b[H");
+
+    if changed.is_empty() {
+        println!("watching day {}, press ctrl-c to stop", day);
+    } else {
+        let changed: Vec<String> = changed
+            .iter()
+            .map(|path| {
+                path.strip_prefix(day_directory)
+                    .or_else(|_| path.strip_prefix(workspace_root()))
+                    .unwrap_or(path)
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        println!("day {} changed: {}", day, changed.join(", "));
+    }
+
+    println!("\n== answers");
+    // Half-finished code gives wrong answers, which would be recorded in the
+    // history as changes.
+    let day_argument = day.to_string();
+    let workspace_manifest_path = workspace_root().join("Cargo.toml");
+    let answers = cargo(
+        options,
+        "run",
+        &[
+            "--quiet",
+            "--manifest-path",
+            &workspace_manifest_path.display().to_string(),
+            "--package",
+            "aoc",
+            "--",
+            "run",
+            &day_argument,
+            "--no-history",
+        ],
+    );
+    report("answers", answers);
+
+    println!("\n== expected answers");
+    let manifest_path = day_directory.join("Cargo.toml");
+    let check = cargo(
+        options,
+        "test",
+        &[
+            "--quiet",
+            "--manifest-path",
+            &manifest_path.display().to_string(),
+            "--test",
+            "answers",
+        ],
+    );
+    report("expected answers", check);
+}
+
+fn cargo(options: &WatchOptions, subcommand: &str, args: &[&str]) -> io::Result<ExitStatus> {
+    let mut command = Command::new("cargo");
+    command.arg(subcommand);
+
+    if options.release {
+        command.arg("--release");
+    }
+
+    command.args(args).status()
+}
+
+fn report(what: &str, status: io::Result<ExitStatus>) {
+    match status {
+        Ok(status) if status.success() => println!("{}: ok", what),
+        Ok(status) => println!("{}: failed ({})", what, status),
+        Err(error) => println!("{}: unable to run cargo: {}", what, error),
+    }
+}
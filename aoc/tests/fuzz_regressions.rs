@@ -0,0 +1,28 @@
+//! Replays every input that once made a parser panic under `cargo fuzz`, so
+//! the fixes are checked on stable without libFuzzer.
+
+use days::DAYS;
+
+use std::fs;
+
+#[test]
+fn parsers_do_not_panic_on_fuzz_regressions() {
+    for day in &DAYS {
+        let directory = days::workspace_root()
+            .join("fuzz/regressions")
+            .join(format!("day_{:02}", day.number));
+
+        let Ok(entries) = fs::read_dir(&directory) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let input = fs::read_to_string(&path).unwrap();
+
+            // Most of these are malformed, but the guarantee is only that the
+            // parser returns rather than panics.
+            let _ = day.parse(&input);
+        }
+    }
+}
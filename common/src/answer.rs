@@ -0,0 +1,67 @@
+use crate::checked::Overflow;
+
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    /// The input has nothing to solve for this part, e.g. a part two example
+    /// given to part one.
+    Unavailable,
+    /// The answer is too large for the type it was worked out in.
+    Overflow,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => value.fmt(f),
+            Answer::Signed(value) => value.fmt(f),
+            Answer::Text(value) => value.fmt(f),
+            Answer::Unavailable => f.write_str("-"),
+            Answer::Overflow => f.write_str("overflow"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $wide:ty, $($integer:ty),+) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::$variant(value as $wide)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+impl_from_integer!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unavailable, Into::into)
+    }
+}
+
+impl<T: Into<Answer>> From<Result<T, Overflow>> for Answer {
+    fn from(value: Result<T, Overflow>) -> Self {
+        value.map_or(Answer::Overflow, Into::into)
+    }
+}
@@ -0,0 +1,236 @@
+use crate::diagnostic::Diagnostic;
+use crate::input;
+use crate::parameters::Parameters;
+use crate::Answer;
+use crate::Solution;
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The committed answers for a day's example inputs.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Answers kept out of version control, usually for the real `input.txt`.
+pub const LOCAL_ANSWERS_FILE: &str = "answers.local.toml";
+
+/// What each part should produce for one input file. A part that is left out
+/// of the answers file is not checked.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Expected answers keyed by the name of the input file they are for.
+pub type AnswerBook = BTreeMap<String, ExpectedAnswers>;
+
+#[derive(Debug)]
+pub enum LoadAnswersError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    InvalidEntry(PathBuf, String),
+}
+
+impl Display for LoadAnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadAnswersError::Read(path, _) => {
+                write!(f, "unable to read answers from {}", path.display())
+            }
+            LoadAnswersError::Parse(path, _) => {
+                write!(f, "unable to parse answers in {}", path.display())
+            }
+            LoadAnswersError::InvalidEntry(path, input) => write!(
+                f,
+                "answers for {:?} in {} should be a table of part_one and part_two",
+                input,
+                path.display()
+            ),
+        }
+    }
+}
+
+impl Error for LoadAnswersError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LoadAnswersError::Read(_, error) => Some(error),
+            LoadAnswersError::Parse(_, error) => Some(error),
+            LoadAnswersError::InvalidEntry(_, _) => None,
+        }
+    }
+}
+
+/// Reads the answers for a day from its directory, merging in the local
+/// answers file if there is one. Missing files are treated as empty.
+pub fn load(day_directory: &Path) -> Result<AnswerBook, LoadAnswersError> {
+    let mut answers = AnswerBook::new();
+
+    for file_name in [ANSWERS_FILE, LOCAL_ANSWERS_FILE] {
+        let path = day_directory.join(file_name);
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(LoadAnswersError::Read(path, error)),
+        };
+
+        answers.extend(parse_answers(&contents).map_err(|error| match error {
+            ParseAnswersError::Toml(error) => LoadAnswersError::Parse(path.clone(), error),
+            ParseAnswersError::InvalidEntry(input) => {
+                LoadAnswersError::InvalidEntry(path.clone(), input)
+            }
+        })?);
+    }
+
+    Ok(answers)
+}
+
+enum ParseAnswersError {
+    Toml(toml::de::Error),
+    InvalidEntry(String),
+}
+
+fn parse_answers(contents: &str) -> Result<AnswerBook, ParseAnswersError> {
+    let table: toml::Table = contents.parse().map_err(ParseAnswersError::Toml)?;
+
+    table
+        .into_iter()
+        .map(|(input, entry)| {
+            let toml::Value::Table(entry) = entry else {
+                return Err(ParseAnswersError::InvalidEntry(input));
+            };
+
+            let part = |key: &str| match entry.get(key) {
+                None => Ok(None),
+                Some(toml::Value::Integer(value)) => Ok(Some(value.to_string())),
+                Some(toml::Value::String(value)) => Ok(Some(value.clone())),
+                Some(_) => Err(ParseAnswersError::InvalidEntry(input.clone())),
+            };
+
+            let expected = ExpectedAnswers {
+                part_one: part("part_one")?,
+                part_two: part("part_two")?,
+            };
+
+            Ok((input, expected))
+        })
+        .collect()
+}
+
+type PartSolver<S> = fn(&<S as Solution>::Input, &Parameters) -> Answer;
+
+/// Solves every input listed in a day's answers files and panics with a
+/// report of each answer that differs. Every `test*.txt` example in the
+/// directory must have an entry, so new examples are not forgotten.
+///
+/// The answers are for the puzzle as written, so every parameter is left at
+/// its default.
+///
+/// Meant to be called from each day's integration tests with
+/// `env!("CARGO_MANIFEST_DIR")`.
+pub fn check<S: Solution>(day_directory: &str) {
+    let day_directory = Path::new(day_directory);
+    let answers = load(day_directory).unwrap_or_else(|error| panic!("{}", error));
+    let parameters = Parameters::new(S::PARAMETERS);
+
+    let mut failures = Vec::new();
+
+    for example in example_files(day_directory) {
+        if !answers.contains_key(&example) {
+            failures.push(format!(
+                "{}: no expected answers in {}",
+                example, ANSWERS_FILE
+            ));
+        }
+    }
+
+    for (file_name, expected) in &answers {
+        let path = day_directory.join(file_name);
+        let input = match input::read_input(&path) {
+            Ok(input) => input::normalise(&input),
+            Err(error) => {
+                failures.push(format!("{}: {}", file_name, error));
+                continue;
+            }
+        };
+
+        let parsed_input = match S::parse(&input) {
+            Ok(parsed_input) => parsed_input,
+            Err(error) => {
+                failures.push(format!(
+                    "{}: {}",
+                    file_name,
+                    Diagnostic::new(&input, &error)
+                ));
+                continue;
+            }
+        };
+
+        let parts: [(&str, &Option<String>, PartSolver<S>); 2] = [
+            ("part one", &expected.part_one, S::part_one),
+            ("part two", &expected.part_two, S::part_two),
+        ];
+
+        for (part, expected, solve) in parts {
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            let answer = solve(&parsed_input, &parameters).to_string();
+            if &answer != expected {
+                failures.push(format!(
+                    "{} {}: expected {}, got {}",
+                    file_name, part, expected, answer
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} answer(s) did not match:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+fn example_files(day_directory: &Path) -> Vec<String> {
+    let entries = fs::read_dir(day_directory)
+        .unwrap_or_else(|error| panic!("unable to read {}: {}", day_directory.display(), error));
+
+    let mut examples: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|file_name| file_name.starts_with("test") && file_name.ends_with(".txt"))
+        .collect();
+    examples.sort();
+
+    examples
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answers::{parse_answers, ExpectedAnswers};
+
+    #[test]
+    fn parse_answers_file() {
+        let answers = parse_answers(
+            "[\"test.txt\"]\npart_one = 142\npart_two = \"-\"\n\n[\"test2.txt\"]\npart_two = 281\n",
+        )
+        .ok()
+        .unwrap();
+
+        assert_eq!(
+            answers["test.txt"],
+            ExpectedAnswers {
+                part_one: Some("142".to_string()),
+                part_two: Some("-".to_string()),
+            }
+        );
+        assert_eq!(answers["test2.txt"].part_one, None);
+    }
+}
@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt::Display;
+
+/// An answer, or a step towards one, didn't fit in the type it was worked
+/// out in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the answer is too large to work out")
+    }
+}
+
+impl Error for Overflow {}
+
+/// The integer operations that can overflow, returning [`Overflow`] instead
+/// of wrapping in release builds or panicking in debug ones.
+pub trait Checked: Sized + Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn try_add(self, other: Self) -> Result<Self, Overflow>;
+
+    fn try_sub(self, other: Self) -> Result<Self, Overflow>;
+
+    fn try_mul(self, other: Self) -> Result<Self, Overflow>;
+}
+
+macro_rules! impl_checked {
+    ($($integer:ty),+) => {
+        $(
+            impl Checked for $integer {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn try_add(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_add(other).ok_or(Overflow)
+                }
+
+                fn try_sub(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_sub(other).ok_or(Overflow)
+                }
+
+                fn try_mul(self, other: Self) -> Result<Self, Overflow> {
+                    self.checked_mul(other).ok_or(Overflow)
+                }
+            }
+        )+
+    };
+}
+
+impl_checked!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Sums and products of an iterator that stop at the first overflow.
+pub trait CheckedIterator<T: Checked>: Iterator<Item = T> + Sized {
+    fn checked_sum(mut self) -> Result<T, Overflow> {
+        self.try_fold(T::ZERO, T::try_add)
+    }
+
+    fn checked_product(mut self) -> Result<T, Overflow> {
+        self.try_fold(T::ONE, T::try_mul)
+    }
+}
+
+impl<T: Checked, I: Iterator<Item = T>> CheckedIterator<T> for I {}
+
+#[cfg(test)]
+mod test {
+    use crate::checked::{Checked, CheckedIterator, Overflow};
+
+    #[test]
+    fn stops_at_the_first_overflow() {
+        assert_eq!([200u8, 55].into_iter().checked_sum(), Ok(255));
+        assert_eq!([200u8, 56].into_iter().checked_sum(), Err(Overflow));
+        assert_eq!([16u8, 16].into_iter().checked_product(), Err(Overflow));
+        assert_eq!(Vec::<u8>::new().into_iter().checked_product(), Ok(1));
+        assert_eq!(i64::MIN.try_add(-1), Err(Overflow));
+        assert_eq!(0u32.try_sub(1), Err(Overflow));
+    }
+}
@@ -0,0 +1,175 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::Display;
+
+/// The piece of the puzzle input a parse error is about.
+///
+/// Parsers work on slices of the input, so a span remembers where its
+/// snippet starts in memory and [`Span::locate`] turns that back into a line
+/// and column of the whole input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    address: usize,
+    snippet: String,
+}
+
+impl Span {
+    /// Points at `snippet`, which should be sliced from the puzzle input.
+    pub fn of(snippet: &str) -> Span {
+        Span {
+            address: snippet.as_ptr() as usize,
+            snippet: snippet.to_string(),
+        }
+    }
+
+    /// Points at the empty string just after `s`, for input that ends too
+    /// early.
+    pub fn end_of(s: &str) -> Span {
+        Span::of(&s[s.len()..])
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    /// Finds the span in `input`, or `None` if it was not sliced from it.
+    pub fn locate<'a>(&self, input: &'a str) -> Option<Location<'a>> {
+        let start = self.address.checked_sub(input.as_ptr() as usize)?;
+        if start + self.snippet.len() > input.len() || !input.is_char_boundary(start) {
+            return None;
+        }
+
+        let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+
+        Some(Location {
+            line: input[..start].matches('\n').count() + 1,
+            column: input[line_start..start].chars().count() + 1,
+            line_text: &input[line_start..line_end],
+        })
+    }
+}
+
+/// Where a [`Span`] is in the puzzle input, counting lines and columns from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub line_text: &'a str,
+}
+
+/// An error from parsing a puzzle input.
+pub trait ParseError: Error {
+    /// The part of the input that caused the error, if it is known.
+    fn span(&self) -> Option<&Span>;
+}
+
+impl ParseError for Infallible {
+    fn span(&self) -> Option<&Span> {
+        match *self {}
+    }
+}
+
+/// Renders a parse error like a compiler diagnostic, with a caret under the
+/// offending part of the input and the chain of errors that caused it.
+pub struct Diagnostic<'a, E> {
+    input: &'a str,
+    error: &'a E,
+}
+
+impl<'a, E: ParseError> Diagnostic<'a, E> {
+    pub fn new(input: &'a str, error: &'a E) -> Diagnostic<'a, E> {
+        Diagnostic { input, error }
+    }
+}
+
+impl<E: ParseError> Display for Diagnostic<'_, E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)?;
+
+        if let Some(span) = self.error.span() {
+            match span.locate(self.input) {
+                Some(location) => {
+                    let gutter = " ".repeat(location.line.to_string().len());
+                    // At least one caret, even for the end of an input that
+                    // ends with a newline, where the line is empty.
+                    let remaining_width =
+                        (location.line_text.chars().count() + 1 - location.column).max(1);
+                    let caret_width = span.snippet().chars().count().clamp(1, remaining_width);
+
+                    write!(
+                        f,
+                        "\n{gutter}--> line {}, column {}",
+                        location.line, location.column
+                    )?;
+                    write!(f, "\n{gutter} |")?;
+                    write!(f, "\n{} | {}", location.line, location.line_text)?;
+                    write!(
+                        f,
+                        "\n{gutter} | {}{}",
+                        " ".repeat(location.column - 1),
+                        "^".repeat(caret_width)
+                    )?;
+                }
+                None => write!(f, "\n  --> {:?}", span.snippet())?,
+            }
+        }
+
+        let mut source = self.error.source();
+        while let Some(error) = source {
+            write!(f, "\ncaused by: {}", error)?;
+            source = error.source();
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::diagnostic::{Diagnostic, ParseError, Span};
+
+    use std::error::Error;
+    use std::fmt::Display;
+
+    #[derive(Debug)]
+    struct EndedEarly(Span);
+
+    impl Display for EndedEarly {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "ended early")
+        }
+    }
+
+    impl Error for EndedEarly {}
+
+    impl ParseError for EndedEarly {
+        fn span(&self) -> Option<&Span> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn locate_span() {
+        let input = "first line\nsecond line";
+        let span = Span::of(&input[18..22]);
+
+        let location = span.locate(input).unwrap();
+
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 8);
+        assert_eq!(location.line_text, "second line");
+        assert_eq!(Span::of("elsewhere").locate(input), None);
+    }
+
+    #[test]
+    fn points_past_a_trailing_newline() {
+        let input = "Game 1\n";
+        let error = EndedEarly(Span::end_of(input));
+
+        assert_eq!(
+            Diagnostic::new(input, &error).to_string(),
+            "ended early\n --> line 2, column 1\n  |\n2 | \n  | ^"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::Solution;
+
+use std::fmt::Display;
+
+/// A colour to draw a cell in. The named colours follow the terminal's
+/// palette, so they suit both light and dark themes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    /// The colour as red, green and blue, for drawing outside a terminal.
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Colour::Red => (205, 49, 49),
+            Colour::Green => (13, 188, 121),
+            Colour::Yellow => (229, 229, 16),
+            Colour::Blue => (36, 114, 200),
+            Colour::Magenta => (188, 63, 188),
+            Colour::Cyan => (17, 168, 205),
+            Colour::Grey => (102, 102, 102),
+            Colour::Rgb(red, green, blue) => (red, green, blue),
+        }
+    }
+}
+
+/// One character of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub fn new(symbol: char) -> Cell {
+        Cell {
+            symbol,
+            colour: None,
+        }
+    }
+
+    pub fn coloured(symbol: char, colour: Colour) -> Cell {
+        Cell {
+            symbol,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(symbol: char) -> Self {
+        Cell::new(symbol)
+    }
+}
+
+/// One picture of a simulation in progress, row by row, with an optional
+/// caption saying what is happening.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    rows: Vec<Vec<Cell>>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new(rows: Vec<Vec<Cell>>) -> Frame {
+        Frame {
+            rows,
+            caption: String::new(),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Frame {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.rows
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+}
+
+/// Displays the frame's symbols without colour.
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows.iter().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell.symbol)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A day whose solution can be watched as it runs.
+pub trait Simulation: Solution {
+    /// Every frame of the simulation in order. Frames are built lazily, so a
+    /// long simulation can start playing straight away.
+    fn frames(input: &Self::Input) -> Box<dyn Iterator<Item = Frame> + '_>;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::frame::{Cell, Colour, Frame};
+
+    #[test]
+    fn display_ignores_colour() {
+        let frame = Frame::new(vec![
+            vec![Cell::new('#'), Cell::coloured('O', Colour::Red)],
+            vec![Cell::new('.'), Cell::new('.')],
+        ])
+        .with_caption("tilting north");
+
+        assert_eq!(frame.to_string(), "#O\n..");
+        assert_eq!(frame.caption(), "tilting north");
+    }
+}
@@ -0,0 +1,129 @@
+use crate::frame::Colour;
+use crate::frame::Frame;
+use crate::parameters::Parameters;
+use crate::Solution;
+
+use std::fmt::Display;
+
+/// A picture of a day's model, for states too big to read as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    /// One square of pixels per cell, in the cell's colour. Symbols are left
+    /// out and cells without a colour are left black.
+    Raster(Frame),
+    Vector(Svg),
+}
+
+impl Image {
+    /// The file extension the image is saved with.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Raster(_) => "png",
+            Image::Vector(_) => "svg",
+        }
+    }
+}
+
+/// A day whose model can be saved as an image, with the same parameters it
+/// was solved with.
+pub trait Render: Solution {
+    fn render(input: &Self::Input, parameters: &Parameters) -> Image;
+
+    /// The image for part two, for days whose model differs between the
+    /// parts. The same as part one's unless overridden.
+    fn render_part_two(input: &Self::Input, parameters: &Parameters) -> Image {
+        Self::render(input, parameters)
+    }
+}
+
+/// A drawing made of coloured lines. The view box fits the lines however far
+/// apart they are, so coordinates in the millions scale down to fit the
+/// screen.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Svg {
+    lines: Vec<Line>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Line {
+    from: (i64, i64),
+    to: (i64, i64),
+    colour: Colour,
+}
+
+impl Svg {
+    pub fn new() -> Svg {
+        Svg::default()
+    }
+
+    pub fn line(&mut self, from: (i64, i64), to: (i64, i64), colour: Colour) {
+        self.lines.push(Line { from, to, colour });
+    }
+
+    fn view_box(&self) -> (i64, i64, i64, i64) {
+        let points = || self.lines.iter().flat_map(|line| [line.from, line.to]);
+
+        let min_x = points().map(|(x, _)| x).min().unwrap_or(0);
+        let max_x = points().map(|(x, _)| x).max().unwrap_or(0);
+        let min_y = points().map(|(_, y)| y).min().unwrap_or(0);
+        let max_y = points().map(|(_, y)| y).max().unwrap_or(0);
+
+        // A little room on every side stops the outermost lines being cut in
+        // half.
+        let margin = ((max_x - min_x).max(max_y - min_y) / 100).max(1);
+
+        (
+            min_x - margin,
+            min_y - margin,
+            max_x - min_x + 2 * margin,
+            max_y - min_y + 2 * margin,
+        )
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (x, y, width, height) = self.view_box();
+
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            x, y, width, height
+        )?;
+        writeln!(
+            f,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="black"/>"#,
+            x, y, width, height
+        )?;
+
+        for line in &self.lines {
+            let (red, green, blue) = line.colour.rgb();
+
+            writeln!(
+                f,
+                r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#{:02x}{:02x}{:02x}" stroke-width="2" stroke-linecap="square" vector-effect="non-scaling-stroke"/>"##,
+                line.from.0, line.from.1, line.to.0, line.to.1, red, green, blue
+            )?;
+        }
+
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::frame::Colour;
+    use crate::image::Svg;
+
+    #[test]
+    fn view_box_fits_every_line() {
+        let mut svg = Svg::new();
+        svg.line((0, 0), (0, 500), Colour::Red);
+        svg.line((0, 500), (-300, 500), Colour::Rgb(0x70, 0xc7, 0x10));
+
+        let svg = svg.to_string();
+
+        assert!(svg.contains(r#"viewBox="-305 -5 310 510""#));
+        assert!(svg.contains(r##"stroke="#70c710""##));
+    }
+}
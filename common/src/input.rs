@@ -0,0 +1,57 @@
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The path that reads the puzzle input from stdin instead of a file.
+pub const STDIN_PATH: &str = "-";
+
+#[derive(Debug)]
+pub struct ReadInputError {
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl Display for ReadInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.as_os_str() == STDIN_PATH {
+            write!(f, "unable to read input from stdin: {}", self.source)
+        } else {
+            write!(
+                f,
+                "unable to read input from {}: {}",
+                self.path.display(),
+                self.source
+            )
+        }
+    }
+}
+
+impl std::error::Error for ReadInputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Reads a puzzle input from `path`, or from stdin if the path is `-`.
+pub fn read_input(path: &Path) -> Result<String, ReadInputError> {
+    let result = if path.as_os_str() == STDIN_PATH {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    result.map_err(|source| ReadInputError {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Converts CRLF line endings to LF and drops trailing whitespace and blank
+/// lines, so every day sees the same text however the input was saved.
+pub fn normalise(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end().to_string()
+}
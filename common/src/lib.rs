@@ -0,0 +1,14 @@
+pub mod answers;
+pub mod checked;
+pub mod diagnostic;
+pub mod frame;
+pub mod image;
+pub mod input;
+pub mod model;
+pub mod parameters;
+
+mod answer;
+mod solution;
+
+pub use answer::Answer;
+pub use solution::Solution;
@@ -0,0 +1,125 @@
+use crate::Solution;
+
+/// A day's parsed input as plain data, for looking through it from outside
+/// Rust, e.g. as dicts and lists in Python.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Integer(i128),
+    Text(String),
+    List(Vec<Value>),
+    /// Named fields, in the order the model gives them.
+    Record(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn record<K: Into<String>>(fields: impl IntoIterator<Item = (K, Value)>) -> Value {
+        Value::Record(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+        )
+    }
+
+    pub fn list<T: Into<Value>>(items: impl IntoIterator<Item = T>) -> Value {
+        Value::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A day whose parsed input can be handed over as a [`Value`].
+pub trait Inspect: Solution {
+    fn inspect(input: &Self::Input) -> Value;
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),+) => {
+        $(
+            impl From<$integer> for Value {
+                fn from(value: $integer) -> Self {
+                    Value::Integer(value as i128)
+                }
+            }
+
+            impl From<&$integer> for Value {
+                fn from(value: &$integer) -> Self {
+                    Value::Integer(*value as i128)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Text(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(value: Vec<T>) -> Self {
+        Value::list(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+impl<A: Into<Value>, B: Into<Value>> From<(A, B)> for Value {
+    fn from((a, b): (A, B)) -> Self {
+        Value::List(vec![a.into(), b.into()])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::Value;
+
+    #[test]
+    fn converts_nested_data() {
+        let value = Value::record([
+            ("name", "in".into()),
+            ("ratings", Value::list([(1u8, 2u64), (3, 4)])),
+            ("parent", Option::<&str>::None.into()),
+        ]);
+
+        assert_eq!(
+            value,
+            Value::Record(vec![
+                ("name".to_string(), Value::Text("in".to_string())),
+                (
+                    "ratings".to_string(),
+                    Value::List(vec![
+                        Value::List(vec![Value::Integer(1), Value::Integer(2)]),
+                        Value::List(vec![Value::Integer(3), Value::Integer(4)]),
+                    ])
+                ),
+                ("parent".to_string(), Value::Null),
+            ])
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+
+/// A constant from a puzzle that can be changed to try out a variant of it,
+/// such as how many times the platform is spun on day 14.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: u64,
+    /// The values the day can cope with. Anything else is refused when it is
+    /// set, so the day can convert the value without checking it again.
+    pub range: RangeInclusive<u64>,
+}
+
+/// The values chosen for a day's parameters. Any that have not been set take
+/// their default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameters {
+    declared: &'static [Parameter],
+    values: HashMap<&'static str, u64>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SetParameterError {
+    UnknownParameter(String, Vec<&'static str>),
+    InvalidValue(String, ParseIntError),
+    OutOfRange(&'static Parameter, u64),
+}
+
+impl Display for SetParameterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SetParameterError::UnknownParameter(name, known) if known.is_empty() => {
+                write!(f, "unknown parameter {:?}, this day has none", name)
+            }
+            SetParameterError::UnknownParameter(name, known) => write!(
+                f,
+                "unknown parameter {:?}, expected one of {}",
+                name,
+                known.join(", ")
+            ),
+            SetParameterError::InvalidValue(value, _) => {
+                write!(f, "invalid parameter value {:?}", value)
+            }
+            SetParameterError::OutOfRange(parameter, value) => write!(
+                f,
+                "{} should be between {} and {}, found {}",
+                parameter.name,
+                parameter.range.start(),
+                parameter.range.end(),
+                value
+            ),
+        }
+    }
+}
+
+impl Error for SetParameterError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SetParameterError::InvalidValue(_, error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Parameters {
+    /// Parameters for a day that declares `declared`, all at their defaults.
+    pub fn new(declared: &'static [Parameter]) -> Parameters {
+        Parameters {
+            declared,
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn set(&mut self, name: &str, value: u64) -> Result<(), SetParameterError> {
+        let parameter = self
+            .declared
+            .iter()
+            .find(|parameter| parameter.name == name)
+            .ok_or_else(|| {
+                SetParameterError::UnknownParameter(
+                    name.to_string(),
+                    self.declared
+                        .iter()
+                        .map(|parameter| parameter.name)
+                        .collect(),
+                )
+            })?;
+
+        if !parameter.range.contains(&value) {
+            return Err(SetParameterError::OutOfRange(parameter, value));
+        }
+
+        self.values.insert(parameter.name, value);
+
+        Ok(())
+    }
+
+    /// Sets a parameter from text, as given on the command line.
+    pub fn set_str(&mut self, name: &str, value: &str) -> Result<(), SetParameterError> {
+        let value = value
+            .parse()
+            .map_err(|error| SetParameterError::InvalidValue(value.to_string(), error))?;
+
+        self.set(name, value)
+    }
+
+    pub fn get(&self, parameter: &Parameter) -> u64 {
+        self.values
+            .get(parameter.name)
+            .copied()
+            .unwrap_or(parameter.default)
+    }
+
+    /// The parameters set to something other than their default, in the
+    /// order the day declares them.
+    pub fn changed(&self) -> impl Iterator<Item = (&'static Parameter, u64)> + '_ {
+        self.declared
+            .iter()
+            .map(|parameter| (parameter, self.get(parameter)))
+            .filter(|(parameter, value)| *value != parameter.default)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parameters::{Parameter, Parameters, SetParameterError};
+
+    const PUSHES: Parameter = Parameter {
+        name: "pushes",
+        description: "How many times the button is pushed.",
+        default: 1000,
+        range: 1..=1_000_000,
+    };
+
+    const DECLARED: &[Parameter] = &[PUSHES];
+
+    #[test]
+    fn unset_parameters_take_their_default() {
+        let parameters = Parameters::new(DECLARED);
+
+        assert_eq!(parameters.get(&PUSHES), 1000);
+    }
+
+    #[test]
+    fn set_checks_the_name_and_range() {
+        let mut parameters = Parameters::new(DECLARED);
+
+        parameters.set_str("pushes", "20").unwrap();
+        assert_eq!(parameters.get(&PUSHES), 20);
+
+        assert_eq!(
+            parameters.set("pulls", 1),
+            Err(SetParameterError::UnknownParameter(
+                "pulls".to_string(),
+                vec!["pushes"]
+            ))
+        );
+        assert_eq!(
+            parameters.set("pushes", 0),
+            Err(SetParameterError::OutOfRange(&DECLARED[0], 0))
+        );
+        assert_eq!(parameters.get(&PUSHES), 20);
+    }
+
+    #[test]
+    fn changed_skips_defaults() {
+        let mut parameters = Parameters::new(DECLARED);
+        parameters.set("pushes", 1000).unwrap();
+
+        assert_eq!(parameters.changed().count(), 0);
+
+        parameters.set("pushes", 10).unwrap();
+
+        assert_eq!(
+            parameters.changed().collect::<Vec<_>>(),
+            [(&DECLARED[0], 10)]
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::diagnostic::ParseError;
+use crate::parameters::Parameter;
+use crate::parameters::Parameters;
+use crate::Answer;
+
+/// A day's puzzle, split into parsing the input and solving each part.
+pub trait Solution {
+    type Input;
+    type Error: ParseError;
+
+    /// The constants from the puzzle that can be changed with `--set` or
+    /// `aoc.toml`. Each part reads them back from the [`Parameters`] it is
+    /// given.
+    const PARAMETERS: &'static [Parameter] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part_one(input: &Self::Input, parameters: &Parameters) -> Answer;
+
+    fn part_two(input: &Self::Input, parameters: &Parameters) -> Answer;
+}
@@ -0,0 +1,298 @@
+//! Every solved day behind one type-erased interface, so the runner and the
+//! Python bindings share a single list of days.
+
+use common::diagnostic::Diagnostic;
+use common::frame::Frame;
+use common::frame::Simulation;
+use common::image::Image;
+use common::image::Render;
+use common::input;
+use common::model::Inspect;
+use common::model::Value;
+use common::parameters::Parameter;
+use common::parameters::Parameters;
+use common::Answer;
+use common::Solution;
+
+use std::any::Any;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub type ParsedInput = Box<dyn Any>;
+type InputParser = fn(&str) -> Result<ParsedInput, String>;
+type PartSolver = fn(&dyn Any, &Parameters) -> Answer;
+type FrameSource = for<'a> fn(&'a dyn Any) -> Frames<'a>;
+type ImageSource = fn(&dyn Any, &Parameters, Part) -> Image;
+type ModelSource = fn(&dyn Any) -> Value;
+
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+pub struct Day {
+    pub number: u8,
+    pub parameters: &'static [Parameter],
+    parse: InputParser,
+    part_one: PartSolver,
+    part_two: PartSolver,
+    frames: Option<FrameSource>,
+    image: Option<ImageSource>,
+    model: Option<ModelSource>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: u8) -> Day
+    where
+        S::Input: 'static,
+    {
+        Day {
+            number,
+            parameters: S::PARAMETERS,
+            parse: parse::<S>,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            frames: None,
+            image: None,
+            model: None,
+        }
+    }
+
+    /// A day whose simulation can also be watched frame by frame.
+    const fn simulated<S: Simulation>(number: u8) -> Day
+    where
+        S::Input: 'static,
+    {
+        Day {
+            frames: Some(frames::<S>),
+            ..Day::new::<S>(number)
+        }
+    }
+
+    /// Lets the day's model also be saved as an image.
+    const fn rendered<S: Render>(self) -> Day
+    where
+        S::Input: 'static,
+    {
+        Day {
+            image: Some(render::<S>),
+            ..self
+        }
+    }
+
+    /// Lets the day's parsed input also be looked through as plain values.
+    const fn inspected<S: Inspect>(self) -> Day
+    where
+        S::Input: 'static,
+    {
+        Day {
+            model: Some(model::<S>),
+            ..self
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<ParsedInput, String> {
+        let _span = tracing::info_span!("parse", day = self.number, bytes = input.len()).entered();
+
+        (self.parse)(input)
+    }
+
+    pub fn solve(&self, part: Part, input: &dyn Any, parameters: &Parameters) -> Answer {
+        match part {
+            Part::One => {
+                let _span = tracing::info_span!("part_one", day = self.number).entered();
+                (self.part_one)(input, parameters)
+            }
+            Part::Two => {
+                let _span = tracing::info_span!("part_two", day = self.number).entered();
+                (self.part_two)(input, parameters)
+            }
+        }
+    }
+
+    pub fn default_parameters(&self) -> Parameters {
+        Parameters::new(self.parameters)
+    }
+
+    /// The frames of the day's simulation, or `None` if it has nothing to
+    /// watch.
+    pub fn frames<'a>(&self, input: &'a dyn Any) -> Option<Frames<'a>> {
+        self.frames.map(|frames| frames(input))
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        self.frames.is_some()
+    }
+
+    /// An image of the day's model for `part`, or `None` if it has nothing
+    /// to draw.
+    pub fn render(&self, input: &dyn Any, parameters: &Parameters, part: Part) -> Option<Image> {
+        self.image.map(|render| render(input, parameters, part))
+    }
+
+    pub fn is_rendered(&self) -> bool {
+        self.image.is_some()
+    }
+
+    /// The day's parsed input as plain values, or `None` if it can't be
+    /// looked through.
+    pub fn model(&self, input: &dyn Any) -> Option<Value> {
+        self.model.map(|model| model(input))
+    }
+
+    pub fn get_default_input_path(&self) -> PathBuf {
+        self.get_path("input.txt")
+    }
+
+    /// The day's small example input, which suits watching a simulation.
+    /// Days with several examples default to the first.
+    pub fn get_default_test_input_path(&self) -> PathBuf {
+        let path = self.get_path("test.txt");
+
+        if path.exists() {
+            path
+        } else {
+            self.get_path("test_1.txt")
+        }
+    }
+
+    /// The day's crate, which holds its inputs and answers files too.
+    pub fn get_directory(&self) -> PathBuf {
+        day_directory(self.number)
+    }
+
+    fn get_path(&self, file_name: &str) -> PathBuf {
+        self.get_directory().join(file_name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn name(self) -> &'static str {
+        match self {
+            Part::One => "part one",
+            Part::Two => "part two",
+        }
+    }
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+/// The directory with every day's crate in, along with the shared crates and
+/// the runner.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the days crate should be inside the workspace")
+}
+
+/// Where the crate for day `number` is, whether or not it exists yet.
+pub fn day_directory(number: u8) -> PathBuf {
+    workspace_root().join(number.to_string())
+}
+
+fn parse<S: Solution>(input: &str) -> Result<ParsedInput, String>
+where
+    S::Input: 'static,
+{
+    let input = input::normalise(input);
+
+    S::parse(&input)
+        .map(|input| Box::new(input) as ParsedInput)
+        .map_err(|error| Diagnostic::new(&input, &error).to_string())
+}
+
+fn part_one<S: Solution>(input: &dyn Any, parameters: &Parameters) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part_one(downcast_input::<S>(input), parameters)
+}
+
+fn part_two<S: Solution>(input: &dyn Any, parameters: &Parameters) -> Answer
+where
+    S::Input: 'static,
+{
+    S::part_two(downcast_input::<S>(input), parameters)
+}
+
+fn frames<S: Simulation>(input: &dyn Any) -> Frames<'_>
+where
+    S::Input: 'static,
+{
+    S::frames(downcast_input::<S>(input))
+}
+
+fn render<S: Render>(input: &dyn Any, parameters: &Parameters, part: Part) -> Image
+where
+    S::Input: 'static,
+{
+    let input = downcast_input::<S>(input);
+
+    match part {
+        Part::One => S::render(input, parameters),
+        Part::Two => S::render_part_two(input, parameters),
+    }
+}
+
+fn model<S: Inspect>(input: &dyn Any) -> Value
+where
+    S::Input: 'static,
+{
+    S::inspect(downcast_input::<S>(input))
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("Input should have been parsed by the same day")
+}
+
+pub const DAYS: [Day; 20] = [
+    Day::new::<one::Day>(1).inspected::<one::Day>(),
+    Day::new::<two::Day>(2).inspected::<two::Day>(),
+    Day::new::<three::Day>(3).inspected::<three::Day>(),
+    Day::new::<four::Day>(4).inspected::<four::Day>(),
+    Day::new::<five::Day>(5).inspected::<five::Day>(),
+    Day::new::<six::Day>(6).inspected::<six::Day>(),
+    Day::new::<seven::Day>(7).inspected::<seven::Day>(),
+    Day::new::<eight::Day>(8).inspected::<eight::Day>(),
+    Day::new::<nine::Day>(9).inspected::<nine::Day>(),
+    Day::simulated::<ten::Day>(10)
+        .rendered::<ten::Day>()
+        .inspected::<ten::Day>(),
+    Day::new::<eleven::Day>(11).inspected::<eleven::Day>(),
+    Day::new::<twelve::Day>(12).inspected::<twelve::Day>(),
+    Day::new::<thirteen::Day>(13).inspected::<thirteen::Day>(),
+    Day::simulated::<fourteen::Day>(14)
+        .rendered::<fourteen::Day>()
+        .inspected::<fourteen::Day>(),
+    Day::new::<fifteen::Day>(15).inspected::<fifteen::Day>(),
+    Day::simulated::<sixteen::Day>(16)
+        .rendered::<sixteen::Day>()
+        .inspected::<sixteen::Day>(),
+    Day::simulated::<seventeen::Day>(17)
+        .rendered::<seventeen::Day>()
+        .inspected::<seventeen::Day>(),
+    Day::simulated::<eighteen::Day>(18)
+        .rendered::<eighteen::Day>()
+        .inspected::<eighteen::Day>(),
+    Day::new::<nineteen::Day>(19).inspected::<nineteen::Day>(),
+    Day::new::<twenty::Day>(20).inspected::<twenty::Day>(),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
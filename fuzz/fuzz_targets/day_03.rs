@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let day = days::get_day(3).expect("day 3 should be registered");
+    let _ = day.parse(input);
+});
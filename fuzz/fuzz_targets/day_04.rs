@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let day = days::get_day(4).expect("day 4 should be registered");
+    let _ = day.parse(input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let day = days::get_day(5).expect("day 5 should be registered");
+    let _ = day.parse(input);
+});
@@ -0,0 +1,197 @@
+use crate::Graph;
+
+use std::collections::HashMap;
+use std::vec;
+
+/// The state of Tarjan's algorithm, with nodes numbered in the order they
+/// were first reached.
+struct Tarjan<'a, G: Graph> {
+    graph: &'a G,
+    indices: HashMap<G::Node, usize>,
+    nodes: Vec<G::Node>,
+    /// The lowest numbered node on the stack each node is known to reach.
+    low_links: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    components: Vec<Vec<G::Node>>,
+}
+
+impl<G: Graph> Tarjan<'_, G> {
+    /// Numbers `node` and puts it on the stack, returning its number and the
+    /// neighbours still to look at.
+    fn visit(&mut self, node: G::Node) -> (usize, vec::IntoIter<G::Node>) {
+        let index = self.nodes.len();
+        let neighbours: Vec<G::Node> = self
+            .graph
+            .neighbours(&node)
+            .into_iter()
+            .map(|(neighbour, _)| neighbour)
+            .collect();
+
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.low_links.push(index);
+        self.on_stack.push(true);
+        self.stack.push(index);
+
+        (index, neighbours.into_iter())
+    }
+
+    /// Looks at every node reachable from `root` that hasn't already been
+    /// looked at. Uses its own stack rather than recursing, so long paths
+    /// can't overflow the call stack.
+    fn search_from(&mut self, root: G::Node) {
+        if self.indices.contains_key(&root) {
+            return;
+        }
+
+        let mut call_stack = vec![self.visit(root)];
+
+        while let Some((index, neighbours)) = call_stack.last_mut() {
+            let index = *index;
+
+            if let Some(neighbour) = neighbours.next() {
+                match self.indices.get(&neighbour) {
+                    Some(&neighbour_index) => {
+                        if self.on_stack[neighbour_index] {
+                            self.low_links[index] = self.low_links[index].min(neighbour_index);
+                        }
+                    }
+                    None => call_stack.push(self.visit(neighbour)),
+                }
+
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some((parent, _)) = call_stack.last() {
+                self.low_links[*parent] = self.low_links[*parent].min(self.low_links[index]);
+            }
+
+            if self.low_links[index] == index {
+                let members = self.stack.split_off(
+                    self.stack
+                        .iter()
+                        .rposition(|member| *member == index)
+                        .unwrap(),
+                );
+
+                for member in &members {
+                    self.on_stack[*member] = false;
+                }
+                self.components.push(
+                    members
+                        .into_iter()
+                        .map(|member| self.nodes[member].clone())
+                        .collect(),
+                );
+            }
+        }
+    }
+}
+
+/// Groups the nodes reachable from `roots` so that two nodes are in the same
+/// group exactly when each can reach the other, using Tarjan's algorithm.
+///
+/// Groups come out in reverse topological order: any edge between two
+/// groups goes from a later group to an earlier one.
+pub fn strongly_connected_components<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Vec<Vec<G::Node>> {
+    let mut tarjan = Tarjan {
+        graph,
+        indices: HashMap::new(),
+        nodes: Vec::new(),
+        low_links: Vec::new(),
+        on_stack: Vec::new(),
+        stack: Vec::new(),
+        components: Vec::new(),
+    };
+
+    for root in roots {
+        tarjan.search_from(root);
+    }
+
+    tarjan.components
+}
+
+#[cfg(test)]
+mod test {
+    use crate::strongly_connected_components;
+    use crate::test::AdjacencyList;
+
+    use proptest::collection;
+    use proptest::prelude::*;
+
+    fn adjacency_list() -> impl Strategy<Value = AdjacencyList> {
+        (1usize..12).prop_flat_map(|size| {
+            collection::vec(collection::vec(0..size, 0..4), size).prop_map(AdjacencyList)
+        })
+    }
+
+    /// Whether each node can reach each other node, including itself.
+    fn reachability(graph: &AdjacencyList) -> Vec<Vec<bool>> {
+        let size = graph.0.len();
+        let mut reachable = vec![vec![false; size]; size];
+
+        for (node, neighbours) in graph.0.iter().enumerate() {
+            reachable[node][node] = true;
+            for neighbour in neighbours {
+                reachable[node][*neighbour] = true;
+            }
+        }
+
+        for via in 0..size {
+            for from in 0..size {
+                for to in 0..size {
+                    if reachable[from][via] && reachable[via][to] {
+                        reachable[from][to] = true;
+                    }
+                }
+            }
+        }
+
+        reachable
+    }
+
+    proptest! {
+        #[test]
+        fn groups_nodes_that_reach_each_other(graph in adjacency_list()) {
+            let size = graph.0.len();
+            let components = strongly_connected_components(&graph, 0..size);
+            let reachable = reachability(&graph);
+
+            let mut component_of = vec![None; size];
+            for (component, nodes) in components.iter().enumerate() {
+                for node in nodes {
+                    prop_assert_eq!(component_of[*node], None);
+                    component_of[*node] = Some(component);
+                }
+            }
+
+            for from in 0..size {
+                for to in 0..size {
+                    prop_assert_eq!(
+                        component_of[from] == component_of[to],
+                        reachable[from][to] && reachable[to][from]
+                    );
+                }
+
+                for to in &graph.0[from] {
+                    prop_assert!(component_of[*to] <= component_of[from]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn only_includes_nodes_reachable_from_the_roots() {
+        let graph = AdjacencyList(vec![vec![1], vec![0, 2], vec![], vec![0]]);
+
+        assert_eq!(
+            strongly_connected_components(&graph, [0]),
+            vec![vec![2], vec![0, 1]]
+        );
+    }
+}
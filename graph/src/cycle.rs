@@ -0,0 +1,145 @@
+/// Where a sequence of states starts repeating. The states from index
+/// `start` onwards repeat every `length` states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Finds the cycle in `states` using Brent's algorithm, which steps through
+/// fewer states than Floyd's. Each state must only depend on the one before,
+/// so the states repeat forever after the first repeat.
+///
+/// Returns `None` if the states run out before a repeat is found.
+pub fn brent<I: Iterator + Clone>(states: I) -> Option<Cycle>
+where
+    I::Item: PartialEq,
+{
+    let mut hare = states.clone();
+    let mut tortoise = hare.next()?;
+    let mut hare_state = hare.next()?;
+    let mut power = 1;
+    let mut length = 1;
+
+    while tortoise != hare_state {
+        if power == length {
+            tortoise = hare_state;
+            power *= 2;
+            length = 0;
+        }
+
+        hare_state = hare.next()?;
+        length += 1;
+    }
+
+    let mut tortoise = states.clone();
+    let mut hare = states;
+    hare.nth(length - 1)?;
+
+    let mut start = 0;
+    let mut tortoise_state = tortoise.next()?;
+    let mut hare_state = hare.next()?;
+    while tortoise_state != hare_state {
+        tortoise_state = tortoise.next()?;
+        hare_state = hare.next()?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Finds the cycle in `states` using Floyd's algorithm. Each state must only
+/// depend on the one before, so the states repeat forever after the first
+/// repeat.
+///
+/// Returns `None` if the states run out before a repeat is found.
+pub fn floyd<I: Iterator + Clone>(states: I) -> Option<Cycle>
+where
+    I::Item: PartialEq,
+{
+    let mut tortoise = states.clone();
+    let mut hare = states.clone();
+    tortoise.next()?;
+    hare.next()?;
+
+    let mut tortoise_state = tortoise.next()?;
+    let mut hare_state = hare.nth(1)?;
+    while tortoise_state != hare_state {
+        tortoise_state = tortoise.next()?;
+        hare_state = hare.nth(1)?;
+    }
+
+    let mut from_start = states;
+    let mut start = 0;
+    let mut tortoise_state = from_start.next()?;
+    while tortoise_state != hare_state {
+        tortoise_state = from_start.next()?;
+        hare_state = hare.next()?;
+        start += 1;
+    }
+
+    let mut length = 1;
+    while from_start.next()? != tortoise_state {
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{brent, floyd, Cycle};
+
+    use std::collections::HashMap;
+    use std::iter;
+
+    use proptest::collection;
+    use proptest::prelude::*;
+
+    /// A function from each of some numbers to another of them, and a number
+    /// to start from.
+    fn function_and_start() -> impl Strategy<Value = (Vec<usize>, usize)> {
+        (1usize..20).prop_flat_map(|size| (collection::vec(0..size, size), 0..size))
+    }
+
+    fn brute_force(function: &[usize], start: usize) -> Cycle {
+        let mut first_seen = HashMap::new();
+        let mut state = start;
+
+        for index in 0.. {
+            if let Some(first) = first_seen.insert(state, index) {
+                return Cycle {
+                    start: first,
+                    length: index - first,
+                };
+            }
+            state = function[state];
+        }
+
+        unreachable!()
+    }
+
+    proptest! {
+        #[test]
+        fn finds_the_same_cycle_as_brute_force((function, start) in function_and_start()) {
+            let states = iter::successors(Some(start), |state| Some(function[*state]));
+            let expected = brute_force(&function, start);
+
+            prop_assert_eq!(brent(states.clone()), Some(expected));
+            prop_assert_eq!(floyd(states), Some(expected));
+        }
+    }
+
+    #[test]
+    fn gives_up_when_the_states_run_out() {
+        assert_eq!(brent([1, 2, 3].into_iter()), None);
+        assert_eq!(floyd([1, 2, 3].into_iter()), None);
+        assert_eq!(
+            brent([5, 5].into_iter()),
+            Some(Cycle {
+                start: 0,
+                length: 1
+            })
+        );
+    }
+}
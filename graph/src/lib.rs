@@ -0,0 +1,66 @@
+mod components;
+mod cycle;
+mod search;
+
+pub use components::strongly_connected_components;
+pub use cycle::brent;
+pub use cycle::floyd;
+pub use cycle::Cycle;
+pub use search::a_star;
+pub use search::breadth_first_search;
+pub use search::dijkstra;
+pub use search::ShortestPaths;
+
+use common::checked::Checked;
+
+use std::hash::Hash;
+
+/// A directed graph with a cost on each edge. Nodes can be worked out as
+/// they are reached, so a graph can be every state a puzzle can get into.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+    /// Must not be negative, as the searches assume adding a step never
+    /// makes a path cheaper.
+    type Cost: Checked + Ord;
+
+    /// The nodes one step on from `node`, with what it costs to reach each.
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, Self::Cost)>;
+}
+
+impl<G: Graph> Graph for &G {
+    type Node = G::Node;
+    type Cost = G::Cost;
+
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, Self::Cost)> {
+        (*self).neighbours(node)
+    }
+}
+
+/// A route through a graph, from the start to the goal inclusive, and what
+/// it costs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Graph;
+
+    /// Numbered nodes, each with the nodes it has an edge to.
+    #[derive(Debug)]
+    pub(crate) struct AdjacencyList(pub(crate) Vec<Vec<usize>>);
+
+    impl Graph for AdjacencyList {
+        type Node = usize;
+        type Cost = u32;
+
+        fn neighbours(&self, node: &usize) -> Vec<(usize, u32)> {
+            self.0[*node]
+                .iter()
+                .map(|neighbour| (*neighbour, 1))
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use crate::Graph;
+use crate::Path;
+
+use common::checked::Checked;
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+/// A node waiting in the queue, ordered only by its priority.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+/// The cheapest cost found to each node, and the node it was reached from.
+type Best<G> = HashMap<<G as Graph>::Node, (<G as Graph>::Cost, Option<<G as Graph>::Node>)>;
+
+type Heuristic<G> = fn(&<G as Graph>::Node) -> <G as Graph>::Cost;
+
+fn no_heuristic<G: Graph>(_: &G::Node) -> G::Cost {
+    G::Cost::ZERO
+}
+
+/// Dijkstra's algorithm, or A* with a heuristic, yielding each node reached
+/// from the start with the cost of the cheapest path to it. Nodes come out in
+/// order of that cost, plus the heuristic for A*.
+///
+/// Paths whose cost overflows are never taken. The search can own its graph
+/// or borrow it, as a reference to a graph is a graph too.
+pub struct ShortestPaths<G: Graph, H = Heuristic<G>> {
+    graph: G,
+    heuristic: H,
+    best: Best<G>,
+    queue: BinaryHeap<Reverse<Entry<G::Node, G::Cost>>>,
+}
+
+impl<G: Graph> ShortestPaths<G> {
+    pub fn dijkstra(graph: G, start: G::Node) -> ShortestPaths<G> {
+        ShortestPaths::a_star(graph, start, no_heuristic::<G>)
+    }
+}
+
+impl<G: Graph, H: Fn(&G::Node) -> G::Cost> ShortestPaths<G, H> {
+    /// The `heuristic` guesses the cost from a node to the goal. If it ever
+    /// guesses too high the paths found may not be the cheapest.
+    pub fn a_star(graph: G, start: G::Node, heuristic: H) -> ShortestPaths<G, H> {
+        let entry = Entry {
+            priority: heuristic(&start),
+            cost: G::Cost::ZERO,
+            node: start.clone(),
+        };
+
+        ShortestPaths {
+            graph,
+            heuristic,
+            best: HashMap::from([(start, (G::Cost::ZERO, None))]),
+            queue: BinaryHeap::from([Reverse(entry)]),
+        }
+    }
+
+    /// The priority of the next node to be looked at, which for Dijkstra's
+    /// algorithm is its cost.
+    pub fn peek_priority(&self) -> Option<G::Cost> {
+        self.queue.peek().map(|Reverse(entry)| entry.priority)
+    }
+
+    /// The nodes waiting to be looked at.
+    pub fn frontier(&self) -> impl Iterator<Item = &G::Node> {
+        self.queue.iter().map(|Reverse(entry)| &entry.node)
+    }
+
+    /// The cheapest path found so far from the start to `node`.
+    pub fn path_to(&self, node: &G::Node) -> Option<Path<G::Node, G::Cost>> {
+        let (cost, _) = self.best.get(node)?;
+        let nodes = follow_parents(node.clone(), |node| self.best[node].1.clone());
+
+        Some(Path { nodes, cost: *cost })
+    }
+}
+
+impl<G: Graph, H: Fn(&G::Node) -> G::Cost> Iterator for ShortestPaths<G, H> {
+    type Item = (G::Node, G::Cost);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Reverse(Entry { cost, node, .. })) = self.queue.pop() {
+            if cost > self.best[&node].0 {
+                continue;
+            }
+
+            for (neighbour, step_cost) in self.graph.neighbours(&node) {
+                let Ok(neighbour_cost) = cost.try_add(step_cost) else {
+                    continue;
+                };
+
+                if let Some((existing_cost, _)) = self.best.get(&neighbour) {
+                    if neighbour_cost >= *existing_cost {
+                        continue;
+                    }
+                }
+
+                let Ok(priority) = neighbour_cost.try_add((self.heuristic)(&neighbour)) else {
+                    continue;
+                };
+
+                self.best
+                    .insert(neighbour.clone(), (neighbour_cost, Some(node.clone())));
+                self.queue.push(Reverse(Entry {
+                    priority,
+                    cost: neighbour_cost,
+                    node: neighbour,
+                }));
+            }
+
+            return Some((node, cost));
+        }
+
+        None
+    }
+}
+
+/// The cheapest path from `start` to any node that `is_goal`.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node, G::Cost>> {
+    first_goal(ShortestPaths::dijkstra(graph, start), is_goal)
+}
+
+/// The cheapest path from `start` to any node that `is_goal`, looking at the
+/// nodes `heuristic` guesses are closest to a goal first.
+pub fn a_star<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> G::Cost,
+) -> Option<Path<G::Node, G::Cost>> {
+    first_goal(ShortestPaths::a_star(graph, start, heuristic), is_goal)
+}
+
+fn first_goal<G: Graph, H: Fn(&G::Node) -> G::Cost>(
+    mut search: ShortestPaths<G, H>,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node, G::Cost>> {
+    loop {
+        let (node, _) = search.next()?;
+
+        if is_goal(&node) {
+            return search.path_to(&node);
+        }
+    }
+}
+
+/// The path with the fewest steps from `start` to any node that `is_goal`,
+/// ignoring what each step costs. The path's cost is its number of steps.
+pub fn breadth_first_search<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node, usize>> {
+    let mut parents: HashMap<G::Node, Option<G::Node>> = HashMap::from([(start.clone(), None)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = follow_parents(node, |node| parents[node].clone());
+
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+
+        for (neighbour, _) in graph.neighbours(&node) {
+            if !parents.contains_key(&neighbour) {
+                parents.insert(neighbour.clone(), Some(node.clone()));
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    None
+}
+
+/// The nodes from the start to `node`, following each node's parent back to
+/// the start, which has none.
+fn follow_parents<N>(node: N, parent: impl Fn(&N) -> Option<N>) -> Vec<N> {
+    let mut nodes = vec![node];
+    while let Some(parent) = parent(nodes.last().unwrap()) {
+        nodes.push(parent);
+    }
+    nodes.reverse();
+
+    nodes
+}
+
+#[cfg(test)]
+mod test {
+    use crate::search::ShortestPaths;
+    use crate::test::AdjacencyList;
+    use crate::{a_star, breadth_first_search, dijkstra, Graph, Path};
+
+    use proptest::collection;
+    use proptest::prelude::*;
+
+    /// A grid of costs to enter each square, moving up, down, left or right.
+    #[derive(Debug)]
+    struct CostGrid(Vec<Vec<u32>>);
+
+    impl Graph for CostGrid {
+        type Node = (usize, usize);
+        type Cost = u32;
+
+        fn neighbours(&self, &(x, y): &(usize, usize)) -> Vec<((usize, usize), u32)> {
+            [(0, -1), (0, 1), (-1, 0), (1, 0)]
+                .into_iter()
+                .filter_map(|(dx, dy)| {
+                    let x = x.checked_add_signed(dx)?;
+                    let y = y.checked_add_signed(dy)?;
+                    let cost = *self.0.get(y)?.get(x)?;
+
+                    Some(((x, y), cost))
+                })
+                .collect()
+        }
+    }
+
+    fn cost_grid() -> impl Strategy<Value = CostGrid> {
+        (1usize..6, 1usize..6).prop_flat_map(|(width, height)| {
+            collection::vec(collection::vec(0u32..10, width), height).prop_map(CostGrid)
+        })
+    }
+
+    /// The cost of every cheapest path from the top left, by relaxing every
+    /// edge until nothing changes.
+    fn bellman_ford(grid: &CostGrid) -> Vec<Vec<u32>> {
+        let mut costs = vec![vec![u32::MAX; grid.0[0].len()]; grid.0.len()];
+        costs[0][0] = 0;
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for y in 0..costs.len() {
+                for x in 0..costs[0].len() {
+                    for ((next_x, next_y), cost) in grid.neighbours(&(x, y)) {
+                        let next_cost = costs[y][x].saturating_add(cost);
+                        if next_cost < costs[next_y][next_x] {
+                            costs[next_y][next_x] = next_cost;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        costs
+    }
+
+    fn path_cost(grid: &CostGrid, path: &Path<(usize, usize), u32>) -> u32 {
+        path.nodes.iter().skip(1).map(|&(x, y)| grid.0[y][x]).sum()
+    }
+
+    proptest! {
+        #[test]
+        fn finds_the_cheapest_paths(grid in cost_grid()) {
+            let expected = bellman_ford(&grid);
+            let goal = (grid.0[0].len() - 1, grid.0.len() - 1);
+
+            let path = dijkstra(&grid, (0, 0), |node| *node == goal).unwrap();
+            prop_assert_eq!(path.cost, expected[goal.1][goal.0]);
+            prop_assert_eq!(path_cost(&grid, &path), path.cost);
+            prop_assert_eq!(path.nodes.first(), Some(&(0, 0)));
+            prop_assert_eq!(path.nodes.last(), Some(&goal));
+
+            let manhattan = |&(x, y): &(usize, usize)| {
+                if grid.0.iter().flatten().all(|cost| *cost > 0) {
+                    (goal.0 - x + goal.1 - y) as u32
+                } else {
+                    0
+                }
+            };
+            let path = a_star(&grid, (0, 0), |node| *node == goal, manhattan).unwrap();
+            prop_assert_eq!(path.cost, expected[goal.1][goal.0]);
+            prop_assert_eq!(path_cost(&grid, &path), path.cost);
+        }
+
+        #[test]
+        fn yields_nodes_in_order_of_cost(grid in cost_grid()) {
+            let expected = bellman_ford(&grid);
+            let visited: Vec<((usize, usize), u32)> =
+                ShortestPaths::dijkstra(&grid, (0, 0)).collect();
+
+            prop_assert_eq!(visited.len(), grid.0.len() * grid.0[0].len());
+            prop_assert!(visited.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+            for ((x, y), cost) in visited {
+                prop_assert_eq!(cost, expected[y][x]);
+            }
+        }
+    }
+
+    #[test]
+    fn finds_the_fewest_steps() {
+        let graph = AdjacencyList(vec![vec![1, 2], vec![3], vec![4], vec![], vec![3], vec![0]]);
+
+        assert_eq!(
+            breadth_first_search(&graph, 0, |node| *node == 3),
+            Some(Path {
+                nodes: vec![0, 1, 3],
+                cost: 2
+            })
+        );
+        assert_eq!(breadth_first_search(&graph, 0, |node| *node == 5), None);
+        assert_eq!(dijkstra(&graph, 0, |node| *node == 5), None);
+    }
+}
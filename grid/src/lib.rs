@@ -0,0 +1,388 @@
+mod position;
+mod render;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+pub use position::Direction;
+pub use position::Position;
+pub use render::Render;
+
+use common::diagnostic::ParseError;
+use common::diagnostic::Span;
+use common::frame::Cell;
+use common::frame::Frame;
+use common::model::Value;
+
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    Empty {
+        span: Span,
+    },
+    UnevenRows {
+        row: usize,
+        span: Span,
+    },
+    InvalidCell {
+        position: Position,
+        span: Span,
+        source: E,
+    },
+}
+
+impl<E> Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::Empty { .. } => write!(f, "grid is empty"),
+            ParseGridError::UnevenRows { row, .. } => {
+                write!(f, "row {} is not as wide as the first row", row + 1)
+            }
+            ParseGridError::InvalidCell { position, .. } => write!(
+                f,
+                "invalid cell in row {}, column {}",
+                position.y + 1,
+                position.x + 1
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseGridError::InvalidCell { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error + 'static> ParseError for ParseGridError<E> {
+    fn span(&self) -> Option<&Span> {
+        match self {
+            ParseGridError::Empty { span }
+            | ParseGridError::UnevenRows { span, .. }
+            | ParseGridError::InvalidCell { span, .. } => Some(span),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row order.
+    ///
+    /// Panics if there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "grid must be rectangular");
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, mapping each character to a cell. Trailing
+    /// newlines are ignored but every row must be the same length.
+    pub fn parse_with<E>(
+        s: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseGridError<E>> {
+        let lines: Vec<&str> = s.trim_end_matches('\n').split('\n').collect();
+
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseGridError::Empty { span: Span::of(s) });
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let mut row_width = 0;
+
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                let cell = parse_cell(c).map_err(|source| ParseGridError::InvalidCell {
+                    position: Position { x, y },
+                    span: Span::of(&line[i..i + c.len_utf8()]),
+                    source,
+                })?;
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            if row_width != width {
+                return Err(ParseGridError::UnevenRows {
+                    row: y,
+                    span: Span::of(line),
+                });
+            }
+        }
+
+        Ok(Grid::new(width, lines.len(), cells))
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    fn get_index(&self, position: Position) -> Option<usize> {
+        if position.x >= self.width || position.y >= self.height {
+            return None;
+        }
+
+        Some(position.y * self.width + position.x)
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.get_index(position).is_some()
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        let index = self.get_index(position)?;
+
+        Some(&self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let index = self.get_index(position)?;
+
+        Some(&mut self.cells[index])
+    }
+
+    /// Swaps two cells. Panics if either position is outside the grid.
+    pub fn swap(&mut self, position_1: Position, position_2: Position) {
+        let index_1 = self
+            .get_index(position_1)
+            .expect("position should be in the grid");
+        let index_2 = self
+            .get_index(position_2)
+            .expect("position should be in the grid");
+
+        self.cells.swap(index_1, index_2);
+    }
+
+    /// The adjacent position in the given direction, if it is in the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position
+            .step(direction)
+            .filter(|next_position| self.contains(*next_position))
+    }
+
+    /// The up to four positions directly above, below, left and right.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight positions surrounding `position`, including diagonals.
+    pub fn neighbours_with_diagonals(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|offset| *offset != (0, 0))
+            .filter_map(move |(dx, dy)| position.offset(dx, dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Every position in the grid in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// The position of the first cell, in row order, matching `predicate`.
+    pub fn find_position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.height {
+            return None;
+        }
+
+        Some(&self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x >= self.width {
+            return None;
+        }
+
+        Some(self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Displays the grid, drawing each cell with `render_cell`.
+    pub fn render<F, D>(&self, render_cell: F) -> Render<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        Render::new(self, render_cell)
+    }
+
+    /// Draws the grid as a frame of a simulation. `draw_cell` is given each
+    /// cell's position so it can pick out the interesting ones.
+    pub fn frame(&self, mut draw_cell: impl FnMut(Position, &T) -> Cell) -> Frame {
+        let rows = self
+            .rows()
+            .enumerate()
+            .map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(x, cell)| draw_cell(Position { x, y }, cell))
+                    .collect()
+            })
+            .collect();
+
+        Frame::new(rows)
+    }
+
+    /// The grid as a list of rows, each a string of the cells drawn by
+    /// `symbol`.
+    pub fn to_value(&self, symbol: impl Fn(&T) -> char) -> Value {
+        Value::list(
+            self.rows()
+                .map(|row| row.iter().map(&symbol).collect::<String>()),
+        )
+    }
+}
+
+impl From<Position> for Value {
+    fn from(position: Position) -> Self {
+        (position.x, position.y).into()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, so the cell at `(x, y)` moves to `(y, x)`.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = self.columns().flatten().cloned().collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates a quarter turn clockwise, so the first column becomes the
+    /// first row read bottom to top.
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.collect::<Vec<&T>>().into_iter().rev())
+            .cloned()
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates a quarter turn anticlockwise, so the last column becomes the
+    /// first row read top to bottom.
+    pub fn rotate_anticlockwise(&self) -> Grid<T> {
+        let columns: Vec<Vec<&T>> = self.columns().map(|column| column.collect()).collect();
+        let cells = columns.into_iter().rev().flatten().cloned().collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError<T::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, T::try_from)
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(|cell| cell.to_string()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Grid, ParseGridError, Position};
+
+    use common::diagnostic::Span;
+
+    fn parse_digits(s: &str) -> Grid<u32> {
+        Grid::parse_with(s, |c| c.to_digit(10).ok_or(c)).unwrap()
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = parse_digits("123\n456\n");
+
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "36\n25\n14");
+    }
+
+    #[test]
+    fn uneven_rows_are_rejected() {
+        let input = "123\n45\n678";
+        let result = Grid::parse_with(input, |c| c.to_digit(10).ok_or(c));
+
+        assert_eq!(
+            result,
+            Err(ParseGridError::UnevenRows {
+                row: 1,
+                span: Span::of(&input[4..6])
+            })
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_in_bounds() {
+        let grid = parse_digits("123\n456\n789");
+
+        assert_eq!(grid.neighbours(Position { x: 0, y: 0 }).count(), 2);
+        assert_eq!(grid.neighbours(Position { x: 1, y: 1 }).count(), 4);
+        assert_eq!(
+            grid.neighbours_with_diagonals(Position { x: 0, y: 2 })
+                .count(),
+            3
+        );
+        assert_eq!(
+            grid.neighbours_with_diagonals(Position { x: 1, y: 1 })
+                .count(),
+            8
+        );
+    }
+}
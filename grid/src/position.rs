@@ -0,0 +1,96 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub y: usize,
+    pub x: usize,
+}
+
+impl Position {
+    /// The adjacent position in the given direction, or `None` if it would
+    /// fall off the top or left of the grid.
+    pub fn step(self, direction: Direction) -> Option<Position> {
+        let Position { x, y } = self;
+
+        match direction {
+            Direction::Up => Some(Position {
+                x,
+                y: y.checked_sub(1)?,
+            }),
+            Direction::Down => Some(Position {
+                x,
+                y: y.checked_add(1)?,
+            }),
+            Direction::Left => Some(Position {
+                x: x.checked_sub(1)?,
+                y,
+            }),
+            Direction::Right => Some(Position {
+                x: x.checked_add(1)?,
+                y,
+            }),
+        }
+    }
+
+    /// Offsets the position by `(dx, dy)`, returning `None` on underflow or
+    /// overflow.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Position> {
+        Some(Position {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// An arrow pointing this way, for drawing things on the move.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use crate::Grid;
+
+/// Displays a grid one row per line, drawing each cell with a closure.
+///
+/// Created by [`Grid::render`].
+pub struct Render<'a, T, F> {
+    grid: &'a Grid<T>,
+    render_cell: F,
+}
+
+impl<'a, T, F> Render<'a, T, F> {
+    pub(crate) fn new(grid: &'a Grid<T>, render_cell: F) -> Render<'a, T, F> {
+        Render { grid, render_cell }
+    }
+}
+
+impl<T, F, D> Display for Render<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.grid.rows().enumerate() {
+            if y > 0 {
+                f.write_str("\n")?;
+            }
+
+            for cell in row {
+                (self.render_cell)(cell).fmt(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
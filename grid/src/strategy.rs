@@ -0,0 +1,22 @@
+//! Proptest strategies for grids written as text, for testing the parsers and
+//! renderers of types built on [`Grid`](crate::Grid).
+
+use proptest::collection;
+use proptest::sample;
+use proptest::strategy::Strategy;
+
+/// Rectangular grids between 1x1 and `max_size` x `max_size`, one row per
+/// line with no trailing newline, with every cell drawn from `cells`.
+pub fn text(cells: &[char], max_size: usize) -> impl Strategy<Value = String> {
+    let cells = cells.to_vec();
+
+    (1..=max_size, 1..=max_size).prop_flat_map(move |(width, height)| {
+        collection::vec(sample::select(cells.clone()), width * height).prop_map(move |cells| {
+            cells
+                .chunks(width)
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+    })
+}
@@ -0,0 +1,340 @@
+use common::checked::Checked;
+use common::checked::Overflow;
+
+use std::iter;
+use std::slice;
+
+/// The integers from `start` up to but not including `end`. It is empty if
+/// `end` is not after `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Checked + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    /// The `length` integers counting up from `start`.
+    pub fn starting_at(start: T, length: T) -> Result<Interval<T>, Overflow> {
+        Ok(Interval {
+            start,
+            end: start.try_add(length)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// How many integers are in the interval, which may not fit in `T` for
+    /// signed types.
+    pub fn len(&self) -> Result<T, Overflow> {
+        if self.is_empty() {
+            return Ok(T::ZERO);
+        }
+
+        self.end.try_sub(self.start)
+    }
+
+    /// The integers in both intervals, which may be empty.
+    pub fn intersection(&self, other: &Interval<T>) -> Interval<T> {
+        Interval {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        }
+    }
+}
+
+/// A set of integers stored as intervals.
+///
+/// The intervals are kept sorted, non-empty and with a gap between each, so
+/// two sets holding the same integers always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Checked + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    fn normalise(mut intervals: Vec<Interval<T>>) -> IntervalSet<T> {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort();
+
+        let mut normalised: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match normalised.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => normalised.push(interval),
+            }
+        }
+
+        IntervalSet {
+            intervals: normalised,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+
+        *self = IntervalSet::normalise(intervals);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::normalise([&self.intervals[..], &other.intervals[..]].concat())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(interval_1), Some(interval_2)) =
+            (self.intervals.get(i), other.intervals.get(j))
+        {
+            let overlap = interval_1.intersection(interval_2);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+
+            if interval_1.end < interval_2.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The integers in `self` that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+
+        for interval in &self.intervals {
+            let mut start = interval.start;
+
+            while other
+                .intervals
+                .get(j)
+                .is_some_and(|other| other.end <= start)
+            {
+                j += 1;
+            }
+
+            for other in other.intervals[j..]
+                .iter()
+                .take_while(|other| other.start < interval.end)
+            {
+                if start < other.start {
+                    intervals.push(Interval::new(start, other.start));
+                }
+                start = start.max(other.end);
+            }
+
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The integers in `universe` that are not in the set.
+    pub fn complement(&self, universe: Interval<T>) -> IntervalSet<T> {
+        IntervalSet::from(universe).difference(self)
+    }
+
+    /// The intervals in the set, in order.
+    pub fn iter(&self) -> slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+
+    /// Every integer in the set, in order.
+    pub fn values(&self) -> impl Iterator<Item = T> + '_ {
+        self.intervals.iter().flat_map(|interval| {
+            iter::successors(Some(interval.start), move |value| {
+                value
+                    .try_add(T::ONE)
+                    .ok()
+                    .filter(|next| *next < interval.end)
+            })
+        })
+    }
+
+    /// How many integers are in the set.
+    pub fn cardinality(&self) -> Result<T, Overflow> {
+        self.intervals
+            .iter()
+            .try_fold(T::ZERO, |total, interval| total.try_add(interval.len()?))
+    }
+}
+
+impl<T: Checked + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::normalise(vec![interval])
+    }
+}
+
+impl<T: Checked + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        IntervalSet::normalise(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Interval, IntervalSet};
+
+    use common::checked::Overflow;
+
+    use std::collections::BTreeSet;
+
+    use proptest::collection;
+    use proptest::prelude::*;
+
+    /// Up to eight intervals, possibly empty or overlapping, over a small
+    /// range so sets share plenty of integers.
+    fn intervals() -> impl Strategy<Value = Vec<Interval<u8>>> {
+        collection::vec(
+            (0u8..64, 0u8..64).prop_map(|(start, end)| Interval::new(start, end)),
+            0..8,
+        )
+    }
+
+    fn brute_force(intervals: &[Interval<u8>]) -> BTreeSet<u8> {
+        intervals
+            .iter()
+            .flat_map(|interval| interval.start..interval.end)
+            .collect()
+    }
+
+    fn values(set: &IntervalSet<u8>) -> BTreeSet<u8> {
+        set.values().collect()
+    }
+
+    proptest! {
+        #[test]
+        fn stores_normalised_intervals(intervals in intervals()) {
+            let set: IntervalSet<u8> = intervals.iter().copied().collect();
+            let stored: Vec<&Interval<u8>> = set.iter().collect();
+
+            prop_assert!(stored.iter().all(|interval| !interval.is_empty()));
+            prop_assert!(stored.windows(2).all(|pair| pair[0].end < pair[1].start));
+            prop_assert_eq!(values(&set), brute_force(&intervals));
+        }
+
+        #[test]
+        fn equal_sets_compare_equal(intervals in intervals()) {
+            let set: IntervalSet<u8> = intervals.iter().copied().collect();
+            let singletons: IntervalSet<u8> = brute_force(&intervals)
+                .into_iter()
+                .map(|value| Interval::new(value, value + 1))
+                .collect();
+
+            prop_assert_eq!(set, singletons);
+        }
+
+        #[test]
+        fn set_operations_match_brute_force(
+            intervals_1 in intervals(),
+            intervals_2 in intervals(),
+        ) {
+            let set_1: IntervalSet<u8> = intervals_1.iter().copied().collect();
+            let set_2: IntervalSet<u8> = intervals_2.iter().copied().collect();
+            let brute_force_1 = brute_force(&intervals_1);
+            let brute_force_2 = brute_force(&intervals_2);
+
+            prop_assert_eq!(
+                values(&set_1.union(&set_2)),
+                &brute_force_1 | &brute_force_2
+            );
+            prop_assert_eq!(
+                values(&set_1.intersection(&set_2)),
+                &brute_force_1 & &brute_force_2
+            );
+            prop_assert_eq!(
+                values(&set_1.difference(&set_2)),
+                &brute_force_1 - &brute_force_2
+            );
+        }
+
+        #[test]
+        fn complement_matches_brute_force(
+            intervals in intervals(),
+            universe_start in 0u8..64,
+            universe_end in 0u8..64,
+        ) {
+            let set: IntervalSet<u8> = intervals.iter().copied().collect();
+            let universe = Interval::new(universe_start, universe_end);
+            let expected: BTreeSet<u8> = (universe_start..universe_end)
+                .filter(|value| !brute_force(&intervals).contains(value))
+                .collect();
+
+            prop_assert_eq!(values(&set.complement(universe)), expected);
+        }
+
+        #[test]
+        fn membership_and_cardinality_match_brute_force(intervals in intervals()) {
+            let set: IntervalSet<u8> = intervals.iter().copied().collect();
+            let brute_force = brute_force(&intervals);
+
+            for value in 0..=u8::MAX {
+                prop_assert_eq!(set.contains(value), brute_force.contains(&value));
+            }
+            prop_assert_eq!(set.cardinality(), Ok(brute_force.len() as u8));
+        }
+    }
+
+    #[test]
+    fn works_up_to_the_edges_of_the_type() {
+        let set = IntervalSet::from(Interval::new(i8::MIN, i8::MAX));
+
+        assert!(set.contains(i8::MIN));
+        assert!(!set.contains(i8::MAX));
+        assert_eq!(set.values().last(), Some(i8::MAX - 1));
+        assert_eq!(set.cardinality(), Err(Overflow));
+        assert_eq!(Interval::starting_at(250u8, 10), Err(Overflow));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::gcd;
+use crate::lcm;
+
+use common::checked::Overflow;
+
+/// The numbers that leave `remainder` when divided by `modulus`, which must
+/// not be zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Congruence {
+    pub remainder: u64,
+    pub modulus: u64,
+}
+
+/// The number that, multiplied by `a`, leaves one when divided by `modulus`.
+/// `a` and `modulus` must have no common divisor other than one.
+fn inverse(a: i128, modulus: i128) -> i128 {
+    let (mut remainder, mut next_remainder) = (a, modulus);
+    let (mut coefficient, mut next_coefficient) = (1, 0);
+
+    while next_remainder != 0 {
+        let quotient = remainder / next_remainder;
+        (remainder, next_remainder) = (next_remainder, remainder - quotient * next_remainder);
+        (coefficient, next_coefficient) =
+            (next_coefficient, coefficient - quotient * next_coefficient);
+    }
+
+    coefficient.rem_euclid(modulus)
+}
+
+/// The numbers in both congruences, if there are any.
+fn combine(a: Congruence, b: Congruence) -> Result<Option<Congruence>, Overflow> {
+    let (remainder_a, remainder_b) = (a.remainder % a.modulus, b.remainder % b.modulus);
+    let divisor = gcd(a.modulus, b.modulus);
+    let difference = i128::from(remainder_b) - i128::from(remainder_a);
+
+    if difference % i128::from(divisor) != 0 {
+        return Ok(None);
+    }
+
+    let modulus = lcm(a.modulus, b.modulus)?;
+
+    // How many times to add a's modulus to its remainder to reach b's.
+    let step_modulus = i128::from(b.modulus / divisor);
+    let steps = (difference / i128::from(divisor)).rem_euclid(step_modulus) as u128
+        * inverse(i128::from(a.modulus / divisor), step_modulus) as u128
+        % step_modulus as u128;
+
+    // Less than the combined modulus, so it fits.
+    let remainder = u128::from(remainder_a) + u128::from(a.modulus) * steps;
+
+    Ok(Some(Congruence {
+        remainder: remainder as u64,
+        modulus,
+    }))
+}
+
+/// The numbers in every one of the `congruences`, which don't need coprime
+/// moduli, as a single congruence. `None` if no number is in them all. Only
+/// overflows if the least common multiple of the moduli does.
+pub fn chinese_remainder(
+    congruences: impl IntoIterator<Item = Congruence>,
+) -> Result<Option<Congruence>, Overflow> {
+    let mut combined = Congruence {
+        remainder: 0,
+        modulus: 1,
+    };
+
+    for congruence in congruences {
+        let Some(next) = combine(combined, congruence)? else {
+            return Ok(None);
+        };
+        combined = next;
+    }
+
+    Ok(Some(combined))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{chinese_remainder, lcm_all, Congruence};
+
+    use common::checked::Overflow;
+
+    use proptest::collection;
+    use proptest::prelude::*;
+
+    fn congruences() -> impl Strategy<Value = Vec<Congruence>> {
+        collection::vec(
+            (0u64..30, 1u64..13).prop_map(|(remainder, modulus)| Congruence { remainder, modulus }),
+            0..4,
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn finds_the_smallest_solution(congruences in congruences()) {
+            let modulus = lcm_all(congruences.iter().map(|congruence| congruence.modulus)).unwrap();
+            let smallest = (0..modulus).find(|n| {
+                congruences
+                    .iter()
+                    .all(|congruence| n % congruence.modulus == congruence.remainder % congruence.modulus)
+            });
+
+            prop_assert_eq!(
+                chinese_remainder(congruences),
+                Ok(smallest.map(|remainder| Congruence { remainder, modulus }))
+            );
+        }
+    }
+
+    #[test]
+    fn handles_moduli_near_the_limit() {
+        let large_prime = 18446744073709551557;
+
+        assert_eq!(
+            chinese_remainder([
+                Congruence {
+                    remainder: large_prime - 1,
+                    modulus: large_prime,
+                },
+                Congruence {
+                    remainder: 0,
+                    modulus: 1 << 32,
+                },
+            ]),
+            Err(Overflow)
+        );
+        assert_eq!(
+            chinese_remainder([
+                Congruence {
+                    remainder: 3,
+                    modulus: u64::MAX,
+                },
+                Congruence {
+                    remainder: 3,
+                    modulus: u64::MAX,
+                },
+            ]),
+            Ok(Some(Congruence {
+                remainder: 3,
+                modulus: u64::MAX
+            }))
+        );
+    }
+}
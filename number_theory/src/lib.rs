@@ -0,0 +1,161 @@
+mod crt;
+mod rational;
+
+pub use crt::chinese_remainder;
+pub use crt::Congruence;
+pub use rational::Rational;
+
+use common::checked::Checked;
+use common::checked::Overflow;
+
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Rem;
+use std::ops::Sub;
+
+/// The integer types, with the operations the functions here need. Only
+/// operations that can't overflow are done without checking.
+pub trait Integer:
+    Checked + Ord + Add<Output = Self> + Sub<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+{
+}
+
+impl<T> Integer for T where
+    T: Checked + Ord + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Rem<Output = T>
+{
+}
+
+/// The greatest common divisor of two numbers, which must not be negative.
+/// The greatest common divisor of zero and `n` is `n`.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of two numbers, which must not be negative.
+/// The least common multiple of zero and anything is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+
+    a.try_mul(b / gcd(a, b))
+}
+
+/// The greatest common divisor of every number, or zero if there are none.
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of every number, or one if there are none.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Result<T, Overflow> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// The largest number whose square is at most `n`, which must not be
+/// negative.
+pub fn isqrt<T: Integer>(n: T) -> T {
+    let mut low = T::ZERO;
+    let mut high = n;
+
+    while low < high {
+        let middle = low + (high - low) / (T::ONE + T::ONE) + T::ONE;
+
+        if middle.try_mul(middle).is_ok_and(|square| square <= n) {
+            low = middle;
+        } else {
+            high = middle - T::ONE;
+        }
+    }
+
+    low
+}
+
+/// How many ways there are to choose `k` things from `n`, or zero if `k` is
+/// out of range. No factorials are worked out, so this only overflows if the
+/// answer does.
+pub fn binomial<T: Integer>(n: T, k: T) -> Result<T, Overflow> {
+    if k < T::ZERO || k > n {
+        return Ok(T::ZERO);
+    }
+
+    let k = k.min(n - k);
+    let mut coefficient = T::ONE;
+    let mut chosen = T::ONE;
+
+    while chosen <= k {
+        // Going from (n - k + chosen - 1) choose (chosen - 1) to
+        // (n - k + chosen) choose chosen, dividing first so only the answer
+        // itself has to fit.
+        let divisor = gcd(coefficient, chosen);
+        coefficient = (coefficient / divisor).try_mul((n - k + chosen) / (chosen / divisor))?;
+        chosen = chosen + T::ONE;
+    }
+
+    Ok(coefficient)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{binomial, gcd, gcd_all, isqrt, lcm, lcm_all};
+
+    use common::checked::Overflow;
+
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn gcd_and_lcm_divide_correctly(a in 0u64..1000, b in 0u64..1000) {
+            let divisor = gcd(a, b);
+            let multiple = lcm(a, b).unwrap();
+
+            prop_assert_eq!(divisor * multiple, a * b);
+            if divisor > 0 {
+                prop_assert_eq!(a % divisor, 0);
+                prop_assert_eq!(b % divisor, 0);
+                prop_assert!((divisor + 1..=a.max(b)).all(|larger| a % larger != 0 || b % larger != 0));
+            }
+        }
+
+        #[test]
+        fn isqrt_is_the_largest_root(n: u64) {
+            let root = isqrt(n);
+
+            prop_assert!(root * root <= n);
+            prop_assert!((root + 1).checked_mul(root + 1).is_none_or(|square| square > n));
+        }
+
+        #[test]
+        fn binomial_matches_pascals_triangle(n in 0u64..60) {
+            let mut row = vec![1u64];
+            for _ in 0..n {
+                row = std::iter::once(1)
+                    .chain(row.windows(2).map(|pair| pair[0] + pair[1]))
+                    .chain(std::iter::once(1))
+                    .collect();
+            }
+
+            for (k, expected) in row.into_iter().enumerate() {
+                prop_assert_eq!(binomial(n, k as u64), Ok(expected));
+            }
+            prop_assert_eq!(binomial(n, n + 1), Ok(0));
+        }
+    }
+
+    #[test]
+    fn only_overflows_when_the_answer_does() {
+        assert_eq!(lcm(u64::MAX, u64::MAX), Ok(u64::MAX));
+        assert_eq!(lcm(u64::MAX, 2), Err(Overflow));
+        assert_eq!(lcm_all([4u64, 6, 10]), Ok(60));
+        assert_eq!(lcm_all(Vec::<u64>::new()), Ok(1));
+        assert_eq!(gcd_all([12u64, 18, 8]), 2);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(i8::MAX), 11);
+        assert_eq!(binomial(67u64, 33), Ok(14226520737620288370));
+        assert_eq!(binomial(68u64, 34), Err(Overflow));
+        assert_eq!(binomial(-1i64, -1), Ok(0));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::gcd;
+use crate::lcm;
+
+use common::checked::Checked;
+use common::checked::Overflow;
+
+use std::fmt::Display;
+
+/// An exact fraction, kept in lowest terms with a positive denominator so
+/// equal fractions compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// Panics if `denominator` is zero, as dividing by zero does.
+    pub fn new(numerator: i128, denominator: i128) -> Result<Rational, Overflow> {
+        assert!(denominator != 0, "attempt to divide by zero");
+
+        let negative = (numerator < 0) != (denominator < 0);
+        let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let numerator = numerator.unsigned_abs() / divisor;
+        let denominator = denominator.unsigned_abs() / divisor;
+
+        let numerator = if negative {
+            0i128.checked_sub_unsigned(numerator)
+        } else {
+            i128::try_from(numerator).ok()
+        };
+
+        Ok(Rational {
+            numerator: numerator.ok_or(Overflow)?,
+            denominator: i128::try_from(denominator).map_err(|_| Overflow)?,
+        })
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// The fraction as a whole number, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    /// One divided by the fraction. Panics if the fraction is zero.
+    pub fn recip(self) -> Result<Rational, Overflow> {
+        Rational::new(self.denominator, self.numerator)
+    }
+
+    /// Panics if `other` is zero.
+    pub fn try_div(self, other: Rational) -> Result<Rational, Overflow> {
+        self.try_mul(other.recip()?)
+    }
+
+    /// Both fractions over their lowest common denominator, which is returned
+    /// along with the new numerators.
+    fn common_denominator(self, other: Rational) -> Result<(i128, i128, i128), Overflow> {
+        let denominator = lcm(self.denominator, other.denominator)?;
+
+        Ok((
+            self.numerator.try_mul(denominator / self.denominator)?,
+            other.numerator.try_mul(denominator / other.denominator)?,
+            denominator,
+        ))
+    }
+}
+
+/// Arithmetic on fractions, cancelling common factors before multiplying so
+/// it only overflows if a fraction in lowest terms can't be stored.
+impl Checked for Rational {
+    const ZERO: Self = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+    const ONE: Self = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    fn try_add(self, other: Self) -> Result<Self, Overflow> {
+        let (numerator, other_numerator, denominator) = self.common_denominator(other)?;
+
+        Rational::new(numerator.try_add(other_numerator)?, denominator)
+    }
+
+    fn try_sub(self, other: Self) -> Result<Self, Overflow> {
+        let (numerator, other_numerator, denominator) = self.common_denominator(other)?;
+
+        Rational::new(numerator.try_sub(other_numerator)?, denominator)
+    }
+
+    fn try_mul(self, other: Self) -> Result<Self, Overflow> {
+        // Each denominator is positive, so these are too.
+        let divisor = gcd(self.numerator.unsigned_abs(), other.denominator as u128) as i128;
+        let other_divisor = gcd(other.numerator.unsigned_abs(), self.denominator as u128) as i128;
+
+        Rational::new(
+            (self.numerator / divisor).try_mul(other.numerator / other_divisor)?,
+            (self.denominator / other_divisor).try_mul(other.denominator / divisor)?,
+        )
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(integer: i128) -> Self {
+        Rational {
+            numerator: integer,
+            denominator: 1,
+        }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_integer() {
+            Some(integer) => write!(f, "{}", integer),
+            None => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{gcd, Rational};
+
+    use common::checked::{Checked, CheckedIterator, Overflow};
+
+    use proptest::prelude::*;
+
+    fn fraction() -> impl Strategy<Value = (i128, i128)> {
+        (
+            -1000i128..1000,
+            (-1000i128..1000).prop_filter("non-zero", |d| *d != 0),
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn stays_in_lowest_terms((numerator, denominator) in fraction()) {
+            let rational = Rational::new(numerator, denominator).unwrap();
+
+            prop_assert!(rational.denominator() > 0);
+            prop_assert_eq!(
+                gcd(rational.numerator().unsigned_abs(), rational.denominator().unsigned_abs()),
+                1
+            );
+            prop_assert_eq!(
+                rational.numerator() * denominator,
+                numerator * rational.denominator()
+            );
+        }
+
+        #[test]
+        fn arithmetic_matches_cross_multiplying(
+            (a, b) in fraction(),
+            (c, d) in fraction(),
+        ) {
+            let x = Rational::new(a, b).unwrap();
+            let y = Rational::new(c, d).unwrap();
+
+            prop_assert_eq!(x.try_add(y), Rational::new(a * d + c * b, b * d));
+            prop_assert_eq!(x.try_sub(y), Rational::new(a * d - c * b, b * d));
+            prop_assert_eq!(x.try_mul(y), Rational::new(a * c, b * d));
+            if c != 0 {
+                prop_assert_eq!(x.try_div(y), Rational::new(a * d, b * c));
+            }
+        }
+    }
+
+    #[test]
+    fn adds_up_exactly() {
+        let thirds = [Rational::new(1, 3).unwrap(); 3];
+        let parts = [(1, 2), (1, 3), (1, 6)].map(|(n, d)| Rational::new(n, d).unwrap());
+
+        assert_eq!(thirds.into_iter().checked_sum(), Ok(Rational::ONE));
+        assert_eq!(
+            parts.into_iter().checked_sum().unwrap().to_integer(),
+            Some(1)
+        );
+        assert_eq!(Rational::new(6, -4).unwrap().to_string(), "-3/2");
+        assert_eq!(Rational::new(i128::MIN, -1), Err(Overflow));
+        assert_eq!(
+            Rational::new(i128::MAX, 2)
+                .unwrap()
+                .try_mul(Rational::new(2, i128::MAX).unwrap()),
+            Ok(Rational::ONE)
+        );
+        assert_eq!(
+            Rational::from(i128::MAX).try_add(Rational::ONE),
+            Err(Overflow)
+        );
+    }
+}
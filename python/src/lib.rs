@@ -0,0 +1,254 @@
+//! Python bindings for each day's parser and solvers.
+//!
+//! ```python
+//! import advent_of_code
+//!
+//! system = advent_of_code.parse(19, open("19/input.txt").read())
+//! system.model()["workflows"]["in"]
+//! system.part_two(max_rating=100)
+//! ```
+
+// Set off by the error conversion `#[pyfunction]` and `#[pymethods]` expand to.
+#![allow(clippy::useless_conversion)]
+
+use common::checked::Overflow;
+use common::model::Value;
+use common::parameters::Parameters;
+use common::Answer;
+
+use days::Day;
+use days::ParsedInput;
+use days::Part;
+use days::DAYS;
+
+use pyo3::exceptions::PyOverflowError;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::types::PyList;
+
+fn get_day(number: u8) -> PyResult<&'static Day> {
+    days::get_day(number)
+        .ok_or_else(|| PyValueError::new_err(format!("day {} is not implemented", number)))
+}
+
+/// A day's parsed puzzle input, ready to be solved or looked through.
+#[pyclass(unsendable, module = "advent_of_code")]
+struct Input {
+    day: &'static Day,
+    input: ParsedInput,
+}
+
+#[pymethods]
+impl Input {
+    #[getter]
+    fn day(&self) -> u8 {
+        self.day.number
+    }
+
+    /// The parsed input as dicts, lists, strings and ints. Raises
+    /// `ValueError` if the day's input can't be looked through.
+    fn model(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let model = self.day.model(self.input.as_ref()).ok_or_else(|| {
+            PyValueError::new_err(format!("day {} has no model", self.day.number))
+        })?;
+
+        Ok(to_python(py, &model))
+    }
+
+    /// Solves part one. Keyword arguments set the day's parameters. Raises
+    /// `OverflowError` if the answer is too large to work out.
+    #[pyo3(signature = (**parameters))]
+    fn part_one(
+        &self,
+        py: Python<'_>,
+        parameters: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<PyObject> {
+        let parameters = self.parameters(parameters)?;
+        let answer = self.day.solve(Part::One, self.input.as_ref(), &parameters);
+
+        answer_to_python(py, answer)
+    }
+
+    /// Solves part two. Keyword arguments set the day's parameters. Raises
+    /// `OverflowError` if the answer is too large to work out.
+    #[pyo3(signature = (**parameters))]
+    fn part_two(
+        &self,
+        py: Python<'_>,
+        parameters: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<PyObject> {
+        let parameters = self.parameters(parameters)?;
+        let answer = self.day.solve(Part::Two, self.input.as_ref(), &parameters);
+
+        answer_to_python(py, answer)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<advent_of_code.Input day={}>", self.day.number)
+    }
+}
+
+impl Input {
+    fn parameters(&self, overrides: Option<&Bound<'_, PyDict>>) -> PyResult<Parameters> {
+        let mut parameters = self.day.default_parameters();
+
+        for (name, value) in overrides.into_iter().flat_map(|overrides| overrides.iter()) {
+            parameters
+                .set(&name.extract::<String>()?, value.extract()?)
+                .map_err(|error| PyValueError::new_err(error.to_string()))?;
+        }
+
+        Ok(parameters)
+    }
+}
+
+/// Parses a day's puzzle input. Raises `ValueError`, pointing at the problem
+/// in the input, if it doesn't parse.
+#[pyfunction]
+#[pyo3(name = "parse")]
+fn parse_input(day: u8, input: &str) -> PyResult<Input> {
+    let day = get_day(day)?;
+    let input = day.parse(input).map_err(PyValueError::new_err)?;
+
+    Ok(Input { day, input })
+}
+
+/// The days that can be parsed and solved.
+#[pyfunction]
+#[pyo3(name = "days")]
+fn list_days() -> Vec<u8> {
+    DAYS.iter().map(|day| day.number).collect()
+}
+
+/// The parameters a day can be solved with, and their defaults.
+#[pyfunction]
+fn parameters(py: Python<'_>, day: u8) -> PyResult<PyObject> {
+    let day = get_day(day)?;
+    let parameters = Value::list(day.parameters.iter().map(|parameter| {
+        Value::record([
+            ("name", parameter.name.into()),
+            ("description", parameter.description.into()),
+            ("default", parameter.default.into()),
+        ])
+    }));
+
+    Ok(to_python(py, &parameters))
+}
+
+fn to_python(py: Python<'_>, value: &Value) -> PyObject {
+    match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_py(py),
+        Value::Integer(value) => value.into_py(py),
+        Value::Text(value) => value.into_py(py),
+        Value::List(items) => {
+            PyList::new_bound(py, items.iter().map(|item| to_python(py, item))).into_py(py)
+        }
+        Value::Record(fields) => {
+            let dict = PyDict::new_bound(py);
+            for (name, value) in fields {
+                dict.set_item(name, to_python(py, value))
+                    .expect("a string key should always be hashable");
+            }
+
+            dict.into_py(py)
+        }
+    }
+}
+
+fn answer_to_python(py: Python<'_>, answer: Answer) -> PyResult<PyObject> {
+    match answer {
+        Answer::Unsigned(value) => Ok(value.into_py(py)),
+        Answer::Signed(value) => Ok(value.into_py(py)),
+        Answer::Text(value) => Ok(value.into_py(py)),
+        Answer::Unavailable => Ok(py.None()),
+        Answer::Overflow => Err(PyOverflowError::new_err(Overflow.to_string())),
+    }
+}
+
+#[pymodule]
+fn advent_of_code(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<Input>()?;
+    module.add_function(wrap_pyfunction!(parse_input, module)?)?;
+    module.add_function(wrap_pyfunction!(list_days, module)?)?;
+    module.add_function(wrap_pyfunction!(parameters, module)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::answer_to_python;
+    use crate::to_python;
+
+    use common::model::Value;
+    use common::Answer;
+
+    use pyo3::exceptions::PyOverflowError;
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+    fn with_python<T>(f: impl FnOnce(Python<'_>) -> T) -> T {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(f)
+    }
+
+    #[test]
+    fn answers_become_python_values() {
+        with_python(|py| {
+            let unsigned = answer_to_python(py, Answer::Unsigned(u128::MAX)).unwrap();
+            assert_eq!(unsigned.extract::<u128>(py).unwrap(), u128::MAX);
+
+            let signed = answer_to_python(py, Answer::Signed(-3)).unwrap();
+            assert_eq!(signed.extract::<i128>(py).unwrap(), -3);
+
+            let text = answer_to_python(py, Answer::Text(String::from("ABC"))).unwrap();
+            assert_eq!(text.extract::<String>(py).unwrap(), "ABC");
+
+            assert!(answer_to_python(py, Answer::Unavailable)
+                .unwrap()
+                .is_none(py));
+        });
+    }
+
+    #[test]
+    fn overflow_raises() {
+        with_python(|py| {
+            let error = answer_to_python(py, Answer::Overflow).unwrap_err();
+
+            assert!(error.is_instance_of::<PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn records_become_dicts_in_order() {
+        let value = Value::record([
+            ("name", Value::from("in")),
+            ("rules", Value::list([1, 2])),
+            ("fallback", Value::Null),
+            ("accepts", Value::from(true)),
+        ]);
+
+        with_python(|py| {
+            let object = to_python(py, &value);
+            let dict = object.downcast_bound::<PyDict>(py).unwrap();
+
+            let keys: Vec<String> = dict.keys().extract().unwrap();
+            assert_eq!(keys, ["name", "rules", "fallback", "accepts"]);
+
+            let name: String = dict.get_item("name").unwrap().unwrap().extract().unwrap();
+            assert_eq!(name, "in");
+            let rules: Vec<i128> = dict.get_item("rules").unwrap().unwrap().extract().unwrap();
+            assert_eq!(rules, [1, 2]);
+            assert!(dict.get_item("fallback").unwrap().unwrap().is_none());
+            let accepts: bool = dict
+                .get_item("accepts")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert!(accepts);
+        });
+    }
+}